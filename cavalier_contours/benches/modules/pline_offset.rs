@@ -1,7 +1,8 @@
 use std::hint::black_box;
 
 use cavalier_contours::polyline::{
-    PlineSource, PlineSourceMut, Polyline, internal::raw_pline_offset::create_raw_offset,
+    OffsetJoinType, PlineSource, PlineSourceMut, Polyline,
+    internal::raw_pline_offset::create_raw_offset,
 };
 use criterion::{BenchmarkGroup, BenchmarkId, Criterion, measurement::WallTime};
 
@@ -98,6 +99,7 @@ pub fn raw_offset_round_join_group(c: &mut Criterion) {
                     black_box(create_raw_offset::<_, _, Polyline<f64>>(
                         black_box(polyline),
                         black_box(*offset),
+                        OffsetJoinType::Round,
                         black_box(1e-5),
                     ))
                 });
@@ -121,11 +123,13 @@ fn repeat_raw_offsets(polyline: &Polyline<f64>, offset: f64, count: u32) {
         black_box(create_raw_offset::<_, _, Polyline<f64>>(
             polyline,
             black_box(offset),
+            OffsetJoinType::Round,
             1e-5,
         ));
         black_box(create_raw_offset::<_, _, Polyline<f64>>(
             polyline,
             black_box(-offset),
+            OffsetJoinType::Round,
             1e-5,
         ));
     }
@@ -0,0 +1,262 @@
+use super::{Vector2, vec2};
+use crate::core::traits::Real;
+
+/// A 2D affine transform (2x3 matrix) mapping a point `(x, y)` to
+/// `(a * x + c * y + e, b * x + d * y + f)`.
+///
+/// The layout of the components matches the SVG/canvas `matrix(a, b, c, d, e, f)` convention,
+/// `(a, b)` and `(c, d)` are the images of the x and y unit vectors and `(e, f)` is the
+/// translation.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::core::math::{Affine2, vec2};
+/// // scale by 2 then translate by (1, 0)
+/// let t = Affine2::scaling(2.0, 2.0).then(&Affine2::translation(1.0, 0.0));
+/// assert_eq!(t.transform_point(vec2(1.0, 1.0)), vec2(3.0, 2.0));
+/// assert_eq!(t.transform_vector(vec2(1.0, 1.0)), vec2(2.0, 2.0));
+/// ```
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Affine2<T = f64> {
+    /// x component of the transformed x unit vector.
+    pub a: T,
+    /// y component of the transformed x unit vector.
+    pub b: T,
+    /// x component of the transformed y unit vector.
+    pub c: T,
+    /// y component of the transformed y unit vector.
+    pub d: T,
+    /// x translation.
+    pub e: T,
+    /// y translation.
+    pub f: T,
+}
+
+impl<T> Affine2<T>
+where
+    T: Real,
+{
+    /// Create a new transform from its components.
+    #[inline]
+    pub fn new(a: T, b: T, c: T, d: T, e: T, f: T) -> Self {
+        Affine2 { a, b, c, d, e, f }
+    }
+
+    /// Create the identity transform.
+    #[inline]
+    pub fn identity() -> Self {
+        Affine2::new(
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+            T::zero(),
+            T::zero(),
+        )
+    }
+
+    /// Create a transform translating by `x` and `y`.
+    #[inline]
+    pub fn translation(x: T, y: T) -> Self {
+        Affine2::new(T::one(), T::zero(), T::zero(), T::one(), x, y)
+    }
+
+    /// Create a transform scaling about the origin by `sx` along the x axis and `sy` along the y
+    /// axis.
+    #[inline]
+    pub fn scaling(sx: T, sy: T) -> Self {
+        Affine2::new(sx, T::zero(), T::zero(), sy, T::zero(), T::zero())
+    }
+
+    /// Create a transform rotating about the origin by `angle` in radians (counter clockwise).
+    #[inline]
+    pub fn rotation(angle: T) -> Self {
+        let (s, c) = (angle.sin(), angle.cos());
+        Affine2::new(c, s, -s, c, T::zero(), T::zero())
+    }
+
+    /// Create a transform rotating about `origin` by `angle` in radians (counter clockwise).
+    #[inline]
+    pub fn rotation_about(origin: Vector2<T>, angle: T) -> Self {
+        Affine2::translation(-origin.x, -origin.y)
+            .then(&Affine2::rotation(angle))
+            .then(&Affine2::translation(origin.x, origin.y))
+    }
+
+    /// Create a transform shearing about the origin, mapping `(x, y)` to `(x + kx * y, y + ky *
+    /// x)`.
+    #[inline]
+    pub fn shearing(kx: T, ky: T) -> Self {
+        Affine2::new(T::one(), ky, kx, T::one(), T::zero(), T::zero())
+    }
+
+    /// Create a transform mirroring across the line passing through `point` with `direction`.
+    ///
+    /// If `direction` is a zero vector (so there is no line to mirror across) the identity
+    /// transform is returned.
+    #[inline]
+    pub fn mirror(point: Vector2<T>, direction: Vector2<T>) -> Self {
+        let u = direction.normalize();
+        if !u.x.is_finite() || !u.y.is_finite() {
+            return Affine2::identity();
+        }
+
+        let two = T::two();
+        // reflection matrix 2 * u * u^T - I
+        let a = two * u.x * u.x - T::one();
+        let b = two * u.x * u.y;
+        let d = two * u.y * u.y - T::one();
+        let linear = Affine2::new(a, b, b, d, T::zero(), T::zero());
+        let moved = point - linear.transform_vector(point);
+        Affine2::new(a, b, b, d, moved.x, moved.y)
+    }
+
+    /// Returns the transform applying `self` followed by `other`.
+    #[inline]
+    pub fn then(&self, other: &Self) -> Self {
+        Affine2::new(
+            other.a * self.a + other.c * self.b,
+            other.b * self.a + other.d * self.b,
+            other.a * self.c + other.c * self.d,
+            other.b * self.c + other.d * self.d,
+            other.a * self.e + other.c * self.f + other.e,
+            other.b * self.e + other.d * self.f + other.f,
+        )
+    }
+
+    /// Determinant of the linear part of the transform, negative if the transform mirrors
+    /// (inverts orientation).
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> T {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Returns the inverse transform or `None` if the determinant is fuzzy zero.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.fuzzy_eq_zero() {
+            return None;
+        }
+
+        let a = self.d / det;
+        let b = -self.b / det;
+        let c = -self.c / det;
+        let d = self.a / det;
+        Some(Affine2::new(
+            a,
+            b,
+            c,
+            d,
+            -(a * self.e + c * self.f),
+            -(b * self.e + d * self.f),
+        ))
+    }
+
+    /// Returns true if the transform maps circles to circles (only uniform scale, rotation,
+    /// mirroring, and translation) using `fuzzy_epsilon` for comparing the lengths and dot product
+    /// of the transformed unit vectors.
+    #[inline]
+    #[must_use]
+    pub fn is_similarity_eps(&self, fuzzy_epsilon: T) -> bool {
+        let x_axis = vec2(self.a, self.b);
+        let y_axis = vec2(self.c, self.d);
+        x_axis.dot(y_axis).fuzzy_eq_zero_eps(fuzzy_epsilon)
+            && x_axis
+                .length_squared()
+                .fuzzy_eq_eps(y_axis.length_squared(), fuzzy_epsilon)
+    }
+
+    /// Apply the transform to a point.
+    #[inline]
+    pub fn transform_point(&self, point: Vector2<T>) -> Vector2<T> {
+        vec2(
+            self.a * point.x + self.c * point.y + self.e,
+            self.b * point.x + self.d * point.y + self.f,
+        )
+    }
+
+    /// Apply the transform to a vector (ignores the translation).
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector2<T>) -> Vector2<T> {
+        vec2(
+            self.a * vector.x + self.c * vector.y,
+            self.b * vector.x + self.d * vector.y,
+        )
+    }
+}
+
+impl<T> Default for Affine2<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::traits::FuzzyEq;
+
+    fn fuzzy_eq(t1: &Affine2, t2: &Affine2) -> bool {
+        [
+            (t1.a, t2.a),
+            (t1.b, t2.b),
+            (t1.c, t2.c),
+            (t1.d, t2.d),
+            (t1.e, t2.e),
+            (t1.f, t2.f),
+        ]
+        .iter()
+        .all(|(x, y)| x.fuzzy_eq(*y))
+    }
+
+    #[test]
+    fn rotation_about() {
+        let origin = vec2(1.0, 2.0);
+        let t = Affine2::rotation_about(origin, 0.7);
+        let p = vec2(4.0, -3.0);
+        assert!(t.transform_point(p).fuzzy_eq(p.rotate_about(origin, 0.7)));
+        assert!(t.transform_point(origin).fuzzy_eq(origin));
+        assert!(t.is_similarity_eps(1e-8));
+        assert!(t.determinant().fuzzy_eq(1.0));
+    }
+
+    #[test]
+    fn mirror() {
+        // mirror across y = x + 1
+        let t = Affine2::mirror(vec2(0.0, 1.0), vec2(2.0, 2.0));
+        assert!(t.transform_point(vec2(0.0, 0.0)).fuzzy_eq(vec2(-1.0, 1.0)));
+        assert!(t.transform_point(vec2(3.0, 4.0)).fuzzy_eq(vec2(3.0, 4.0)));
+        assert!(t.determinant().fuzzy_eq(-1.0));
+        assert!(t.is_similarity_eps(1e-8));
+        assert!(fuzzy_eq(&t.then(&t), &Affine2::identity()));
+    }
+
+    #[test]
+    fn mirror_zero_direction() {
+        let t = Affine2::mirror(vec2(1.0, 2.0), vec2(0.0, 0.0));
+        assert_eq!(t, Affine2::identity());
+    }
+
+    #[test]
+    fn compose_and_inverse() {
+        let t = Affine2::shearing(0.5, 0.0)
+            .then(&Affine2::scaling(2.0, 3.0))
+            .then(&Affine2::translation(1.0, -1.0));
+        assert!(!t.is_similarity_eps(1e-8));
+        let p = vec2(2.0, 4.0);
+        assert!(t.transform_point(p).fuzzy_eq(vec2(9.0, 11.0)));
+        let inv = t.inverse().unwrap();
+        assert!(inv.transform_point(t.transform_point(p)).fuzzy_eq(p));
+        assert!(fuzzy_eq(&t.then(&inv), &Affine2::identity()));
+        assert!(Affine2::scaling(1.0, 0.0).inverse().is_none());
+    }
+}
@@ -471,7 +471,8 @@ where
 /// Test if a `point` is within a arc sweep angle region.
 ///
 /// Arc is defined by `center`, `arc_start`, `arc_end`, and arc direction parameter `is_clockwise`.
-/// The angle region is defined as if the arc had infinite radius projected outward in a cone.
+/// The angle region is defined as if the arc had infinite radius projected outward in a cone. Arcs
+/// sweeping more than a half circle are supported.
 ///
 /// `epsilon` is a positional tolerance used for fuzzy comparing against the sweep boundaries.
 ///
@@ -522,11 +523,22 @@ where
     let start_cross = start_vector.perp_dot(point_vector);
     let end_cross = end_vector.perp_dot(point_vector);
 
-    // First test the whole sweep region without tolerance.
-    let exactly_within_sweep = if is_clockwise {
-        start_cross <= T::zero() && end_cross >= T::zero()
+    // First test the whole sweep region without tolerance. A sweep larger than a half circle is
+    // the union of the half planes rather than the intersection (relative threshold keeps near
+    // zero sweeps from being taken as near full circles due to rounding).
+    let sweep_cross = start_vector.perp_dot(end_vector);
+    let sweep_cross_threshold =
+        T::fuzzy_epsilon() * (start_vector.length_squared() * end_vector.length_squared()).sqrt();
+    let is_large_sweep = if is_clockwise {
+        sweep_cross > sweep_cross_threshold
     } else {
-        start_cross >= T::zero() && end_cross <= T::zero()
+        sweep_cross < -sweep_cross_threshold
+    };
+    let exactly_within_sweep = match (is_clockwise, is_large_sweep) {
+        (true, true) => start_cross <= T::zero() || end_cross >= T::zero(),
+        (true, false) => start_cross <= T::zero() && end_cross >= T::zero(),
+        (false, true) => start_cross >= T::zero() || end_cross <= T::zero(),
+        (false, false) => start_cross >= T::zero() && end_cross <= T::zero(),
     };
     if exactly_within_sweep {
         return true;
@@ -632,4 +644,28 @@ mod tests {
             ));
         }
     }
+
+    #[test]
+    fn large_arc_sweep_includes_points_past_half_circle() {
+        let center = Vector2::zero();
+        // three quarter circle from (1, 0) to (0, -1) counter clockwise
+        let start = Vector2::new(1.0, 0.0);
+        let end = Vector2::new(0.0, -1.0);
+        for (point, expected) in [
+            (Vector2::new(1.0, 1.0), true),
+            (Vector2::new(-1.0, 1.0), true),
+            (Vector2::new(-1.0, -1.0), true),
+            (Vector2::new(1.0, -1.0), false),
+        ] {
+            assert_eq!(
+                point_within_arc_sweep(center, start, end, false, point, 1e-5),
+                expected
+            );
+            // clockwise from end to start covers the same region
+            assert_eq!(
+                point_within_arc_sweep(center, end, start, true, point, 1e-5),
+                expected
+            );
+        }
+    }
 }
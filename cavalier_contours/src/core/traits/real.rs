@@ -46,6 +46,13 @@ pub trait Real:
     fn max_value() -> Self {
         num_traits::real::Real::max_value()
     }
+
+    /// Returns true if this number is neither infinite nor NaN.
+    #[inline]
+    #[must_use]
+    fn is_finite(self) -> bool {
+        self.to_f64().is_some_and(f64::is_finite)
+    }
 }
 
 impl Real for f32 {
@@ -68,6 +75,11 @@ impl Real for f32 {
     fn four() -> Self {
         4.0f32
     }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        num_traits::Float::is_finite(self)
+    }
 }
 
 impl Real for f64 {
@@ -90,4 +102,9 @@ impl Real for f64 {
     fn four() -> Self {
         4.0f64
     }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        num_traits::Float::is_finite(self)
+    }
 }
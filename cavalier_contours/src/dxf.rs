@@ -0,0 +1,405 @@
+//! Reading and writing polylines in ASCII DXF files, enabled with the `dxf` feature.
+//!
+//! DXF LWPOLYLINE entities use the same vertex and bulge representation as [`Polyline`] so they
+//! convert without approximation. Only the ENTITIES section is read (block definitions and inserts
+//! are ignored) and written.
+
+use std::fmt::{self, Write};
+
+use crate::{
+    core::{
+        math::{Vector2, bulge_from_angle, point_on_circle},
+        traits::{FuzzyEq, Real},
+    },
+    polyline::{PlineSource, PlineSourceMut, PlineVertex, Polyline},
+};
+
+/// Polylines read from a DXF file.
+#[derive(Debug, Clone, Default)]
+pub struct DxfPolylines {
+    /// Polylines in the order their entities appear in the file. The userdata of each polyline is
+    /// set to a single value which is the index of its layer name in `layers`.
+    pub plines: Vec<Polyline<f64>>,
+    /// Layer names of the entities read (each name appears once).
+    pub layers: Vec<String>,
+}
+
+impl DxfPolylines {
+    /// Returns the layer name of a polyline read from the DXF file (using the polyline userdata).
+    ///
+    /// Returns `None` if the polyline userdata does not refer to a layer.
+    #[must_use]
+    pub fn layer_name(&self, pline: &Polyline<f64>) -> Option<&str> {
+        let index = usize::try_from(*pline.userdata.first()?).ok()?;
+        self.layers.get(index).map(String::as_str)
+    }
+
+    fn add(&mut self, mut pline: Polyline<f64>, layer: &str) {
+        if pline.vertex_count() < 2 {
+            return;
+        }
+        let index = self
+            .layers
+            .iter()
+            .position(|l| l == layer)
+            .unwrap_or_else(|| {
+                self.layers.push(layer.to_owned());
+                self.layers.len() - 1
+            });
+        pline.set_userdata_values([index as u64]);
+        self.plines.push(pline);
+    }
+}
+
+/// Error returned when DXF data fails to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DxfError {
+    /// Group code line is not an integer (binary DXF files are not supported).
+    InvalidGroupCode {
+        /// Line number (starting at 1).
+        line: usize,
+    },
+    /// Group code is not followed by a value line.
+    MissingValue {
+        /// Line number (starting at 1) of the group code.
+        line: usize,
+    },
+    /// Group value that should be a number failed to parse.
+    InvalidNumber {
+        /// Line number (starting at 1).
+        line: usize,
+    },
+}
+
+impl fmt::Display for DxfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DxfError::InvalidGroupCode { line } => write!(f, "invalid group code on line {line}"),
+            DxfError::MissingValue { line } => {
+                write!(f, "group code on line {line} is missing a value")
+            }
+            DxfError::InvalidNumber { line } => write!(f, "invalid number on line {line}"),
+        }
+    }
+}
+
+impl std::error::Error for DxfError {}
+
+/// Group code and value pair, with the line number of the value for errors.
+#[derive(Debug, Clone, Copy)]
+struct Group<'a> {
+    code: i32,
+    value: &'a str,
+    line: usize,
+}
+
+impl Group<'_> {
+    fn number(&self) -> Result<f64, DxfError> {
+        self.value
+            .parse::<f64>()
+            .ok()
+            .filter(|n| n.is_finite())
+            .ok_or(DxfError::InvalidNumber { line: self.line })
+    }
+
+    fn flags(&self) -> Result<i64, DxfError> {
+        self.value
+            .parse::<i64>()
+            .map_err(|_| DxfError::InvalidNumber { line: self.line })
+    }
+}
+
+/// Entity with its type name and groups (not including the type group).
+struct Entity<'a> {
+    kind: &'a str,
+    groups: &'a [Group<'a>],
+}
+
+impl Entity<'_> {
+    fn layer(&self) -> &str {
+        self.groups
+            .iter()
+            .find(|g| g.code == 8)
+            .map_or("0", |g| g.value)
+    }
+
+    /// Returns the value of the first group with `code` as a number, or `default` if not present.
+    fn number(&self, code: i32, default: f64) -> Result<f64, DxfError> {
+        self.groups
+            .iter()
+            .find(|g| g.code == code)
+            .map_or(Ok(default), Group::number)
+    }
+
+    fn flags(&self) -> Result<i64, DxfError> {
+        self.groups
+            .iter()
+            .find(|g| g.code == 70)
+            .map_or(Ok(0), Group::flags)
+    }
+
+    /// Returns true if the entity extrusion direction is negative Z, which mirrors the entity
+    /// coordinate system x axis.
+    fn is_mirrored(&self) -> Result<bool, DxfError> {
+        Ok(self.number(230, 1.0)? < 0.0)
+    }
+}
+
+fn parse_groups(data: &str) -> Result<Vec<Group<'_>>, DxfError> {
+    let mut groups = Vec::new();
+    let mut lines = data.lines().enumerate();
+    while let Some((i, code_line)) = lines.next() {
+        let code_line = code_line.trim();
+        if code_line.is_empty() && groups.is_empty() {
+            // allow leading blank lines
+            continue;
+        }
+        let code = code_line
+            .parse::<i32>()
+            .map_err(|_| DxfError::InvalidGroupCode { line: i + 1 })?;
+        let (j, value) = lines.next().ok_or(DxfError::MissingValue { line: i + 1 })?;
+        groups.push(Group {
+            code,
+            value: value.trim(),
+            line: j + 1,
+        });
+    }
+
+    Ok(groups)
+}
+
+/// Read the LWPOLYLINE, POLYLINE, LINE, ARC, and CIRCLE entities in ASCII DXF `data` as polylines.
+///
+/// LINE and ARC entities become open polylines, CIRCLE entities become closed polylines with two
+/// half circle arc segments. ARC entities larger than a half circle are split into two arc
+/// segments, LWPOLYLINE and POLYLINE vertex bulges are kept as is (see
+/// [`PlineSource::split_large_arcs`]). Polylines with less than 2 vertexes and 3D POLYLINE
+/// entities (3D polylines, polygon meshes, and polyface meshes) are skipped. Entities with a
+/// negative Z extrusion direction are mirrored into world coordinates, the extrusion direction is
+/// otherwise ignored (entities are projected onto the XY plane).
+///
+/// # Errors
+///
+/// Returns [`DxfError`] if `data` is not valid ASCII DXF data.
+///
+/// # Examples
+/// ```
+/// # use cavalier_contours::dxf::*;
+/// # use cavalier_contours::polyline::*;
+/// let data = "0\nSECTION\n2\nENTITIES\n\
+///             0\nLWPOLYLINE\n8\nCUT\n90\n2\n70\n1\n\
+///             10\n0.0\n20\n0.0\n42\n1.0\n10\n2.0\n20\n0.0\n42\n1.0\n\
+///             0\nLINE\n8\nSCORE\n10\n0.0\n20\n0.0\n11\n5.0\n21\n0.0\n\
+///             0\nENDSEC\n0\nEOF\n";
+/// let result = read_dxf(data).unwrap();
+/// assert_eq!(result.plines.len(), 2);
+/// assert!(result.plines[0].is_closed());
+/// assert_eq!(result.layer_name(&result.plines[0]), Some("CUT"));
+/// assert_eq!(result.layer_name(&result.plines[1]), Some("SCORE"));
+/// ```
+pub fn read_dxf(data: &str) -> Result<DxfPolylines, DxfError> {
+    let groups = parse_groups(data)?;
+
+    // split the ENTITIES section groups into entities
+    let mut entities = Vec::new();
+    let mut in_entities = false;
+    let mut i = 0;
+    while i < groups.len() {
+        let g = groups[i];
+        if g.code != 0 {
+            i += 1;
+            continue;
+        }
+        let end = groups[i + 1..]
+            .iter()
+            .position(|g| g.code == 0)
+            .map_or(groups.len(), |p| i + 1 + p);
+        match g.value {
+            "SECTION" => {
+                in_entities = groups
+                    .get(i + 1)
+                    .is_some_and(|n| n.code == 2 && n.value == "ENTITIES");
+            }
+            "ENDSEC" => in_entities = false,
+            kind if in_entities => entities.push(Entity {
+                kind,
+                groups: &groups[i + 1..end],
+            }),
+            _ => {}
+        }
+        i = end;
+    }
+
+    let mut result = DxfPolylines::default();
+    let mut entities = entities.iter();
+    while let Some(entity) = entities.next() {
+        match entity.kind {
+            "LWPOLYLINE" => result.add(read_lwpolyline(entity)?, entity.layer()),
+            "POLYLINE" => {
+                // vertexes follow as VERTEX entities until SEQEND
+                let flags = entity.flags()?;
+                let mut pline = Polyline::new();
+                pline.set_is_closed(flags & 1 != 0);
+                for vertex in entities.by_ref().take_while(|e| e.kind != "SEQEND") {
+                    if vertex.kind != "VERTEX" || vertex.flags()? & 16 != 0 {
+                        // skip spline frame control points
+                        continue;
+                    }
+                    pline.add(
+                        vertex.number(10, 0.0)?,
+                        vertex.number(20, 0.0)?,
+                        vertex.number(42, 0.0)?,
+                    );
+                }
+                // skip 3D polylines, polygon meshes, and polyface meshes
+                if flags & (8 | 16 | 64) == 0 {
+                    if entity.is_mirrored()? {
+                        mirror_x(&mut pline);
+                    }
+                    result.add(pline, entity.layer());
+                }
+            }
+            "LINE" => {
+                let mut pline = Polyline::new();
+                pline.add(entity.number(10, 0.0)?, entity.number(20, 0.0)?, 0.0);
+                pline.add(entity.number(11, 0.0)?, entity.number(21, 0.0)?, 0.0);
+                if pline.at(0).pos().fuzzy_eq(pline.at(1).pos()) {
+                    continue;
+                }
+                result.add(pline, entity.layer());
+            }
+            "ARC" | "CIRCLE" => {
+                let center = Vector2::new(entity.number(10, 0.0)?, entity.number(20, 0.0)?);
+                let radius = entity.number(40, 0.0)?;
+                if radius <= 0.0 {
+                    continue;
+                }
+                let mut pline = if entity.kind == "ARC" {
+                    arc_pline(
+                        center,
+                        radius,
+                        entity.number(50, 0.0)?,
+                        entity.number(51, 0.0)?,
+                    )
+                } else {
+                    let mut circle = Polyline::new_closed();
+                    circle.add(center.x - radius, center.y, 1.0);
+                    circle.add(center.x + radius, center.y, 1.0);
+                    circle
+                };
+                if entity.is_mirrored()? {
+                    mirror_x(&mut pline);
+                }
+                result.add(pline, entity.layer());
+            }
+            _ => {}
+        }
+    }
+
+    Ok(result)
+}
+
+fn read_lwpolyline(entity: &Entity) -> Result<Polyline<f64>, DxfError> {
+    let mut pline = Polyline::new();
+    pline.set_is_closed(entity.flags()? & 1 != 0);
+    for g in entity.groups {
+        match g.code {
+            10 => pline.add(g.number()?, 0.0, 0.0),
+            20 | 42 if pline.is_empty() => {}
+            20 => {
+                let last = pline.last().unwrap();
+                pline.set_last(PlineVertex::new(last.x, g.number()?, last.bulge));
+            }
+            42 => {
+                let last = pline.last().unwrap();
+                pline.set_last(last.with_bulge(g.number()?));
+            }
+            _ => {}
+        }
+    }
+    if entity.is_mirrored()? {
+        mirror_x(&mut pline);
+    }
+
+    Ok(pline)
+}
+
+/// Create an open polyline for a counter clockwise arc from `start_angle` to `end_angle` (in
+/// degrees), arcs larger than a half circle are split in two.
+fn arc_pline(center: Vector2<f64>, radius: f64, start_angle: f64, end_angle: f64) -> Polyline<f64> {
+    let start_angle = start_angle.to_radians();
+    let mut sweep = (end_angle.to_radians() - start_angle).rem_euclid(std::f64::consts::TAU);
+    if sweep.fuzzy_eq_zero() {
+        // start and end angle are the same, full circle
+        sweep = std::f64::consts::TAU;
+    }
+    let count = if sweep > std::f64::consts::PI { 2 } else { 1 };
+    let seg_sweep = sweep / f64::from(count);
+    let bulge = bulge_from_angle(seg_sweep);
+
+    let mut pline = Polyline::new();
+    for i in 0..=count {
+        let point = point_on_circle(radius, center, start_angle + seg_sweep * f64::from(i));
+        pline.add(point.x, point.y, if i < count { bulge } else { 0.0 });
+    }
+    pline
+}
+
+/// Mirror polyline about the y axis (for entities with negative Z extrusion direction).
+fn mirror_x(pline: &mut Polyline<f64>) {
+    for i in 0..pline.vertex_count() {
+        let v = pline.at(i);
+        pline.set(i, -v.x, v.y, -v.bulge);
+    }
+}
+
+/// Write polylines as LWPOLYLINE entities in ASCII DXF data, each polyline is given with its layer
+/// name.
+///
+/// The DXF data written only has an ENTITIES section.
+///
+/// # Examples
+/// ```
+/// # use cavalier_contours::core::traits::FuzzyEq;
+/// # use cavalier_contours::dxf::*;
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::pline_closed;
+/// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+/// let data = write_dxf([(&circle, "CUT")]);
+/// let result = read_dxf(&data).unwrap();
+/// assert_eq!(result.plines.len(), 1);
+/// assert!(result.plines[0].area().fuzzy_eq(std::f64::consts::PI));
+/// ```
+pub fn write_dxf<'a, P, T, I>(entities: I) -> String
+where
+    P: PlineSource<Num = T> + ?Sized + 'a,
+    T: Real,
+    I: IntoIterator<Item = (&'a P, &'a str)>,
+{
+    let mut data = String::new();
+    let mut write_group = |code: i32, value: fmt::Arguments| {
+        writeln!(data, "{code:>3}\n{value}").expect("writing to string cannot fail");
+    };
+
+    write_group(0, format_args!("SECTION"));
+    write_group(2, format_args!("ENTITIES"));
+    for (pline, layer) in entities {
+        write_group(0, format_args!("LWPOLYLINE"));
+        write_group(100, format_args!("AcDbEntity"));
+        write_group(8, format_args!("{layer}"));
+        write_group(100, format_args!("AcDbPolyline"));
+        write_group(90, format_args!("{}", pline.vertex_count()));
+        write_group(70, format_args!("{}", u8::from(pline.is_closed())));
+        for v in pline.iter_vertexes() {
+            write_group(10, format_args!("{:?}", v.x.to_f64().unwrap()));
+            write_group(20, format_args!("{:?}", v.y.to_f64().unwrap()));
+            if !v.bulge_is_zero() {
+                write_group(42, format_args!("{:?}", v.bulge.to_f64().unwrap()));
+            }
+        }
+    }
+    write_group(0, format_args!("ENDSEC"));
+    write_group(0, format_args!("EOF"));
+
+    data
+}
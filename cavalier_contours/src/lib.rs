@@ -8,5 +8,9 @@ pub use static_aabb2d_index;
 mod macros;
 #[macro_use]
 pub mod core;
+#[cfg(feature = "dxf")]
+pub mod dxf;
 pub mod polyline;
 pub mod shape_algorithms;
+#[cfg(feature = "svg")]
+pub mod svg;
@@ -3,7 +3,22 @@
 //! Not expected to be used directly as part of the library but may be used to help learn about the
 //! algorithms.
 pub mod pline_boolean;
+pub mod pline_clip;
 pub mod pline_contains;
+pub mod pline_convex_hull;
+pub mod pline_corners;
+pub mod pline_distance;
+pub mod pline_fill;
+pub mod pline_fit_arcs;
 pub mod pline_intersects;
+pub mod pline_medial_axis;
+pub mod pline_minkowski;
 pub mod pline_offset;
+pub mod pline_path_slice;
+pub mod pline_repair;
+pub mod pline_sample;
+pub mod pline_simplify;
+pub mod pline_transform;
+pub mod pline_validate;
+pub mod pline_variable_offset;
 pub mod raw_pline_offset;
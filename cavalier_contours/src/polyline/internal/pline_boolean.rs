@@ -7,14 +7,17 @@ use crate::{
     core::math::dist_squared,
     polyline::{
         BooleanOp, BooleanPlineSlice, BooleanResult, BooleanResultInfo, BooleanResultPline,
-        FindIntersectsOptions, PlineBasicIntersect, PlineBooleanOptions, PlineCreation,
-        PlineSource, PlineViewData, seg_midpoint, seg_split_at_point,
+        FillRule, FindIntersectsOptions, PlineBasicIntersect, PlineBooleanOptions, PlineCreation,
+        PlineFillOptions, PlineSelfIntersectOptions, PlineSource, PlineView, PlineViewData,
+        Polyline, seg_midpoint, seg_split_at_point,
     },
+    shape_algorithms::{IndexedPolyline, Shape, ShapeBooleanOptions},
 };
 use std::collections::BTreeMap;
 
-use super::pline_intersects::{
-    OverlappingSlice, find_intersects, sort_and_join_overlapping_intersects,
+use super::{
+    pline_fill::resolve_self_intersects,
+    pline_intersects::{OverlappingSlice, find_intersects, sort_and_join_overlapping_intersects},
 };
 use crate::{
     core::{math::Vector2, traits::Real},
@@ -802,6 +805,204 @@ where
     result
 }
 
+/// Stitches slices end to end into polylines. `slice_view` returns the view of a slice following
+/// its direction, slice directions must agree (every start point connects with an end point).
+///
+/// `select` is given the polyline stitched so far, the index of its last slice, and the indexes of
+/// the slices starting at its end point (including the first slice of the polyline once it
+/// connects back to its start), it returns the slice to continue with or `None` to end the
+/// polyline. Closed polylines with less than 3 vertexes or with area less than
+/// `collapsed_area_eps` are discarded, polylines that cannot be closed are kept open only if
+/// `keep_open` is true. Each result polyline is returned with the indexes of its slices.
+pub(crate) fn stitch_slices<'a, P, T, O, V, S>(
+    slice_count: usize,
+    slice_view: V,
+    mut select: S,
+    pos_equal_eps: T,
+    collapsed_area_eps: Option<T>,
+    keep_open: bool,
+) -> Vec<(O, Vec<usize>)>
+where
+    P: PlineSource<Num = T> + ?Sized + 'a,
+    T: Real,
+    O: PlineCreation<Num = T>,
+    V: Fn(usize) -> PlineView<'a, P>,
+    S: FnMut(&O, usize, &[usize]) -> Option<usize>,
+{
+    let mut result = Vec::new();
+    if slice_count == 0 {
+        return result;
+    }
+
+    // load all the slice start points into spatial index
+    let aabb_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(slice_count);
+        for i in 0..slice_count {
+            let pt = slice_view(i).at(0).pos();
+            builder.add(pt.x, pt.y, pt.x, pt.y);
+        }
+        builder.build().unwrap()
+    };
+
+    let mut visited = vec![false; slice_count];
+    let mut query_results = Vec::new();
+    let mut query_stack = Vec::with_capacity(8);
+
+    for i in 0..slice_count {
+        if visited[i] {
+            continue;
+        }
+        visited[i] = true;
+
+        let mut current_pline = O::create_from_remove_repeat(&slice_view(i), pos_equal_eps);
+        let mut slice_indexes = vec![i];
+        let mut current_idx = i;
+        let mut loop_count = 0;
+        loop {
+            if loop_count > slice_count {
+                // prevent infinite loop
+                unreachable!("loop_count exceeded slice count while stitching slices");
+            }
+            loop_count += 1;
+
+            query_results.clear();
+            let mut query_visitor = |j: usize| {
+                // skip already visited
+                if j == i || !visited[j] {
+                    query_results.push(j);
+                }
+            };
+            let ep = current_pline.last().unwrap().pos();
+            aabb_index.visit_query_with_stack(
+                ep.x - pos_equal_eps,
+                ep.y - pos_equal_eps,
+                ep.x + pos_equal_eps,
+                ep.y + pos_equal_eps,
+                &mut query_visitor,
+                &mut query_stack,
+            );
+
+            let next_idx = if query_results.is_empty() {
+                None
+            } else {
+                select(&current_pline, current_idx, &query_results)
+            };
+            let Some(next_idx) = next_idx else {
+                // may arrive here due to epsilon/thresholds around overlapping segments
+                if keep_open && current_pline.vertex_count() > 1 {
+                    result.push((current_pline, slice_indexes));
+                }
+                break;
+            };
+
+            if next_idx == i {
+                // connected back to beginning, close polyline
+                if current_pline.vertex_count() > 2 {
+                    current_pline.remove_last();
+                    current_pline.set_is_closed(true);
+                    if collapsed_area_eps.is_none_or(|eps| current_pline.area().abs() >= eps) {
+                        result.push((current_pline, slice_indexes));
+                    }
+                }
+                break;
+            }
+
+            current_pline.remove_last();
+            current_pline.extend_remove_repeat(&slice_view(next_idx), pos_equal_eps);
+            visited[next_idx] = true;
+            slice_indexes.push(next_idx);
+            current_idx = next_idx;
+        }
+    }
+
+    result
+}
+
+/// Resolves both polylines into non-self-intersecting loops using `fill_rule` and performs the
+/// boolean operation between the resulting shapes.
+fn fill_rule_boolean<P, R, O, T>(
+    pline1: &P,
+    pline2: &R,
+    operation: BooleanOp,
+    fill_rule: FillRule,
+    options: &PlineBooleanOptions<T>,
+) -> BooleanResult<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let constructed_index;
+    let pline1_aabb_index = if let Some(x) = options.pline1_aabb_index {
+        x
+    } else {
+        constructed_index = pline1.create_approx_aabb_index();
+        &constructed_index
+    };
+
+    // with no intersects between the polylines each lies within a single region of the other so
+    // containment is determined by the fill rule at any one vertex
+    let boolean_info =
+        process_for_boolean(pline1, pline2, pline1_aabb_index, options.pos_equal_eps);
+    let result_info = if boolean_info.completely_overlapping() {
+        BooleanResultInfo::Overlapping
+    } else if boolean_info.any_intersects() {
+        BooleanResultInfo::Intersected
+    } else if fill_rule.is_filled(pline2.winding_number(pline1.at(0).pos())) {
+        BooleanResultInfo::Pline1InsidePline2
+    } else if fill_rule.is_filled(pline1.winding_number(pline2.at(0).pos())) {
+        BooleanResultInfo::Pline2InsidePline1
+    } else {
+        BooleanResultInfo::Disjoint
+    };
+
+    let fill_options = |aabb_index| PlineFillOptions {
+        aabb_index,
+        pos_equal_eps: options.pos_equal_eps,
+        collapsed_area_eps: options.collapsed_area_eps,
+    };
+    let shape1 = Shape::from_plines(resolve_self_intersects::<_, Polyline<T>, _>(
+        pline1,
+        fill_rule,
+        &fill_options(Some(pline1_aabb_index)),
+    ));
+    let shape2 = Shape::from_plines(resolve_self_intersects::<_, Polyline<T>, _>(
+        pline2,
+        fill_rule,
+        &fill_options(None),
+    ));
+    let result = shape1.boolean_opt(
+        &shape2,
+        operation,
+        &ShapeBooleanOptions {
+            pos_equal_eps: options.pos_equal_eps,
+            collapsed_area_eps: options.collapsed_area_eps,
+        },
+    );
+
+    let composite_userdata = pline1
+        .get_userdata_values()
+        .chain(pline2.get_userdata_values())
+        .collect::<Vec<_>>();
+    let to_output = |plines: Vec<IndexedPolyline<T>>| {
+        plines
+            .into_iter()
+            .map(|p| {
+                let mut pline = O::create_from(&p.polyline);
+                pline.set_userdata_values(composite_userdata.iter().copied());
+                pline
+            })
+            .collect::<Vec<_>>()
+    };
+
+    BooleanResult::from_whole_plines(
+        to_output(result.ccw_plines),
+        to_output(result.cw_plines),
+        result_info,
+    )
+}
+
 /// Perform boolean operation between two polylines using parameters given.
 pub fn polyline_boolean<P, R, O, T>(
     pline1: &P,
@@ -823,6 +1024,39 @@ where
         return BooleanResult::empty(BooleanResultInfo::InvalidInput);
     }
 
+    // split arcs larger than a half circle, result slices then refer to the split polylines
+    let split1 = pline1.split_large_arcs(options.pos_equal_eps);
+    let split2 = pline2.split_large_arcs(options.pos_equal_eps);
+    if split1.is_some() || split2.is_some() {
+        let split_options = PlineBooleanOptions {
+            pline1_aabb_index: options.pline1_aabb_index.filter(|_| split1.is_none()),
+            ..*options
+        };
+        return match (&split1, &split2) {
+            (Some(p1), Some(p2)) => polyline_boolean(p1, p2, operation, &split_options),
+            (Some(p1), None) => polyline_boolean(p1, pline2, operation, &split_options),
+            (None, Some(p2)) => polyline_boolean(pline1, p2, operation, &split_options),
+            (None, None) => unreachable!(),
+        };
+    }
+
+    if let Some(fill_rule) = options.fill_rule {
+        let pline1_self_intersects =
+            pline1.scan_for_self_intersect_opt(&PlineSelfIntersectOptions {
+                aabb_index: options.pline1_aabb_index,
+                pos_equal_eps: options.pos_equal_eps,
+                ..Default::default()
+            });
+        let pline2_self_intersects =
+            pline2.scan_for_self_intersect_opt(&PlineSelfIntersectOptions {
+                pos_equal_eps: options.pos_equal_eps,
+                ..Default::default()
+            });
+        if pline1_self_intersects || pline2_self_intersects {
+            return fill_rule_boolean(pline1, pline2, operation, fill_rule, options);
+        }
+    }
+
     let constructed_index;
     let pline1_aabb_index = if let Some(x) = options.pline1_aabb_index {
         x
@@ -0,0 +1,254 @@
+//! Clipping a polyline against a closed boundary polyline.
+//!
+//! The polyline is split at all of its intersects with the boundary (including the end points of
+//! segments overlapping the boundary) and each resulting part is classified as inside or outside of
+//! the boundary using a point on the part.
+
+use crate::{
+    core::{
+        math::{Vector2, dist_squared},
+        traits::Real,
+    },
+    polyline::{
+        ClipKeep, ClipResult, ClipResultPline, FindIntersectsOptions, PlineClipOptions,
+        PlineCreation, PlineSource, PlineViewData, seg_midpoint,
+    },
+};
+
+use super::pline_intersects::{find_intersects, split_large_arcs_source_indexes};
+
+/// Clip `polyline` against the closed `boundary` polyline, keeping the parts selected by `keep`.
+///
+/// Parts that lie on the boundary are considered inside. Returns an empty result if `polyline` has
+/// less than 2 vertexes or `boundary` is not closed or has less than 2 vertexes.
+pub fn clip_polyline<P, R, O, T>(
+    polyline: &P,
+    boundary: &R,
+    keep: ClipKeep,
+    options: &PlineClipOptions<T>,
+) -> ClipResult<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let mut result = ClipResult::empty();
+    if polyline.vertex_count() < 2 || boundary.vertex_count() < 2 || !boundary.is_closed() {
+        return result;
+    }
+
+    // split arcs larger than a half circle
+    if let Some(split_boundary) = boundary.split_large_arcs(options.pos_equal_eps) {
+        return clip_polyline(polyline, &split_boundary, keep, options);
+    }
+    if let Some(split) = polyline.split_large_arcs(options.pos_equal_eps) {
+        let split_options = PlineClipOptions {
+            pline_aabb_index: None,
+            ..*options
+        };
+        let mut result: ClipResult<O> = clip_polyline(&split, boundary, keep, &split_options);
+        // map split polyline vertex indexes back to the source polyline vertex indexes
+        let source_indexes = split_large_arcs_source_indexes(polyline, options.pos_equal_eps);
+        for part in result.inside.iter_mut().chain(result.outside.iter_mut()) {
+            part.start_index = source_indexes[part.start_index];
+            part.end_index = source_indexes[part.end_index];
+        }
+        return result;
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+
+    let constructed_index;
+    let pline_aabb_index = if let Some(x) = options.pline_aabb_index {
+        x
+    } else {
+        constructed_index = polyline.create_approx_aabb_index();
+        &constructed_index
+    };
+
+    let intrs = find_intersects(
+        polyline,
+        boundary,
+        &FindIntersectsOptions {
+            pline1_aabb_index: Some(pline_aabb_index),
+            pos_equal_eps,
+        },
+    );
+
+    let any_overlapping = !intrs.overlapping_intersects.is_empty();
+    let split_points = sorted_split_points(
+        polyline,
+        intrs
+            .basic_intersects
+            .iter()
+            .map(|intr| (intr.start_index1, intr.point))
+            .chain(intrs.overlapping_intersects.iter().flat_map(|intr| {
+                [
+                    (intr.start_index1, intr.point1),
+                    (intr.start_index1, intr.point2),
+                ]
+            })),
+        pos_equal_eps,
+    );
+
+    // classify a part using the midpoint of its first segment
+    let is_inside = |v_data: &PlineViewData<T>| {
+        let view = v_data.view(polyline);
+        let midpoint = seg_midpoint(view.at(0), view.at(1));
+        let on_boundary = any_overlapping
+            && boundary
+                .closest_point(midpoint, pos_equal_eps)
+                .is_some_and(|r| r.distance <= pos_equal_eps);
+        on_boundary || boundary.winding_number(midpoint) != 0
+    };
+
+    let mut add_part = |v_data: PlineViewData<T>| {
+        let Some(target) = result.parts_mut(keep, is_inside(&v_data)) else {
+            return;
+        };
+        let pline = O::create_from_remove_repeat(&v_data.view(polyline), pos_equal_eps);
+        if pline.vertex_count() < 2 {
+            return;
+        }
+        target.push(ClipResultPline {
+            pline,
+            start_index: v_data.start_index,
+            end_index: polyline.fwd_wrapping_index(v_data.start_index, v_data.end_index_offset),
+        });
+    };
+
+    if polyline.is_closed() && split_points.is_empty() {
+        // no intersects, entire polyline is either inside or outside
+        let inside = is_inside(&PlineViewData::from_entire_pline(polyline));
+        if let Some(target) = result.parts_mut(keep, inside) {
+            target.push(ClipResultPline {
+                pline: O::create_from(polyline),
+                start_index: 0,
+                end_index: polyline.vertex_count() - 1,
+            });
+        }
+    } else {
+        for v_data in slices_at_split_points(polyline, &split_points, pos_equal_eps) {
+            add_part(v_data);
+        }
+    }
+
+    result
+}
+
+impl<O> ClipResult<O>
+where
+    O: PlineCreation,
+{
+    /// Returns the parts to add a clipped part to, or `None` if the part is not kept.
+    fn parts_mut(&mut self, keep: ClipKeep, inside: bool) -> Option<&mut Vec<ClipResultPline<O>>> {
+        match (keep, inside) {
+            (ClipKeep::Inside | ClipKeep::Both, true) => Some(&mut self.inside),
+            (ClipKeep::Outside | ClipKeep::Both, false) => Some(&mut self.outside),
+            _ => None,
+        }
+    }
+}
+
+/// Sorts split points along the polyline (by segment index then distance from segment start),
+/// removing repeat points. Points at the end of a segment are moved to the start of the next
+/// segment (except at the end of an open polyline).
+pub(crate) fn sorted_split_points<P, T, I>(
+    polyline: &P,
+    points: I,
+    pos_equal_eps: T,
+) -> Vec<(usize, Vector2<T>)>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    I: Iterator<Item = (usize, Vector2<T>)>,
+{
+    let last_seg_index = polyline.vertex_count() - 2;
+    let mut split_points = points
+        .map(|(index, point)| {
+            if !polyline.is_closed() && index == last_seg_index {
+                return (index, point);
+            }
+            let next_index = polyline.next_wrapping_index(index);
+            if point.fuzzy_eq_eps(polyline.at(next_index).pos(), pos_equal_eps) {
+                (next_index, point)
+            } else {
+                (index, point)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    split_points.sort_unstable_by(|(index1, point1), (index2, point2)| {
+        index1.cmp(index2).then_with(|| {
+            let start_pos = polyline.at(*index1).pos();
+            dist_squared(*point1, start_pos).total_cmp(&dist_squared(*point2, start_pos))
+        })
+    });
+    split_points.dedup_by(|(index1, point1), (index2, point2)| {
+        index1 == index2 && point1.fuzzy_eq_eps(*point2, pos_equal_eps)
+    });
+
+    split_points
+}
+
+/// Splits the polyline into slices between consecutive `split_points` (as returned by
+/// [`sorted_split_points`]). An open polyline is also split at its end points, a closed polyline
+/// with a single split point gives one slice starting and ending at that point and a closed
+/// polyline with no split points gives the entire polyline.
+pub(crate) fn slices_at_split_points<P, T>(
+    polyline: &P,
+    split_points: &[(usize, Vector2<T>)],
+    pos_equal_eps: T,
+) -> Vec<PlineViewData<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut slices = Vec::new();
+    if polyline.is_closed() {
+        match split_points.len() {
+            0 => slices.push(PlineViewData::from_entire_pline(polyline)),
+            1 => {
+                // single intersect, entire polyline path starting and ending at the intersect
+                let (index, point) = split_points[0];
+                slices.extend(PlineViewData::from_new_start(
+                    polyline,
+                    point,
+                    index,
+                    pos_equal_eps,
+                ));
+            }
+            n => {
+                for k in 0..n {
+                    let (start_index, start_point) = split_points[k];
+                    let (end_index, end_point) = split_points[(k + 1) % n];
+                    slices.extend(PlineViewData::from_slice_points(
+                        polyline,
+                        start_point,
+                        start_index,
+                        end_point,
+                        end_index,
+                        pos_equal_eps,
+                    ));
+                }
+            }
+        }
+    } else {
+        let start = (0, polyline.at(0).pos());
+        let end = (polyline.vertex_count() - 2, polyline.last().unwrap().pos());
+        let mut prev = start;
+        for &next in split_points.iter().chain(std::iter::once(&end)) {
+            slices.extend(PlineViewData::from_slice_points(
+                polyline,
+                prev.1,
+                prev.0,
+                next.1,
+                next.0,
+                pos_equal_eps,
+            ));
+            prev = next;
+        }
+    }
+    slices
+}
@@ -34,6 +34,23 @@ where
     {
         return PlineContainsResult::InvalidInput;
     }
+
+    // split arcs larger than a half circle
+    let split1 = pline1.split_large_arcs(options.pos_equal_eps);
+    let split2 = pline2.split_large_arcs(options.pos_equal_eps);
+    if split1.is_some() || split2.is_some() {
+        let split_options = PlineContainsOptions {
+            pline1_aabb_index: options.pline1_aabb_index.filter(|_| split1.is_none()),
+            ..*options
+        };
+        return match (&split1, &split2) {
+            (Some(p1), Some(p2)) => polyline_contains(p1, p2, &split_options),
+            (Some(p1), None) => polyline_contains(p1, pline2, &split_options),
+            (None, Some(p2)) => polyline_contains(pline1, p2, &split_options),
+            (None, None) => unreachable!(),
+        };
+    }
+
     let pos_equal_eps = options.pos_equal_eps;
     let constructed_index;
     let pline1_aabb_index = if let Some(x) = options.pline1_aabb_index {
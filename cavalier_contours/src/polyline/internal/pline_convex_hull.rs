@@ -0,0 +1,400 @@
+//! Convex hull of points and arc segments.
+//!
+//! Each input is an element that is either a point or a counter clockwise arc (clockwise arcs are
+//! reversed). The hull is traced counter clockwise by gift wrapping: from the current position on
+//! the current element the next hull edge is the common tangent line (with both elements on the
+//! left) that turns the least from the current direction. The tangent between two elements is the
+//! outer common tangent of their circles (points are circles with zero radius) and is only used if
+//! its end points lie within the arc sweeps. Following an arc is a tangent line of zero length to
+//! a point further along the arc, so the hull follows arcs where they are on the boundary.
+//!
+//! Points strictly inside the convex hull of all the points can never be on the hull so the points
+//! are first reduced to their (line segment) convex hull vertexes.
+
+use crate::{
+    core::{
+        math::{Vector2, angle, bulge_from_angle, normalize_radians, point_on_circle},
+        traits::Real,
+    },
+    polyline::{
+        PlineCreation, PlineSource, PlineSourceMut, PlineVertex, Polyline,
+        seg_arc_radius_and_center,
+    },
+};
+
+/// Point (zero radius) or counter clockwise arc.
+#[derive(Debug, Clone, Copy)]
+struct Element<T> {
+    center: Vector2<T>,
+    radius: T,
+    start_angle: T,
+    sweep_angle: T,
+}
+
+impl<T> Element<T>
+where
+    T: Real,
+{
+    fn point(p: Vector2<T>) -> Self {
+        Self {
+            center: p,
+            radius: T::zero(),
+            start_angle: T::zero(),
+            sweep_angle: T::zero(),
+        }
+    }
+
+    fn is_point(&self) -> bool {
+        self.radius <= T::zero()
+    }
+
+    /// Angle offset of `p` (on the circle) from the start of the arc, snapped to the start of the
+    /// arc if just before it, or `None` if `p` is not within the arc sweep.
+    fn sweep_offset(&self, p: Vector2<T>, eps: T) -> Option<T> {
+        if self.is_point() {
+            return Some(T::zero());
+        }
+
+        let angle_eps = eps / self.radius;
+        let offset = normalize_radians(angle(self.center, p) - self.start_angle);
+        if offset <= self.sweep_angle + angle_eps {
+            Some(num_traits::real::Real::min(offset, self.sweep_angle))
+        } else if offset >= T::tau() - angle_eps {
+            Some(T::zero())
+        } else {
+            None
+        }
+    }
+
+    fn start_point(&self) -> Vector2<T> {
+        point_on_circle(self.radius, self.center, self.start_angle)
+    }
+}
+
+/// Hull edge candidate leaving `from` on the current element and arriving at `to` on element
+/// `next`.
+#[derive(Debug, Clone, Copy)]
+struct Candidate<T> {
+    next: usize,
+    from: Vector2<T>,
+    to: Vector2<T>,
+    /// Angle swept along the current element (zero for points).
+    progress: T,
+    /// Direction of the hull edge.
+    direction: T,
+}
+
+/// Add the vertexes and arc segments of `pline` to the hull inputs.
+pub fn add_pline_elements<P, T>(
+    pline: &P,
+    points: &mut Vec<Vector2<T>>,
+    arcs: &mut Vec<(PlineVertex<T>, PlineVertex<T>)>,
+) where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    points.extend(pline.iter_vertexes().map(|v| v.pos()));
+    arcs.extend(pline.iter_segments().filter(|(v1, _)| !v1.bulge_is_zero()));
+}
+
+/// Returns the counter clockwise convex hull of the `points` and `arcs` (arc segments given as the
+/// start and end vertex) as a closed polyline.
+pub fn convex_hull<O, T>(
+    points: &[Vector2<T>],
+    arcs: &[(PlineVertex<T>, PlineVertex<T>)],
+    pos_equal_eps: T,
+) -> O
+where
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let eps = pos_equal_eps;
+    let mut elements = points_hull(points, eps)
+        .into_iter()
+        .map(Element::point)
+        .collect::<Vec<_>>();
+
+    for &(v1, v2) in arcs {
+        if v1.pos().fuzzy_eq_eps(v2.pos(), eps) {
+            continue;
+        }
+        let (radius, center) = seg_arc_radius_and_center(v1, v2);
+        // reverse clockwise arcs so all arcs are counter clockwise
+        let start = if v1.bulge_is_neg() { v2 } else { v1 };
+        elements.push(Element {
+            center,
+            radius,
+            start_angle: angle(center, start.pos()),
+            sweep_angle: num_traits::real::Real::abs(
+                T::four() * num_traits::real::Real::atan(v1.bulge),
+            ),
+        });
+    }
+
+    if elements.is_empty() {
+        return O::with_capacity(0, true);
+    }
+
+    // start at the lowest (then left most) point on the hull, the hull edge leaving it goes in the
+    // +x direction
+    let bottom_angle = -T::pi() / T::two();
+    let lowest = |e: &Element<T>| {
+        if e.is_point() {
+            return Some(e.center);
+        }
+        let bottom = point_on_circle(e.radius, e.center, bottom_angle);
+        e.sweep_offset(bottom, eps).map(|_| bottom)
+    };
+    let start_pos = elements
+        .iter()
+        .filter_map(lowest)
+        .min_by(|a, b| {
+            if (a.y - b.y).fuzzy_eq_eps(T::zero(), eps) {
+                a.x.total_cmp(&b.x)
+            } else {
+                a.y.total_cmp(&b.y)
+            }
+        })
+        .unwrap();
+
+    let start = if let Some(i) = elements
+        .iter()
+        .position(|e| e.is_point() && e.center.fuzzy_eq_eps(start_pos, eps))
+    {
+        i
+    } else {
+        // start point is on an arc, add it as a point to stop at
+        elements.push(Element::point(start_pos));
+        elements.len() - 1
+    };
+
+    if let Some(hull) = trace_hull(&elements, start, eps) {
+        return O::create_from(&hull);
+    }
+
+    // fall back to the hull of the points if tracing failed (should not happen)
+    let mut result = O::with_capacity(0, true);
+    for p in points_hull(points, eps) {
+        result.add(p.x, p.y, T::zero());
+    }
+    result
+}
+
+/// Trace the hull counter clockwise starting at the point element `start`, returns `None` if the
+/// trace fails to return to the start.
+fn trace_hull<T>(elements: &[Element<T>], start: usize, eps: T) -> Option<Polyline<T>>
+where
+    T: Real,
+{
+    let mut hull = Polyline::new_closed();
+    let mut current = start;
+    let mut pos = elements[start].center;
+    let mut direction = T::zero();
+    // elements visited without moving, prevents looping between elements that touch at a point
+    let mut visited_in_place = vec![start];
+
+    let max_steps = 4 * elements.len() + 8;
+    for _ in 0..max_steps {
+        let element = &elements[current];
+        let pos_offset = element.sweep_offset(pos, eps).unwrap_or_else(T::zero);
+
+        let mut best: Option<(Candidate<T>, T)> = None;
+        for (next, other) in elements.iter().enumerate() {
+            if next == current {
+                continue;
+            }
+
+            let Some(c) = tangent_candidate(element, other, next, pos, pos_offset, eps) else {
+                continue;
+            };
+
+            let length = (c.to - c.from).length();
+            let moves = length > eps || c.progress * element.radius > eps;
+            if !moves && visited_in_place.contains(&next) {
+                continue;
+            }
+
+            let mut turn = normalize_radians(c.direction - direction);
+            if turn > T::tau() - eps {
+                turn = T::zero();
+            }
+
+            let is_better = match best {
+                None => true,
+                Some((b, best_turn)) => {
+                    let b_length = (b.to - b.from).length();
+                    // turns equal if the difference in direction is within eps at the end of
+                    // the longer edge, then prefer stopping at the start or else the longer edge
+                    let max_length = num_traits::real::Real::max(length, b_length);
+                    let turn_eps = eps / num_traits::real::Real::max(max_length, eps);
+                    if (turn - best_turn).fuzzy_eq_eps(T::zero(), turn_eps) {
+                        next == start || (b.next != start && length > b_length)
+                    } else {
+                        turn < best_turn
+                    }
+                }
+            };
+
+            if is_better {
+                best = Some((c, turn));
+            }
+        }
+
+        let (c, _) = best?;
+        let arc_bulge = if c.progress * element.radius > eps {
+            bulge_from_angle(c.progress)
+        } else {
+            T::zero()
+        };
+        hull.add_or_replace_vertex(PlineVertex::from_vector2(pos, arc_bulge), eps);
+        hull.add_or_replace_vertex(PlineVertex::from_vector2(c.from, T::zero()), eps);
+
+        let moved = (c.to - c.from).length() > eps || c.progress * element.radius > eps;
+        if moved {
+            visited_in_place.clear();
+        }
+        visited_in_place.push(c.next);
+
+        current = c.next;
+        pos = c.to;
+        direction = c.direction;
+
+        if current == start {
+            if hull.vertex_count() > 1 && hull[0].pos().fuzzy_eq_eps(hull.last()?.pos(), eps) {
+                hull.remove_last();
+            }
+            return Some(hull.remove_redundant(eps).unwrap_or(hull));
+        }
+    }
+
+    None
+}
+
+/// Returns the hull edge candidate from `pos` (at `pos_offset` along `element`) to `other`.
+fn tangent_candidate<T>(
+    element: &Element<T>,
+    other: &Element<T>,
+    next: usize,
+    pos: Vector2<T>,
+    pos_offset: T,
+    eps: T,
+) -> Option<Candidate<T>>
+where
+    T: Real,
+{
+    let (from, to, direction) = if element.is_point() {
+        if other.is_point() && pos.fuzzy_eq_eps(other.center, eps) {
+            return None;
+        }
+        common_tangent(pos, T::zero(), other.center, other.radius, eps)?
+    } else if !other.is_point()
+        && element.center.fuzzy_eq_eps(other.center, eps)
+        && element.radius.fuzzy_eq_eps(other.radius, eps)
+    {
+        // arcs on the same circle connect where the other arc starts
+        let p = other.start_point();
+        let direction = other.start_angle + T::pi() / T::two();
+        (p, p, direction)
+    } else {
+        common_tangent(
+            element.center,
+            element.radius,
+            other.center,
+            other.radius,
+            eps,
+        )?
+    };
+
+    // tangent must touch the arcs within their sweeps and be at or ahead of the current position
+    let from_offset = element.sweep_offset(from, eps)?;
+    let angle_eps = if element.is_point() {
+        T::zero()
+    } else {
+        eps / element.radius
+    };
+    if from_offset < pos_offset - angle_eps {
+        return None;
+    }
+    other.sweep_offset(to, eps)?;
+
+    Some(Candidate {
+        next,
+        from,
+        to,
+        progress: num_traits::real::Real::max(from_offset - pos_offset, T::zero()),
+        direction,
+    })
+}
+
+/// Returns the outer common tangent from circle 1 to circle 2 with both circles to the left, as
+/// the tangent points on circle 1 and circle 2 and the direction angle of the tangent line. Returns
+/// `None` if the circles are concentric or one is inside the other.
+fn common_tangent<T>(
+    center1: Vector2<T>,
+    radius1: T,
+    center2: Vector2<T>,
+    radius2: T,
+    eps: T,
+) -> Option<(Vector2<T>, Vector2<T>, T)>
+where
+    T: Real,
+{
+    let v = center2 - center1;
+    let dist = v.length();
+    if dist < eps {
+        return None;
+    }
+
+    let cos = (radius2 - radius1) / dist;
+    if num_traits::real::Real::abs(cos) > T::one() + eps / dist {
+        return None;
+    }
+    let cos = num_traits::real::Real::max(num_traits::real::Real::min(cos, T::one()), -T::one());
+
+    // normal of the tangent line pointing toward the circle centers
+    let normal_angle = angle(Vector2::zero(), v) + num_traits::real::Real::acos(cos);
+    let normal = point_on_circle(T::one(), Vector2::zero(), normal_angle);
+    let p1 = center1 - normal.scale(radius1);
+    let p2 = center2 - normal.scale(radius2);
+    Some((p1, p2, normal_angle - T::pi() / T::two()))
+}
+
+/// Returns the counter clockwise convex hull vertexes of `points` (monotone chain), collinear
+/// points along the hull are excluded.
+fn points_hull<T>(points: &[Vector2<T>], eps: T) -> Vec<Vector2<T>>
+where
+    T: Real,
+{
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup_by(|a, b| a.fuzzy_eq_eps(*b, eps));
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<Vector2<T>> = Vec::with_capacity(2 * sorted.len());
+    let is_left_turn = |hull: &[Vector2<T>], p: Vector2<T>| {
+        let a = hull[hull.len() - 2];
+        let b = hull[hull.len() - 1];
+        (b - a).perp_dot(p - a) > eps * (b - a).length()
+    };
+
+    // lower hull then upper hull
+    for &p in &sorted {
+        while hull.len() >= 2 && !is_left_turn(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    let lower_len = hull.len() + 1;
+    for &p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !is_left_turn(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // last point is the first point
+    hull.pop();
+    hull
+}
@@ -0,0 +1,383 @@
+//! Filleting (rounding) and chamfering the corners of a polyline.
+//!
+//! A fillet arc center is found by offsetting the two segments adjacent to a corner toward the
+//! inside of the corner by the fillet radius and intersecting the offsets (lines offset to lines and
+//! arcs offset to circles), the fillet arc then goes between the closest points on the segments to
+//! the center. A chamfer cuts the corner between the points at the chamfer distance along each
+//! adjacent segment. Corners that do not fit (fillet tangent points not on the segments, or
+//! treatments of neighboring corners overlapping along a segment) are left sharp.
+
+use crate::{
+    core::{
+        math::{
+            CircleCircleIntr, LineCircleIntr, LineLineIntr, Vector2, angle, bulge_from_angle,
+            circle_circle_intr, delta_angle, line_circle_intr, line_line_intr,
+            point_from_parametric, point_on_circle,
+        },
+        traits::Real,
+    },
+    polyline::{
+        CornerTreatment, PlineCornerOptions, PlineCreation, PlineSource, PlineVertex,
+        dist_from_segment_start, seg_arc_radius_and_center, seg_closest_point, seg_length,
+        seg_split_at_point, seg_tangent_vector,
+    },
+};
+
+/// Corner replaced by a fillet arc or chamfer line from `in_point` (on the incoming segment) to
+/// `out_point` (on the outgoing segment).
+#[derive(Debug, Clone, Copy)]
+struct TreatedCorner<T> {
+    in_point: Vector2<T>,
+    out_point: Vector2<T>,
+    /// Bulge of the segment from `in_point` to `out_point`.
+    bulge: T,
+    /// Length trimmed from the end of the incoming segment.
+    in_trim: T,
+    /// Length trimmed from the start of the outgoing segment.
+    out_trim: T,
+}
+
+/// Returns a new polyline with the corners treated (filleted or chamfered) as given.
+pub fn treat_corners<P, O, T>(
+    polyline: &P,
+    treatment: CornerTreatment<T>,
+    options: &PlineCornerOptions<T>,
+) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let vc = polyline.vertex_count();
+    if vc < 3 && !(polyline.is_closed() && vc == 2) {
+        return O::create_from(polyline);
+    }
+
+    let eps = options.pos_equal_eps;
+    let mut corners = (0..vc)
+        .map(|i| {
+            if !polyline.is_closed() && (i == 0 || i == vc - 1) {
+                return None;
+            }
+
+            let prev = polyline.at(polyline.prev_wrapping_index(i));
+            let v = polyline.at(i);
+            let next = polyline.at(polyline.next_wrapping_index(i));
+            let treatment = options
+                .vertex_treatments
+                .and_then(|t| t.get(i).copied())
+                .unwrap_or(treatment);
+            match treatment {
+                CornerTreatment::Sharp => None,
+                CornerTreatment::Fillet { radius } => fillet_corner(prev, v, next, radius, eps),
+                CornerTreatment::Chamfer { distance } => {
+                    chamfer_corner(prev, v, next, distance, eps)
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // leave corners sharp where the treatments of neighboring corners overlap along a segment
+    for i in 0..polyline.segment_count() {
+        let j = polyline.next_wrapping_index(i);
+        let start_trim = corners[i].map_or(T::zero(), |c| c.out_trim);
+        let end_trim = corners[j].map_or(T::zero(), |c| c.in_trim);
+        if start_trim + end_trim > seg_length(polyline.at(i), polyline.at(j)) + eps {
+            corners[i] = None;
+            corners[j] = None;
+        }
+    }
+
+    let mut result = O::with_capacity(vc, polyline.is_closed());
+    result.set_userdata_values(polyline.get_userdata_values());
+    for i in 0..vc {
+        let v = polyline.at(i);
+        let seg_bulge = if i < polyline.segment_count() {
+            let j = polyline.next_wrapping_index(i);
+            trimmed_seg_bulge(v, polyline.at(j), corners[i], corners[j], eps)
+        } else {
+            T::zero()
+        };
+
+        // add_or_replace_vertex removes zero length segments where trims meet
+        match corners[i] {
+            Some(c) => {
+                result.add_or_replace_vertex(PlineVertex::from_vector2(c.in_point, c.bulge), eps);
+                result
+                    .add_or_replace_vertex(PlineVertex::from_vector2(c.out_point, seg_bulge), eps);
+            }
+            None => result.add_or_replace_vertex(v.with_bulge(seg_bulge), eps),
+        }
+    }
+
+    if result.is_closed()
+        && result.vertex_count() > 1
+        && result
+            .at(0)
+            .pos()
+            .fuzzy_eq_eps(result.last().unwrap().pos(), eps)
+    {
+        result.remove_last();
+    }
+
+    result
+}
+
+/// Returns the unit tangent vectors of the segments into and out of the corner at `v`, or `None`
+/// if there is no corner (segments are tangent, reverse direction, or have zero length).
+fn corner_tangents<T>(
+    prev: PlineVertex<T>,
+    v: PlineVertex<T>,
+    next: PlineVertex<T>,
+    eps: T,
+) -> Option<(Vector2<T>, Vector2<T>)>
+where
+    T: Real,
+{
+    if prev.pos().fuzzy_eq_eps(v.pos(), eps) || v.pos().fuzzy_eq_eps(next.pos(), eps) {
+        return None;
+    }
+
+    let t_in = seg_tangent_vector(prev, v, v.pos()).normalize();
+    let t_out = seg_tangent_vector(v, next, v.pos()).normalize();
+    if num_traits::real::Real::abs(t_in.perp_dot(t_out)) < eps {
+        return None;
+    }
+
+    Some((t_in, t_out))
+}
+
+/// Segment offset toward the inside of a corner, lines offset to lines and arcs to circles.
+#[derive(Debug, Clone, Copy)]
+enum OffsetSeg<T> {
+    Line(Vector2<T>, Vector2<T>),
+    Circle(T, Vector2<T>),
+}
+
+/// Offset the segment from `v1` to `v2` by `distance` to the left (or right if `left` is false).
+fn offset_seg<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    distance: T,
+    left: bool,
+    eps: T,
+) -> Option<OffsetSeg<T>>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let normal = (v2.pos() - v1.pos()).unit_perp();
+        let offset = if left {
+            normal.scale(distance)
+        } else {
+            normal.scale(-distance)
+        };
+        return Some(OffsetSeg::Line(v1.pos() + offset, v2.pos() + offset));
+    }
+
+    // arc center is to the left of counter clockwise arcs
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let offset_radius = if left == v1.bulge_is_pos() {
+        radius - distance
+    } else {
+        radius + distance
+    };
+
+    (offset_radius > eps).then_some(OffsetSeg::Circle(offset_radius, center))
+}
+
+/// Returns the intersect between the offset segments closest to `point`.
+fn closest_intersect<T>(
+    seg1: OffsetSeg<T>,
+    seg2: OffsetSeg<T>,
+    point: Vector2<T>,
+    eps: T,
+) -> Option<Vector2<T>>
+where
+    T: Real,
+{
+    let line_circle = |p0, p1, radius, center| match line_circle_intr(p0, p1, radius, center, eps) {
+        LineCircleIntr::NoIntersect => Vec::new(),
+        LineCircleIntr::TangentIntersect { t0 } => vec![point_from_parametric(p0, p1, t0)],
+        LineCircleIntr::TwoIntersects { t0, t1 } => vec![
+            point_from_parametric(p0, p1, t0),
+            point_from_parametric(p0, p1, t1),
+        ],
+    };
+
+    let intersects = match (seg1, seg2) {
+        (OffsetSeg::Line(a0, a1), OffsetSeg::Line(b0, b1)) => {
+            match line_line_intr(a0, a1, b0, b1, eps) {
+                LineLineIntr::TrueIntersect { seg1_t, .. }
+                | LineLineIntr::FalseIntersect { seg1_t, .. } => {
+                    vec![point_from_parametric(a0, a1, seg1_t)]
+                }
+                LineLineIntr::NoIntersect | LineLineIntr::Overlapping { .. } => Vec::new(),
+            }
+        }
+        (OffsetSeg::Line(p0, p1), OffsetSeg::Circle(radius, center))
+        | (OffsetSeg::Circle(radius, center), OffsetSeg::Line(p0, p1)) => {
+            line_circle(p0, p1, radius, center)
+        }
+        (OffsetSeg::Circle(r1, c1), OffsetSeg::Circle(r2, c2)) => {
+            match circle_circle_intr(r1, c1, r2, c2, eps) {
+                CircleCircleIntr::TangentIntersect { point } => vec![point],
+                CircleCircleIntr::TwoIntersects { point1, point2 } => vec![point1, point2],
+                CircleCircleIntr::NoIntersect | CircleCircleIntr::Overlapping => Vec::new(),
+            }
+        }
+    };
+
+    intersects.into_iter().min_by(|a, b| {
+        (*a - point)
+            .length_squared()
+            .total_cmp(&(*b - point).length_squared())
+    })
+}
+
+/// Returns the point where a circle centered at `center` with `radius` touches the segment from
+/// `v1` to `v2`, or `None` if it is not tangent to the segment.
+fn tangent_point<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    center: Vector2<T>,
+    radius: T,
+    eps: T,
+) -> Option<Vector2<T>>
+where
+    T: Real,
+{
+    let point = seg_closest_point(v1, v2, center, eps);
+    let dist = (point - center).length();
+    (num_traits::real::Real::abs(dist - radius) < eps).then_some(point)
+}
+
+/// Fillet the corner at `v` with an arc of `radius`.
+fn fillet_corner<T>(
+    prev: PlineVertex<T>,
+    v: PlineVertex<T>,
+    next: PlineVertex<T>,
+    radius: T,
+    eps: T,
+) -> Option<TreatedCorner<T>>
+where
+    T: Real,
+{
+    if !radius.is_finite() || radius < eps {
+        return None;
+    }
+
+    let (t_in, t_out) = corner_tangents(prev, v, next, eps)?;
+    // fillet center is on the inside of the turn
+    let turns_left = t_in.perp_dot(t_out) > T::zero();
+    let in_offset = offset_seg(prev, v, radius, turns_left, eps)?;
+    let out_offset = offset_seg(v, next, radius, turns_left, eps)?;
+    let center = closest_intersect(in_offset, out_offset, v.pos(), eps)?;
+
+    let in_point = tangent_point(prev, v, center, radius, eps)?;
+    let out_point = tangent_point(v, next, center, radius, eps)?;
+    let bulge = bulge_from_angle(delta_angle(
+        angle(center, in_point),
+        angle(center, out_point),
+    ));
+
+    Some(TreatedCorner {
+        in_point,
+        out_point,
+        bulge,
+        in_trim: seg_length(prev, v) - dist_from_segment_start(prev, v, in_point, eps),
+        out_trim: dist_from_segment_start(v, next, out_point, eps),
+    })
+}
+
+/// Returns the point at `distance` along the segment from `v1` to `v2`, measured from `v2` if
+/// `from_end` is true or from `v1` otherwise.
+fn point_at_distance<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    distance: T,
+    from_end: bool,
+) -> Vector2<T>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let dir = (v2.pos() - v1.pos()).normalize();
+        return if from_end {
+            v2.pos() - dir.scale(distance)
+        } else {
+            v1.pos() + dir.scale(distance)
+        };
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    // angle increases along counter clockwise arcs
+    let sweep = if v1.bulge_is_pos() {
+        distance / radius
+    } else {
+        -distance / radius
+    };
+    if from_end {
+        point_on_circle(radius, center, angle(center, v2.pos()) - sweep)
+    } else {
+        point_on_circle(radius, center, angle(center, v1.pos()) + sweep)
+    }
+}
+
+/// Chamfer the corner at `v` at `distance` along the adjacent segments.
+fn chamfer_corner<T>(
+    prev: PlineVertex<T>,
+    v: PlineVertex<T>,
+    next: PlineVertex<T>,
+    distance: T,
+    eps: T,
+) -> Option<TreatedCorner<T>>
+where
+    T: Real,
+{
+    if !distance.is_finite() || distance < eps || corner_tangents(prev, v, next, eps).is_none() {
+        return None;
+    }
+
+    if distance > seg_length(prev, v) + eps || distance > seg_length(v, next) + eps {
+        return None;
+    }
+
+    Some(TreatedCorner {
+        in_point: point_at_distance(prev, v, distance, true),
+        out_point: point_at_distance(v, next, distance, false),
+        bulge: T::zero(),
+        in_trim: distance,
+        out_trim: distance,
+    })
+}
+
+/// Returns the bulge of the segment from `v1` to `v2` after trimming for the corners treated at
+/// each end.
+fn trimmed_seg_bulge<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    start_corner: Option<TreatedCorner<T>>,
+    end_corner: Option<TreatedCorner<T>>,
+    eps: T,
+) -> T
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        return T::zero();
+    }
+
+    let start = match start_corner {
+        Some(c) => seg_split_at_point(v1, v2, c.out_point, eps).split_vertex,
+        None => v1,
+    };
+    match end_corner {
+        Some(c) => {
+            seg_split_at_point(start, v2, c.in_point, eps)
+                .updated_start
+                .bulge
+        }
+        None => start.bulge,
+    }
+}
@@ -0,0 +1,696 @@
+//! Minimum distance and Hausdorff distance between polylines.
+//!
+//! The minimum distance between two segments that do not intersect is found at an end point of
+//! one of the segments or at a pair of interior points where the line between them is normal to
+//! both segments. For an arc the normal passes through the arc center so the interior candidates
+//! are the arc points in the direction normal to the other line segment or along the line through
+//! both arc centers. Segment pairs are pruned using a spatial index of the other polyline with the
+//! query box expanded by the current minimum distance.
+//!
+//! The one-sided Hausdorff distance is the maximum over all points of the polyline of the distance
+//! to the other polyline. It is found by best-first branch and bound over pieces of the segments:
+//! the distance is evaluated exactly (arcs are not approximated) at piece end points to get a
+//! lower bound and each piece has an upper bound on the distance over its interior, pieces are
+//! split in half until no upper bound exceeds the lower bound by more than the tolerance. Upper
+//! bounds use the segments of the other polyline closest to the piece end points: the distance to
+//! a line segment is convex so it is bounded by the chord end points (plus the piece sagitta), the
+//! distance to an arc from points within its sweep is the difference between the distance to the
+//! center and the radius which is bounded by the closest and farthest piece points from the
+//! center, otherwise the distance is bounded using it being 1-Lipschitz along the piece.
+
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use static_aabb2d_index::{Control as AabbControl, NeighborPriorityQueue, StaticAABB2DIndex};
+
+use crate::{
+    core::{
+        math::{
+            Vector2, angle, angle_from_bulge, bulge_from_angle, point_on_circle,
+            point_within_arc_sweep,
+        },
+        traits::Real,
+    },
+    polyline::{
+        PlineDistanceOptions, PlineDistanceResult, PlineSegIntr, PlineSource, PlineVertex,
+        pline_error::check_finite_vertexes, pline_seg_intr, seg_arc_radius_and_center,
+        seg_closest_point, seg_fast_approx_bounding_box, seg_length,
+    },
+};
+
+/// Returns `v1` with bulge set to zero if the segment from `v1` to `v2` has coincident end points
+/// (avoids computing an arc center for a collapsed arc).
+fn seg_start<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, pos_equal_eps: T) -> PlineVertex<T>
+where
+    T: Real,
+{
+    if !v1.bulge_is_zero() && v1.pos().fuzzy_eq_eps(v2.pos(), pos_equal_eps) {
+        v1.with_bulge(T::zero())
+    } else {
+        v1
+    }
+}
+
+/// Interior points on the arc segment `a1` to `a2` where the arc normal is also normal to the
+/// segment `b1` to `b2` (candidates for the closest points between the segments).
+fn normal_candidates<T>(
+    a1: PlineVertex<T>,
+    a2: PlineVertex<T>,
+    b1: PlineVertex<T>,
+    b2: PlineVertex<T>,
+    pos_equal_eps: T,
+) -> impl Iterator<Item = Vector2<T>>
+where
+    T: Real,
+{
+    let directions = if a1.bulge_is_zero() {
+        None
+    } else {
+        let (radius, center) = seg_arc_radius_and_center(a1, a2);
+        let dir = if b1.bulge_is_zero() {
+            (b2.pos() - b1.pos()).perp()
+        } else {
+            let (_, b_center) = seg_arc_radius_and_center(b1, b2);
+            b_center - center
+        };
+
+        // concentric arcs or collapsed line have no single normal direction (end points cover it)
+        (dir.length() > pos_equal_eps).then(|| {
+            let dir = dir.normalize().scale(radius);
+            (center, [center + dir, center - dir])
+        })
+    };
+
+    directions.into_iter().flat_map(move |(center, points)| {
+        points.into_iter().filter(move |&p| {
+            point_within_arc_sweep(
+                center,
+                a1.pos(),
+                a2.pos(),
+                a1.bulge_is_neg(),
+                p,
+                pos_equal_eps,
+            )
+        })
+    })
+}
+
+/// Closest points between the segment `v1` to `v2` and the segment `u1` to `u2`, returns the
+/// distance and the points on each segment.
+pub fn seg_closest_points<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    u1: PlineVertex<T>,
+    u2: PlineVertex<T>,
+    pos_equal_eps: T,
+) -> (T, Vector2<T>, Vector2<T>)
+where
+    T: Real,
+{
+    let v1 = seg_start(v1, v2, pos_equal_eps);
+    let u1 = seg_start(u1, u2, pos_equal_eps);
+
+    match pline_seg_intr(v1, v2, u1, u2, pos_equal_eps) {
+        PlineSegIntr::NoIntersect => {}
+        PlineSegIntr::TangentIntersect { point } | PlineSegIntr::OneIntersect { point } => {
+            return (T::zero(), point, point);
+        }
+        PlineSegIntr::TwoIntersects { point1, .. }
+        | PlineSegIntr::OverlappingLines { point1, .. }
+        | PlineSegIntr::OverlappingArcs { point1, .. } => {
+            return (T::zero(), point1, point1);
+        }
+    }
+
+    let on_v = |p| seg_closest_point(v1, v2, p, pos_equal_eps);
+    let on_u = |p| seg_closest_point(u1, u2, p, pos_equal_eps);
+    let candidates = [v1.pos(), v2.pos()]
+        .into_iter()
+        .chain(normal_candidates(v1, v2, u1, u2, pos_equal_eps))
+        .map(|p| (p, on_u(p)))
+        .chain(
+            [u1.pos(), u2.pos()]
+                .into_iter()
+                .chain(normal_candidates(u1, u2, v1, v2, pos_equal_eps))
+                .map(|q| (on_v(q), q)),
+        );
+
+    let mut result = (T::zero(), v1.pos(), on_u(v1.pos()));
+    result.0 = (result.2 - result.1).length();
+    for (p, q) in candidates {
+        let dist = (q - p).length();
+        if dist < result.0 {
+            result = (dist, p, q);
+        }
+    }
+
+    result
+}
+
+/// Closest point on a polyline with its segment index and distance.
+#[derive(Debug, Clone, Copy)]
+struct Nearest<T> {
+    distance: T,
+    seg_index: usize,
+    point: Vector2<T>,
+}
+
+/// Polyline with its spatial index for finding closest points.
+struct IndexedPline<'a, P, T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    pline: &'a P,
+    index: &'a StaticAABB2DIndex<T>,
+    pos_equal_eps: T,
+}
+
+impl<P, T> IndexedPline<'_, P, T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    fn segment(&self, i: usize) -> (PlineVertex<T>, PlineVertex<T>) {
+        (
+            self.pline.at(i),
+            self.pline.at(self.pline.next_wrapping_index(i)),
+        )
+    }
+
+    /// Closest point on the polyline to `point` (polyline must not be empty).
+    fn nearest(&self, point: Vector2<T>, queue: &mut NeighborPriorityQueue<T>) -> Nearest<T> {
+        if self.pline.vertex_count() == 1 {
+            let p = self.pline.at(0).pos();
+            return Nearest {
+                distance: (p - point).length(),
+                seg_index: 0,
+                point: p,
+            };
+        }
+
+        let mut best: Option<Nearest<T>> = None;
+        let mut visitor = |i: usize, dist_squared: T| {
+            if let Some(b) = best
+                && dist_squared > b.distance * b.distance
+            {
+                return AabbControl::Break(());
+            }
+
+            let (v1, v2) = self.segment(i);
+            let cp = seg_closest_point(v1, v2, point, self.pos_equal_eps);
+            let distance = (cp - point).length();
+            if best.is_none_or(|b| distance < b.distance) {
+                best = Some(Nearest {
+                    distance,
+                    seg_index: i,
+                    point: cp,
+                });
+            }
+            AabbControl::Continue
+        };
+        self.index
+            .visit_neighbors_with_queue(point.x, point.y, &mut visitor, queue);
+
+        best.unwrap()
+    }
+}
+
+/// Returns true if both `pline` and `other` have vertexes and all their vertexes are finite.
+fn valid_inputs<P, O, T>(pline: &P, other: &O) -> bool
+where
+    P: PlineSource<Num = T> + ?Sized,
+    O: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    !pline.is_empty()
+        && !other.is_empty()
+        && check_finite_vertexes(pline, 0).is_ok()
+        && check_finite_vertexes(other, 1).is_ok()
+}
+
+/// Compute the minimum distance between `pline` and `other`.
+///
+/// See [`PlineSource::min_distance_opt`] for more information.
+pub fn min_distance<P, O, T>(
+    pline: &P,
+    other: &O,
+    options: &PlineDistanceOptions<T>,
+) -> Option<PlineDistanceResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    O: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if !valid_inputs(pline, other) {
+        return None;
+    }
+
+    let eps = options.pos_equal_eps;
+    let constructed_index;
+    let other_index = if let Some(x) = options.pline2_aabb_index {
+        x
+    } else {
+        constructed_index = other.create_approx_aabb_index();
+        &constructed_index
+    };
+    let indexed = IndexedPline {
+        pline: other,
+        index: other_index,
+        pos_equal_eps: eps,
+    };
+
+    // start with the closest point to the first vertex
+    let mut queue = NeighborPriorityQueue::new();
+    let first = pline.at(0).pos();
+    let nearest = indexed.nearest(first, &mut queue);
+    let mut result = PlineDistanceResult {
+        distance: nearest.distance,
+        point: first,
+        seg_index: 0,
+        other_point: nearest.point,
+        other_seg_index: nearest.seg_index,
+    };
+
+    if pline.vertex_count() == 1 || other.vertex_count() == 1 {
+        if other.vertex_count() == 1 {
+            // closest point on pline to the single vertex of other
+            let p = other.at(0).pos();
+            if let Some(cp) = pline.closest_point(p, eps) {
+                result = PlineDistanceResult {
+                    distance: cp.distance,
+                    point: cp.seg_point,
+                    seg_index: cp.seg_start_index,
+                    other_point: p,
+                    other_seg_index: 0,
+                };
+            }
+        }
+        return Some(result);
+    }
+
+    let mut stack = Vec::with_capacity(8);
+    for (i, (v1, v2)) in pline.iter_segments().enumerate() {
+        if result.distance.fuzzy_eq_zero_eps(eps) {
+            break;
+        }
+
+        let bb = seg_fast_approx_bounding_box(v1, v2);
+        let d = result.distance;
+        let mut visitor = |j: usize| {
+            let (u1, u2) = indexed.segment(j);
+            let (distance, point, other_point) = seg_closest_points(v1, v2, u1, u2, eps);
+            if distance < result.distance {
+                result = PlineDistanceResult {
+                    distance,
+                    point,
+                    seg_index: i,
+                    other_point,
+                    other_seg_index: j,
+                };
+            }
+        };
+        other_index.visit_query_with_stack(
+            bb.min_x - d,
+            bb.min_y - d,
+            bb.max_x + d,
+            bb.max_y + d,
+            &mut visitor,
+            &mut stack,
+        );
+    }
+
+    Some(result)
+}
+
+/// Piece of a polyline segment (parametric range of the segment) in the Hausdorff distance branch
+/// and bound, ordered by upper bound so the largest is popped first from a [`BinaryHeap`].
+struct Piece<T> {
+    seg_index: usize,
+    t0: T,
+    t1: T,
+    start: (Vector2<T>, Nearest<T>),
+    end: (Vector2<T>, Nearest<T>),
+    upper_bound: T,
+}
+
+impl<T: Real> PartialEq for Piece<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Real> Eq for Piece<T> {}
+
+impl<T: Real> PartialOrd for Piece<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Real> Ord for Piece<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.upper_bound.total_cmp(&other.upper_bound)
+    }
+}
+
+/// Segment of the polyline being measured in the Hausdorff distance with its arc properties.
+#[derive(Clone, Copy)]
+struct SourceSeg<T> {
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    /// Arc center, radius, start angle, and sweep angle (`None` for a line segment).
+    arc: Option<(Vector2<T>, T, T, T)>,
+}
+
+impl<T> SourceSeg<T>
+where
+    T: Real,
+{
+    fn new(v1: PlineVertex<T>, v2: PlineVertex<T>, pos_equal_eps: T) -> Self {
+        let v1 = seg_start(v1, v2, pos_equal_eps);
+        let arc = (!v1.bulge_is_zero()).then(|| {
+            let (radius, center) = seg_arc_radius_and_center(v1, v2);
+            (
+                center,
+                radius,
+                angle(center, v1.pos()),
+                angle_from_bulge(v1.bulge),
+            )
+        });
+        Self { v1, v2, arc }
+    }
+
+    fn point_at(&self, t: T) -> Vector2<T> {
+        match self.arc {
+            Some((center, radius, start_angle, sweep)) => {
+                point_on_circle(radius, center, start_angle + sweep * t)
+            }
+            None => self.v1.pos() + (self.v2.pos() - self.v1.pos()).scale(t),
+        }
+    }
+
+    /// Piece of the segment from parameter `t0` at `p0` to parameter `t1` at `p1` as segment
+    /// vertexes.
+    fn piece(
+        &self,
+        t0: T,
+        t1: T,
+        p0: Vector2<T>,
+        p1: Vector2<T>,
+    ) -> (PlineVertex<T>, PlineVertex<T>) {
+        let bulge = match self.arc {
+            Some((_, _, _, sweep)) => bulge_from_angle(sweep * (t1 - t0)),
+            None => T::zero(),
+        };
+        (
+            PlineVertex::from_vector2(p0, bulge),
+            PlineVertex::from_vector2(p1, T::zero()),
+        )
+    }
+}
+
+/// Farthest distance from `point` to the segment `w1` to `w2`.
+fn seg_farthest_dist<T>(
+    w1: PlineVertex<T>,
+    w2: PlineVertex<T>,
+    point: Vector2<T>,
+    pos_equal_eps: T,
+) -> T
+where
+    T: Real,
+{
+    let end_points =
+        num_traits::real::Real::max((w1.pos() - point).length(), (w2.pos() - point).length());
+    if w1.bulge_is_zero() || w1.pos().fuzzy_eq_eps(w2.pos(), pos_equal_eps) {
+        return end_points;
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(w1, w2);
+    let dir = center - point;
+    if dir.length() < pos_equal_eps {
+        return radius;
+    }
+
+    let farthest = center + dir.normalize().scale(radius);
+    if point_within_arc_sweep(
+        center,
+        w1.pos(),
+        w2.pos(),
+        w1.bulge_is_neg(),
+        farthest,
+        pos_equal_eps,
+    ) {
+        (farthest - point).length()
+    } else {
+        end_points
+    }
+}
+
+/// Upper bound on the distance from any point on the piece `w1` to `w2` (with path length
+/// `length` and `sagitta`) to the segment `u1` to `u2`.
+fn piece_upper_bound<T>(
+    (w1, w2): (PlineVertex<T>, PlineVertex<T>),
+    length: T,
+    sagitta: T,
+    (u1, u2): (PlineVertex<T>, PlineVertex<T>),
+    pos_equal_eps: T,
+) -> T
+where
+    T: Real,
+{
+    let dist = |p| (seg_closest_point(u1, u2, p, pos_equal_eps) - p).length();
+    let d0 = dist(w1.pos());
+    let d1 = dist(w2.pos());
+    let lipschitz = (d0 + d1 + length) / T::two();
+
+    let u1 = seg_start(u1, u2, pos_equal_eps);
+    if u1.bulge_is_zero() {
+        // distance to a line segment is convex, bounded along the chord
+        return num_traits::real::Real::min(
+            lipschitz,
+            num_traits::real::Real::max(d0, d1) + sagitta,
+        );
+    }
+
+    if num_traits::real::Real::abs(u1.bulge) > T::one() {
+        return lipschitz;
+    }
+
+    // the piece is within the sweep of the arc if its end points and the apex of its tangents are
+    let (radius, center) = seg_arc_radius_and_center(u1, u2);
+    let in_sweep = |p| {
+        point_within_arc_sweep(
+            center,
+            u1.pos(),
+            u2.pos(),
+            u1.bulge_is_neg(),
+            p,
+            pos_equal_eps,
+        )
+    };
+    let mut apex = None;
+    if !w1.bulge_is_zero() {
+        let chord = w2.pos() - w1.pos();
+        let b = w1.bulge;
+        // tan(sweep / 2) from bulge = tan(sweep / 4)
+        let tan_half_sweep = T::two() * b / (T::one() - b * b);
+        let midpoint = w1.pos() + chord.scale(T::one() / T::two());
+        apex = Some(midpoint - chord.perp().scale(tan_half_sweep / T::two()));
+    }
+
+    if !(in_sweep(w1.pos()) && in_sweep(w2.pos()) && apex.is_none_or(in_sweep)) {
+        return lipschitz;
+    }
+
+    let closest = (seg_closest_point(w1, w2, center, pos_equal_eps) - center).length();
+    let farthest = seg_farthest_dist(w1, w2, center, pos_equal_eps);
+    let in_sweep_bound = num_traits::real::Real::max(farthest - radius, radius - closest);
+    num_traits::real::Real::min(lipschitz, in_sweep_bound)
+}
+
+/// Compute the one-sided Hausdorff distance from `pline` to `other`.
+///
+/// See [`PlineSource::hausdorff_distance_opt`] for more information.
+pub fn hausdorff_distance<P, O, T>(
+    pline: &P,
+    other: &O,
+    options: &PlineDistanceOptions<T>,
+) -> Option<PlineDistanceResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    O: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if !valid_inputs(pline, other) {
+        return None;
+    }
+
+    let eps = options.pos_equal_eps;
+    let tolerance = num_traits::real::Real::abs(options.hausdorff_tolerance);
+    let constructed_index;
+    let other_index = if let Some(x) = options.pline2_aabb_index {
+        x
+    } else {
+        constructed_index = other.create_approx_aabb_index();
+        &constructed_index
+    };
+    let indexed = IndexedPline {
+        pline: other,
+        index: other_index,
+        pos_equal_eps: eps,
+    };
+
+    let mut queue = NeighborPriorityQueue::new();
+    let mut nearest = |p| indexed.nearest(p, &mut queue);
+
+    let first = pline.at(0).pos();
+    let first_nearest = nearest(first);
+    let mut result = PlineDistanceResult {
+        distance: first_nearest.distance,
+        point: first,
+        seg_index: 0,
+        other_point: first_nearest.point,
+        other_seg_index: first_nearest.seg_index,
+    };
+
+    let update_result =
+        |seg_index, (p, n): (Vector2<T>, Nearest<T>), result: &mut PlineDistanceResult<T>| {
+            if n.distance > result.distance {
+                *result = PlineDistanceResult {
+                    distance: n.distance,
+                    point: p,
+                    seg_index,
+                    other_point: n.point,
+                    other_seg_index: n.seg_index,
+                };
+            }
+        };
+
+    let segs = pline
+        .iter_segments()
+        .map(|(v1, v2)| SourceSeg::new(v1, v2, eps))
+        .collect::<Vec<_>>();
+
+    let upper_bound = |seg: &SourceSeg<T>,
+                       t0,
+                       t1,
+                       start: (Vector2<T>, Nearest<T>),
+                       end: (Vector2<T>, Nearest<T>)| {
+        let piece = seg.piece(t0, t1, start.0, end.0);
+        let length = seg_length(piece.0, piece.1);
+        let sagitta =
+            num_traits::real::Real::abs(piece.0.bulge) * (end.0 - start.0).length() / T::two();
+        let lipschitz = (start.1.distance + end.1.distance + length) / T::two();
+        [start.1.seg_index, end.1.seg_index]
+            .into_iter()
+            .filter(|_| other.vertex_count() > 1)
+            .map(|j| piece_upper_bound(piece, length, sagitta, indexed.segment(j), eps))
+            .fold(lipschitz, num_traits::real::Real::min)
+    };
+
+    // initial pieces, arcs split into pieces sweeping at most a quarter circle
+    let mut heap = BinaryHeap::new();
+    for (i, seg) in segs.iter().enumerate() {
+        let count = match seg.arc {
+            Some((_, _, _, sweep)) => num_traits::real::Real::ceil(
+                num_traits::real::Real::abs(sweep) / (T::pi() / T::two()),
+            )
+            .to_usize()
+            .unwrap_or(1)
+            .max(1),
+            None => 1,
+        };
+
+        let mut start = (seg.v1.pos(), nearest(seg.v1.pos()));
+        update_result(i, start, &mut result);
+        for k in 1..=count {
+            let t0 = T::from(k - 1).unwrap() / T::from(count).unwrap();
+            let t1 = T::from(k).unwrap() / T::from(count).unwrap();
+            let p1 = if k == count {
+                seg.v2.pos()
+            } else {
+                seg.point_at(t1)
+            };
+            let end = (p1, nearest(p1));
+            update_result(i, end, &mut result);
+            heap.push(Piece {
+                seg_index: i,
+                t0,
+                t1,
+                start,
+                end,
+                upper_bound: upper_bound(seg, t0, t1, start, end),
+            });
+            start = end;
+        }
+    }
+
+    while let Some(piece) = heap.pop() {
+        if piece.upper_bound <= result.distance + tolerance {
+            break;
+        }
+
+        if (piece.end.0 - piece.start.0).length() < eps {
+            continue;
+        }
+
+        let seg = &segs[piece.seg_index];
+        let tm = (piece.t0 + piece.t1) / T::two();
+        let pm = seg.point_at(tm);
+        let mid = (pm, nearest(pm));
+        update_result(piece.seg_index, mid, &mut result);
+
+        for (t0, t1, start, end) in [
+            (piece.t0, tm, piece.start, mid),
+            (tm, piece.t1, mid, piece.end),
+        ] {
+            let upper_bound = upper_bound(seg, t0, t1, start, end);
+            if upper_bound > result.distance + tolerance {
+                heap.push(Piece {
+                    seg_index: piece.seg_index,
+                    t0,
+                    t1,
+                    start,
+                    end,
+                    upper_bound,
+                });
+            }
+        }
+    }
+
+    Some(result)
+}
+
+/// Compute the symmetric Hausdorff distance between `pline` and `other`.
+///
+/// See [`PlineSource::symmetric_hausdorff_distance_opt`] for more information.
+pub fn symmetric_hausdorff_distance<P, O, T>(
+    pline: &P,
+    other: &O,
+    options: &PlineDistanceOptions<T>,
+) -> Option<PlineDistanceResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    O: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let forward = hausdorff_distance(pline, other, options)?;
+    let reverse_options = PlineDistanceOptions {
+        pline1_aabb_index: options.pline2_aabb_index,
+        pline2_aabb_index: options.pline1_aabb_index,
+        ..*options
+    };
+    let reverse = hausdorff_distance(other, pline, &reverse_options)?;
+    if reverse.distance > forward.distance {
+        // swap so point is still on pline
+        Some(PlineDistanceResult {
+            distance: reverse.distance,
+            point: reverse.other_point,
+            seg_index: reverse.other_seg_index,
+            other_point: reverse.point,
+            other_seg_index: reverse.seg_index,
+        })
+    } else {
+        Some(forward)
+    }
+}
@@ -0,0 +1,204 @@
+//! Resolving self intersecting closed polylines into non-self-intersecting loops using a fill rule.
+//!
+//! The polyline is sliced at all of its self intersects. Each slice separates the area to its left
+//! from the area to its right, the winding number is sampled on both sides of each slice and the
+//! slice is kept only if the fill rule gives a filled area on exactly one side (inverting the slice
+//! so the filled area is on its left). The kept slices are then stitched together into closed
+//! loops, counter clockwise loops bound filled area and clockwise loops bound holes.
+
+use static_aabb2d_index::StaticAABB2DIndex;
+
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{
+        FillRule, PlineBasicIntersect, PlineCreation, PlineFillOptions, PlineOrientation,
+        PlineSource, PlineViewData, seg_midpoint, seg_tangent_vector,
+    },
+};
+
+use super::{
+    pline_boolean::{ProcessForBooleanResult, slice_at_intersects, stitch_slices},
+    pline_intersects::all_self_intersects_as_basic,
+};
+
+impl FillRule {
+    /// Returns true if the area with the `winding` number given is filled according to the rule.
+    #[inline]
+    #[must_use]
+    pub fn is_filled(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// Resolve a closed polyline into non-self-intersecting closed polylines that bound the area filled
+/// according to `fill_rule`.
+///
+/// Result polylines that bound filled area are counter clockwise and polylines that bound holes
+/// are clockwise. Returns an empty vector if the polyline is open, has less than 2 vertexes, or
+/// has no filled area.
+pub fn resolve_self_intersects<P, O, T>(
+    polyline: &P,
+    fill_rule: FillRule,
+    options: &PlineFillOptions<T>,
+) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    if polyline.vertex_count() < 2 || !polyline.is_closed() {
+        return Vec::new();
+    }
+
+    if let Some(split) = polyline.split_large_arcs(options.pos_equal_eps) {
+        let split_options = PlineFillOptions {
+            aabb_index: None,
+            ..*options
+        };
+        return resolve_self_intersects(&split, fill_rule, &split_options);
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+
+    let constructed_index;
+    let aabb_index = if let Some(x) = options.aabb_index {
+        x
+    } else {
+        constructed_index = polyline.create_approx_aabb_index();
+        &constructed_index
+    };
+
+    let slices = filled_boundary_slices(polyline, fill_rule, aabb_index, pos_equal_eps);
+    stitch_filled_slices(polyline, &slices, pos_equal_eps, options.collapsed_area_eps)
+}
+
+/// Slices the polyline at its self intersects and returns the slices that bound filled area
+/// (with direction set so the filled area is on the left).
+fn filled_boundary_slices<P, T>(
+    polyline: &P,
+    fill_rule: FillRule,
+    aabb_index: &StaticAABB2DIndex<T>,
+    pos_equal_eps: T,
+) -> Vec<PlineViewData<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let self_intersects = all_self_intersects_as_basic(polyline, aabb_index, true, pos_equal_eps);
+
+    // every self intersect is a slice point on both of its segments, use the segment that starts
+    // at the intersect if it lies on a segment end point
+    let lookup_index = |index: usize, point: Vector2<T>| {
+        let next_index = polyline.next_wrapping_index(index);
+        if point.fuzzy_eq_eps(polyline.at(next_index).pos(), pos_equal_eps) {
+            next_index
+        } else {
+            index
+        }
+    };
+    let mut intersects = Vec::with_capacity(2 * self_intersects.len());
+    for intr in &self_intersects {
+        let index1 = lookup_index(intr.start_index1, intr.point);
+        let index2 = lookup_index(intr.start_index2, intr.point);
+        intersects.push(PlineBasicIntersect::new(index1, index2, intr.point));
+        intersects.push(PlineBasicIntersect::new(index2, index1, intr.point));
+    }
+
+    let mut slices = Vec::new();
+    if intersects.is_empty() {
+        slices.push(PlineViewData::from_entire_pline(polyline));
+    } else {
+        let info = ProcessForBooleanResult {
+            overlapping_slices: Vec::new(),
+            intersects,
+            pline1_orientation: PlineOrientation::Open,
+            pline2_orientation: PlineOrientation::Open,
+        };
+        let mut boolean_slices = Vec::new();
+        slice_at_intersects(
+            polyline,
+            &info,
+            false,
+            &mut |_| true,
+            &mut boolean_slices,
+            pos_equal_eps,
+        );
+        slices.extend(boolean_slices.into_iter().map(|s| s.view_data));
+    }
+
+    // sample distance either side of the slice, small enough to not cross other boundaries
+    let sample_dist = T::from(10.0).unwrap() * pos_equal_eps;
+    slices.retain_mut(|v_data| {
+        let view = v_data.view(polyline);
+        let v1 = view.at(0);
+        let v2 = view.at(1);
+        let midpoint = seg_midpoint(v1, v2);
+        let left = seg_tangent_vector(v1, v2, midpoint)
+            .unit_perp()
+            .scale(sample_dist);
+        let left_filled = fill_rule.is_filled(polyline.winding_number(midpoint + left));
+        let right_filled = fill_rule.is_filled(polyline.winding_number(midpoint - left));
+        if left_filled == right_filled {
+            // filled or empty on both sides, slice does not bound filled area
+            return false;
+        }
+        v_data.inverted_direction = right_filled;
+        true
+    });
+
+    slices
+}
+
+/// Stitches slices into closed polylines. Where multiple slices connect at a point the slice that
+/// turns furthest left is taken so touching loops are split apart rather than joined.
+fn stitch_filled_slices<P, T, O>(
+    polyline: &P,
+    slices: &[PlineViewData<T>],
+    pos_equal_eps: T,
+    collapsed_area_eps: Option<T>,
+) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let select = |current_pline: &O, _: usize, candidates: &[usize]| {
+        let vc = current_pline.vertex_count();
+        let incoming = if vc < 2 {
+            Vector2::zero()
+        } else {
+            let v1 = current_pline.at(vc - 2);
+            let v2 = current_pline.at(vc - 1);
+            seg_tangent_vector(v1, v2, v2.pos())
+        };
+        let turn_angle = |j: usize| {
+            let view = slices[j].view(polyline);
+            let outgoing = seg_tangent_vector(view.at(0), view.at(1), view.at(0).pos());
+            incoming.perp_dot(outgoing).atan2(incoming.dot(outgoing))
+        };
+        candidates
+            .iter()
+            .copied()
+            .map(|j| (j, turn_angle(j)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(j, _)| j)
+    };
+
+    stitch_slices(
+        slices.len(),
+        |i| slices[i].view(polyline),
+        select,
+        pos_equal_eps,
+        collapsed_area_eps,
+        false,
+    )
+    .into_iter()
+    .map(|(mut pline, _): (O, _)| {
+        pline.set_userdata_values(polyline.get_userdata_values());
+        pline
+    })
+    .collect()
+}
@@ -0,0 +1,198 @@
+//! Fitting arcs to runs of line segments (inverse of approximating arcs with lines).
+//!
+//! Each run of consecutive line segments is processed greedily: starting from the first point of
+//! the run, the segment being created is extended over as many points as possible while a single
+//! line or arc still lies within the tolerance of all the points (and the midpoints of the line
+//! segments between them). The arc tested is the arc through the first, middle, and last points so
+//! arcs are not required to be tangent to the segments they connect to. Existing arc segments are
+//! kept unchanged.
+
+use crate::{
+    core::{
+        math::{
+            Vector2, angle, bulge_from_angle, line_seg_closest_point, midpoint, normalize_radians,
+        },
+        traits::Real,
+    },
+    polyline::{PlineCreation, PlineFitArcsOptions, PlineSource},
+};
+
+/// Returns a new polyline with runs of line segments replaced by line and arc segments that lie
+/// within `tolerance` of the original line segment end points and midpoints.
+///
+/// Arcs created sweep at most a half circle. The first vertex (and last vertex if open) of the
+/// polyline is always kept. Repeat positions (within `options.pos_equal_eps`) inside a run of line
+/// segments are skipped.
+pub fn fit_arcs<P, O, T>(polyline: &P, tolerance: T, options: &PlineFitArcsOptions<T>) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let vc = polyline.vertex_count();
+    let seg_count = polyline.segment_count();
+    let mut result = O::with_capacity(0, polyline.is_closed());
+    result.set_userdata_values(polyline.get_userdata_values());
+
+    let tolerance = num_traits::real::Real::abs(tolerance);
+    let mut points = Vec::new();
+    let mut i = 0;
+    while i < seg_count {
+        let v = polyline.at(i);
+        if !v.bulge_is_zero() {
+            result.add_vertex(v);
+            i += 1;
+            continue;
+        }
+
+        // collect the points of the run of line segments starting at i
+        points.clear();
+        points.push(v.pos());
+        while i < seg_count && polyline.at(i).bulge_is_zero() {
+            i += 1;
+            let p = polyline.at(i % vc).pos();
+            let run_end = i == seg_count || !polyline.at(i).bulge_is_zero();
+            let last = points.len() - 1;
+            if !points[last].fuzzy_eq_eps(p, options.pos_equal_eps) {
+                points.push(p);
+            } else if run_end {
+                // repeat position skipped unless it is the end of the run, the end point replaces
+                // the previous point (start point of the run is always kept)
+                if last == 0 {
+                    points.push(p);
+                } else {
+                    points[last] = p;
+                }
+            }
+        }
+
+        fit_run(&points, tolerance, &mut result);
+    }
+
+    if !polyline.is_closed() && vc > 0 {
+        result.add_vertex(polyline.at(vc - 1));
+    }
+
+    result
+}
+
+/// Fit lines and arcs to the run of `points` (connected by line segments), adding all the
+/// vertexes except the last point to `result`.
+fn fit_run<O, T>(points: &[Vector2<T>], tolerance: T, result: &mut O)
+where
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let n = points.len();
+    let mut start = 0;
+    while start < n - 1 {
+        let mut end = start + 1;
+        let mut bulge = T::zero();
+        while end + 1 < n {
+            match fit_segment(&points[start..=end + 1], tolerance) {
+                Some(b) => {
+                    end += 1;
+                    bulge = b;
+                }
+                None => break,
+            }
+        }
+
+        result.add(points[start].x, points[start].y, bulge);
+        start = end;
+    }
+}
+
+/// Returns the bulge of a single line (zero) or arc segment from the first to the last of `points`
+/// that lies within `tolerance` of all the points and the midpoints between them, or `None` if no
+/// such segment is found.
+fn fit_segment<T>(points: &[Vector2<T>], tolerance: T) -> Option<T>
+where
+    T: Real,
+{
+    let start = points[0];
+    let end = points[points.len() - 1];
+    if (end - start).length() <= tolerance {
+        return None;
+    }
+
+    let test_points = || {
+        points
+            .iter()
+            .copied()
+            .chain(points.windows(2).map(|w| midpoint(w[0], w[1])))
+    };
+
+    let line_fits =
+        test_points().all(|p| (line_seg_closest_point(start, end, p) - p).length() <= tolerance);
+    if line_fits {
+        return Some(T::zero());
+    }
+
+    // arc through the first, middle, and last points
+    let mid = points[points.len() / 2];
+    let (center, radius) = circle_through_points(start, mid, end)?;
+    let is_ccw = (mid - start).perp_dot(end - mid) > T::zero();
+
+    // angle traveled from the start point in the direction of the arc
+    let start_angle = angle(center, start);
+    let progress = |p: Vector2<T>| {
+        let a = angle(center, p);
+        if is_ccw {
+            normalize_radians(a - start_angle)
+        } else {
+            normalize_radians(start_angle - a)
+        }
+    };
+
+    let sweep = progress(end);
+    if sweep > T::pi() {
+        return None;
+    }
+
+    // points must progress along the arc without going past the end
+    let mut prev_progress = T::zero();
+    for &p in &points[1..] {
+        let prog = progress(p);
+        if prog < prev_progress || prog > sweep {
+            return None;
+        }
+        prev_progress = prog;
+    }
+
+    let arc_fits = test_points().all(|p| ((p - center).length() - radius).abs() <= tolerance);
+    if !arc_fits {
+        return None;
+    }
+
+    Some(if is_ccw {
+        bulge_from_angle(sweep)
+    } else {
+        -bulge_from_angle(sweep)
+    })
+}
+
+/// Returns the center and radius of the circle through the three points given or `None` if the
+/// points are collinear.
+fn circle_through_points<T>(
+    p0: Vector2<T>,
+    p1: Vector2<T>,
+    p2: Vector2<T>,
+) -> Option<(Vector2<T>, T)>
+where
+    T: Real,
+{
+    let a = p1 - p0;
+    let b = p2 - p0;
+    let d = T::two() * a.perp_dot(b);
+    let a_len_sq = a.length_squared();
+    let b_len_sq = b.length_squared();
+    let offset = Vector2::new(
+        (b.y * a_len_sq - a.y * b_len_sq) / d,
+        (a.x * b_len_sq - b.x * a_len_sq) / d,
+    );
+
+    let radius = offset.length();
+    // not finite if the points are collinear
+    radius.is_finite().then(|| (p0 + offset, radius))
+}
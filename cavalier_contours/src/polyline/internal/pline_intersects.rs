@@ -12,9 +12,10 @@ use crate::{
     polyline::{
         FindIntersectsOptions, PlineBasicIntersect, PlineIntersect, PlineIntersectFilterItem,
         PlineIntersectVisitContext, PlineIntersectVisitor, PlineIntersectsCollection,
-        PlineOverlappingIntersect, PlineSegIntr, PlineSource, PlineView, PlineViewData,
-        TwoPlinesIntersectFilterItem, TwoPlinesIntersectVisitor, pline_seg_intr,
-        seg_fast_approx_bounding_box, seg_split_at_point, seg_tangent_vector,
+        PlineOverlappingIntersect, PlineSegIntr, PlineSelfIntersectOptions, PlineSource, PlineView,
+        PlineViewData, SelfIntersectsInclude, TwoPlinesIntersectFilterItem,
+        TwoPlinesIntersectVisitor, pline_seg_intr, seg_fast_approx_bounding_box, seg_is_large_arc,
+        seg_split_at_point, seg_tangent_vector,
     },
 };
 use static_aabb2d_index as aabb_index;
@@ -314,9 +315,126 @@ where
     }
 }
 
+/// Returns the vertex index of `polyline` each vertex of its
+/// [`PlineSource::split_large_arcs`] result comes from (both vertexes of a split arc map to the
+/// start vertex index of the arc), used to map segment indexes found on the split polyline back to
+/// the source polyline.
+pub(crate) fn split_large_arcs_source_indexes<P, T>(polyline: &P, pos_equal_eps: T) -> Vec<usize>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut source_indexes = Vec::with_capacity(polyline.vertex_count());
+    for (i, j) in polyline.iter_segment_indexes() {
+        source_indexes.push(i);
+        if seg_is_large_arc(polyline.at(i), polyline.at(j), pos_equal_eps) {
+            source_indexes.push(i);
+        }
+    }
+    if !polyline.is_closed() {
+        source_indexes.push(polyline.vertex_count() - 1);
+    }
+    source_indexes
+}
+
+/// Returns `intersect` with both of its segment indexes mapped by `map`.
+fn map_intersect_indexes<T, F>(intersect: PlineIntersect<T>, map: F) -> PlineIntersect<T>
+where
+    F: Fn(usize) -> usize,
+{
+    match intersect {
+        PlineIntersect::Basic(intr) => {
+            PlineIntersect::new_basic(map(intr.start_index1), map(intr.start_index2), intr.point)
+        }
+        PlineIntersect::Overlapping(intr) => PlineIntersect::new_overlapping(
+            map(intr.start_index1),
+            map(intr.start_index2),
+            intr.point1,
+            intr.point2,
+        ),
+    }
+}
+
+/// Self intersect visitor for a polyline with its large arcs split, forwards intersects to the
+/// wrapped visitor with segment indexes mapped back to the source polyline. Items are filtered by
+/// the wrapped visitor as local segments of the source polyline.
+pub(crate) struct SplitArcsSelfIntersectVisitor<'a, V> {
+    pub visitor: &'a mut V,
+    /// Source polyline vertex index for each split polyline vertex index.
+    pub source_indexes: &'a [usize],
+}
+
+impl<T, C, V> PlineIntersectVisitor<T, C> for SplitArcsSelfIntersectVisitor<'_, V>
+where
+    T: Real,
+    C: ControlFlow,
+    V: PlineIntersectVisitor<T, C>,
+{
+    #[inline]
+    fn filter_map(&self, item: PlineIntersectFilterItem) -> Option<usize> {
+        let index = item.index();
+        self.visitor
+            .filter_map(PlineIntersectFilterItem::LocalSegment(
+                self.source_indexes[index],
+            ))
+            .map(|_| index)
+    }
+
+    #[inline]
+    fn visit(&mut self, intersect: PlineIntersect<T>) -> C {
+        let source_indexes = self.source_indexes;
+        self.visitor
+            .visit(map_intersect_indexes(intersect, |i| source_indexes[i]))
+    }
+}
+
+/// Visits self intersects of a polyline using the options provided, the polyline must not have any
+/// arcs sweeping more than a half circle (see [`PlineSource::split_large_arcs`]).
+pub(crate) fn visit_self_intersects_unsplit<P, T, C, V>(
+    polyline: &P,
+    visitor: &mut V,
+    options: &PlineSelfIntersectOptions<T>,
+) -> C
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    C: ControlFlow,
+    V: PlineIntersectVisitor<T, C>,
+{
+    if options.include == SelfIntersectsInclude::Local {
+        // local intersects only
+        return visit_local_self_intersects(polyline, visitor, options.pos_equal_eps);
+    }
+
+    let constructed_index;
+    let index = if let Some(x) = options.aabb_index {
+        x
+    } else {
+        constructed_index = polyline.create_approx_aabb_index();
+        &constructed_index
+    };
+
+    if options.include == SelfIntersectsInclude::Global {
+        // global intersects only
+        return visit_global_self_intersects(polyline, index, visitor, options.pos_equal_eps);
+    }
+
+    // else all intersects
+    try_cf!(visit_local_self_intersects(
+        polyline,
+        visitor,
+        options.pos_equal_eps
+    ));
+
+    visit_global_self_intersects(polyline, index, visitor, options.pos_equal_eps)
+}
+
 /// Find all self intersects of a polyline. If `include_overlapping` is `true` then overlapping
 /// intersects are returned as two basic intersects, one at each end of the overlap. If
 /// `include_overlapping` is `false` then overlapping intersects are not returned.
+///
+/// Arcs sweeping more than a half circle are split (and a spatial index built for the split
+/// polyline) with segment indexes mapped back to `polyline`.
 pub fn all_self_intersects_as_basic<P, T>(
     polyline: &P,
     aabb_index: &StaticAABB2DIndex<T>,
@@ -327,6 +445,18 @@ where
     P: PlineSource<Num = T> + ?Sized,
     T: Real,
 {
+    if let Some(split) = polyline.split_large_arcs(pos_equal_eps) {
+        let source_indexes = split_large_arcs_source_indexes(polyline, pos_equal_eps);
+        let split_index = split.create_approx_aabb_index();
+        let mut intrs =
+            all_self_intersects_as_basic(&split, &split_index, include_overlapping, pos_equal_eps);
+        for intr in &mut intrs {
+            intr.start_index1 = source_indexes[intr.start_index1];
+            intr.start_index2 = source_indexes[intr.start_index2];
+        }
+        return intrs;
+    }
+
     let mut visitor = BasicIntersectVisitor {
         intrs: Vec::new(),
         include_overlapping,
@@ -628,6 +758,10 @@ where
     }
 }
 
+/// Find all intersects between two polylines.
+///
+/// Arcs sweeping more than a half circle are split (segment intersect functions assume arcs of at
+/// most a half circle) with segment indexes mapped back to the polylines given.
 pub fn find_intersects<P, O, T>(
     pline1: &P,
     pline2: &O,
@@ -642,6 +776,34 @@ where
         return PlineIntersectsCollection::new_empty();
     }
 
+    let pos_equal_eps = options.pos_equal_eps;
+    if let Some(split1) = pline1.split_large_arcs(pos_equal_eps) {
+        let source_indexes = split_large_arcs_source_indexes(pline1, pos_equal_eps);
+        let split_options = FindIntersectsOptions {
+            pline1_aabb_index: None,
+            pos_equal_eps,
+        };
+        let mut intrs = find_intersects(&split1, pline2, &split_options);
+        for intr in &mut intrs.basic_intersects {
+            intr.start_index1 = source_indexes[intr.start_index1];
+        }
+        for intr in &mut intrs.overlapping_intersects {
+            intr.start_index1 = source_indexes[intr.start_index1];
+        }
+        return intrs;
+    }
+    if let Some(split2) = pline2.split_large_arcs(pos_equal_eps) {
+        let source_indexes = split_large_arcs_source_indexes(pline2, pos_equal_eps);
+        let mut intrs = find_intersects(pline1, &split2, options);
+        for intr in &mut intrs.basic_intersects {
+            intr.start_index2 = source_indexes[intr.start_index2];
+        }
+        for intr in &mut intrs.overlapping_intersects {
+            intr.start_index2 = source_indexes[intr.start_index2];
+        }
+        return intrs;
+    }
+
     let mut visitor = FindIntersectsVisitor::new(
         pline1,
         pline2,
@@ -701,6 +863,19 @@ where
     O: PlineSource<Num = T> + ?Sized,
     T: Real,
 {
+    // segment intersect functions assume arcs of at most a half circle
+    let pos_equal_eps = options.pos_equal_eps;
+    if let Some(split1) = pline1.split_large_arcs(pos_equal_eps) {
+        let split_options = FindIntersectsOptions {
+            pline1_aabb_index: None,
+            pos_equal_eps,
+        };
+        return scan_for_intersect(&split1, pline2, &split_options);
+    }
+    if let Some(split2) = pline2.split_large_arcs(pos_equal_eps) {
+        return scan_for_intersect(pline1, &split2, options);
+    }
+
     let mut found_intersect = false;
 
     let mut visitor = |intersect: PlineSegIntr<T>,
@@ -0,0 +1,620 @@
+//! Approximate medial axis of closed polylines.
+//!
+//! The boundary polylines are sampled at a fixed spacing and the Delaunay triangulation of the
+//! samples is built by incremental (Bowyer-Watson) insertion. The circumcenters of the triangles
+//! are the Voronoi vertexes of the samples and the Voronoi edges connect the circumcenters of
+//! adjacent triangles. The Voronoi edges inside the boundary approximate the medial axis, except
+//! edges between samples next to each other along the boundary. Those are removed by the angle the
+//! two samples subtend at the edge, which is small between neighboring samples and large where the
+//! edge is equidistant to separate parts of the boundary.
+//!
+//! The remaining edges form a graph that is reduced to nodes (branch end points and junctions)
+//! connected by chains of points. Short branches left by sampling near corners are pruned, junctions
+//! split by sampling are merged, and the clearance radius of every point is the exact distance to
+//! the boundary polylines.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    core::{
+        math::{Vector2, angle_from_bulge, line_seg_closest_point, point_from_parametric},
+        traits::Real,
+    },
+    polyline::{
+        MedialAxis, MedialAxisEdge, MedialAxisPoint, PlineMedialAxisOptions, PlineSource,
+        seg_arc_radius_and_center, seg_length,
+    },
+};
+
+const NONE: usize = usize::MAX;
+
+#[derive(Debug, Clone, Copy)]
+struct Triangle {
+    /// Vertex indexes in counter clockwise order.
+    v: [usize; 3],
+    /// Adjacent triangle across the edge opposite each vertex (or [`NONE`]).
+    adj: [usize; 3],
+    alive: bool,
+}
+
+impl Triangle {
+    /// Edge opposite vertex `i` (counter clockwise).
+    fn edge(&self, i: usize) -> (usize, usize) {
+        (self.v[(i + 1) % 3], self.v[(i + 2) % 3])
+    }
+}
+
+fn orient<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> T
+where
+    T: Real,
+{
+    (b - a).perp_dot(c - a)
+}
+
+/// Positive if `d` is inside the circumcircle of the counter clockwise triangle `a`, `b`, `c`.
+fn in_circle<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>, d: Vector2<T>) -> T
+where
+    T: Real,
+{
+    let a = a - d;
+    let b = b - d;
+    let c = c - d;
+    a.length_squared() * b.perp_dot(c)
+        + b.length_squared() * c.perp_dot(a)
+        + c.length_squared() * a.perp_dot(b)
+}
+
+fn circumcenter<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> Vector2<T>
+where
+    T: Real,
+{
+    let b = b - a;
+    let c = c - a;
+    let d = T::two() * b.perp_dot(c);
+    let b_len = b.length_squared();
+    let c_len = c.length_squared();
+    a + Vector2::new(c.y * b_len - b.y * c_len, b.x * c_len - c.x * b_len).scale(T::one() / d)
+}
+
+/// Delaunay triangulation built by incremental insertion, the first 3 points are the vertexes of
+/// a super triangle containing all the other points.
+struct Triangulation<T> {
+    points: Vec<Vector2<T>>,
+    triangles: Vec<Triangle>,
+    /// Insertion stamp of the triangles in the current cavity.
+    marks: Vec<usize>,
+    last: usize,
+}
+
+impl<T> Triangulation<T>
+where
+    T: Real,
+{
+    fn new(samples: &[Vector2<T>], pos_equal_eps: T) -> Self {
+        let (mut min, mut max) = (samples[0], samples[0]);
+        for p in samples {
+            min = Vector2::new(
+                num_traits::real::Real::min(min.x, p.x),
+                num_traits::real::Real::min(min.y, p.y),
+            );
+            max = Vector2::new(
+                num_traits::real::Real::max(max.x, p.x),
+                num_traits::real::Real::max(max.y, p.y),
+            );
+        }
+        let center = (min + max).scale(T::one() / T::two());
+        let size = num_traits::real::Real::max(max.x - min.x, max.y - min.y) + T::one();
+        let far = size * T::from(20).unwrap();
+
+        let mut result = Self {
+            points: vec![
+                Vector2::new(center.x - far, center.y - size),
+                Vector2::new(center.x + far, center.y - size),
+                Vector2::new(center.x, center.y + far),
+            ],
+            triangles: vec![Triangle {
+                v: [0, 1, 2],
+                adj: [NONE; 3],
+                alive: true,
+            }],
+            marks: vec![NONE],
+            last: 0,
+        };
+
+        for &p in samples {
+            result.insert(p, pos_equal_eps);
+        }
+
+        result
+    }
+
+    fn contains(&self, t: usize, p: Vector2<T>) -> bool {
+        let tri = &self.triangles[t];
+        (0..3).all(|i| {
+            let (a, b) = tri.edge(i);
+            orient(self.points[a], self.points[b], p) >= T::zero()
+        })
+    }
+
+    /// Find the triangle containing `p` by walking from the last triangle created.
+    fn locate(&self, p: Vector2<T>) -> usize {
+        let mut t = self.last;
+        let mut steps = 0;
+        'walk: while steps < self.triangles.len() {
+            steps += 1;
+            let tri = &self.triangles[t];
+            for i in 0..3 {
+                let (a, b) = tri.edge(i);
+                if orient(self.points[a], self.points[b], p) < T::zero() && tri.adj[i] != NONE {
+                    t = tri.adj[i];
+                    continue 'walk;
+                }
+            }
+            return t;
+        }
+
+        // walk did not terminate due to numeric error, fall back to scanning all triangles
+        (0..self.triangles.len())
+            .find(|&i| self.triangles[i].alive && self.contains(i, p))
+            .unwrap_or(self.last)
+    }
+
+    fn in_circumcircle(&self, t: usize, p: Vector2<T>) -> bool {
+        let v = self.triangles[t].v;
+        in_circle(self.points[v[0]], self.points[v[1]], self.points[v[2]], p) > T::zero()
+    }
+
+    fn insert(&mut self, p: Vector2<T>, pos_equal_eps: T) {
+        let start = self.locate(p);
+        if self.triangles[start]
+            .v
+            .iter()
+            .any(|&i| self.points[i].fuzzy_eq_eps(p, pos_equal_eps))
+        {
+            return;
+        }
+
+        let p_idx = self.points.len();
+        self.points.push(p);
+
+        // cavity of triangles whose circumcircle contains p
+        let stamp = p_idx;
+        let mut cavity = vec![start];
+        self.marks[start] = stamp;
+        let mut i = 0;
+        while i < cavity.len() {
+            let t = cavity[i];
+            i += 1;
+            for n in self.triangles[t].adj {
+                if n != NONE && self.marks[n] != stamp && self.in_circumcircle(n, p) {
+                    self.marks[n] = stamp;
+                    cavity.push(n);
+                }
+            }
+        }
+
+        // grow the cavity until p sees every boundary edge (keeps the cavity star shaped when the
+        // circle test is inconsistent due to numeric error)
+        let boundary = loop {
+            let mut boundary = Vec::new();
+            let mut grow = None;
+            for &t in &cavity {
+                let tri = self.triangles[t];
+                for j in 0..3 {
+                    let n = tri.adj[j];
+                    if n != NONE && self.marks[n] == stamp {
+                        continue;
+                    }
+                    let (a, b) = tri.edge(j);
+                    if n != NONE && orient(self.points[a], self.points[b], p) <= T::zero() {
+                        grow = Some(n);
+                    }
+                    boundary.push((a, b, n));
+                }
+            }
+            match grow {
+                Some(n) => {
+                    self.marks[n] = stamp;
+                    cavity.push(n);
+                }
+                None => break boundary,
+            }
+        };
+
+        for &t in &cavity {
+            self.triangles[t].alive = false;
+        }
+
+        let first_new = self.triangles.len();
+        let mut by_start = HashMap::with_capacity(boundary.len());
+        let mut by_end = HashMap::with_capacity(boundary.len());
+        for (k, &(a, b, n)) in boundary.iter().enumerate() {
+            let t = first_new + k;
+            by_start.insert(a, t);
+            by_end.insert(b, t);
+            if n != NONE {
+                let outside = &mut self.triangles[n];
+                if let Some(j) = (0..3).find(|&j| outside.edge(j) == (b, a)) {
+                    outside.adj[j] = t;
+                }
+            }
+            self.triangles.push(Triangle {
+                v: [a, b, p_idx],
+                adj: [NONE, NONE, n],
+                alive: true,
+            });
+            self.marks.push(NONE);
+        }
+
+        for t in first_new..self.triangles.len() {
+            let [a, b, _] = self.triangles[t].v;
+            // edge b -> p is shared with the triangle starting at b, edge p -> a with the triangle
+            // ending at a
+            self.triangles[t].adj[0] = by_start.get(&b).copied().unwrap_or(NONE);
+            self.triangles[t].adj[1] = by_end.get(&a).copied().unwrap_or(NONE);
+        }
+
+        self.last = first_new;
+    }
+}
+
+/// Sample the boundary of `pline` with at most `sample_distance` between samples.
+fn sample_boundary<P, T>(pline: &P, sample_distance: T, samples: &mut Vec<Vector2<T>>)
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    for (v1, v2) in pline.iter_segments() {
+        let count = num_traits::real::Real::ceil(seg_length(v1, v2) / sample_distance);
+        let count = count.to_usize().unwrap_or(1).max(1);
+        let count_t = T::from(count).unwrap();
+        if v1.bulge_is_zero() {
+            samples.extend(
+                (0..count).map(|i| {
+                    point_from_parametric(v1.pos(), v2.pos(), T::from(i).unwrap() / count_t)
+                }),
+            );
+        } else {
+            let (radius, center) = seg_arc_radius_and_center(v1, v2);
+            let start = v1.pos() - center;
+            let sweep = angle_from_bulge(v1.bulge);
+            samples.extend((0..count).map(|i| {
+                let a = sweep * T::from(i).unwrap() / count_t;
+                let (sin, cos) = num_traits::real::Real::sin_cos(a);
+                let dir =
+                    Vector2::new(start.x * cos - start.y * sin, start.x * sin + start.y * cos);
+                center + dir.normalize().scale(radius)
+            }));
+        }
+    }
+}
+
+fn find_root(groups: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while groups[root] != root {
+        root = groups[root];
+    }
+    let mut i = i;
+    while groups[i] != root {
+        let next = groups[i];
+        groups[i] = root;
+        i = next;
+    }
+    root
+}
+
+/// Graph of medial axis points with undirected edges.
+struct AxisGraph<T> {
+    points: Vec<MedialAxisPoint<T>>,
+    adj: Vec<Vec<usize>>,
+}
+
+impl<T> AxisGraph<T>
+where
+    T: Real,
+{
+    fn remove_edge(&mut self, a: usize, b: usize) {
+        self.adj[a].retain(|&x| x != b);
+        self.adj[b].retain(|&x| x != a);
+    }
+
+    /// Walk from `start` through `next` until reaching a vertex that is not degree 2 (or returning
+    /// to `start`), marking the edges visited.
+    fn walk_chain(
+        &self,
+        start: usize,
+        next: usize,
+        visited: &mut HashSet<(usize, usize)>,
+    ) -> Vec<usize> {
+        let mut chain = vec![start];
+        let mut prev = start;
+        let mut current = next;
+        loop {
+            visited.insert((prev.min(current), prev.max(current)));
+            chain.push(current);
+            if current == start || self.adj[current].len() != 2 {
+                return chain;
+            }
+            let following = if self.adj[current][0] == prev {
+                self.adj[current][1]
+            } else {
+                self.adj[current][0]
+            };
+            prev = current;
+            current = following;
+        }
+    }
+
+    /// All the chains between vertexes that are not degree 2, followed by closed loops of degree 2
+    /// vertexes.
+    fn chains(&self) -> Vec<Vec<usize>> {
+        let mut visited = HashSet::new();
+        let mut result = Vec::new();
+        for start in 0..self.points.len() {
+            if self.adj[start].len() == 2 {
+                continue;
+            }
+            for &next in &self.adj[start] {
+                if !visited.contains(&(start.min(next), start.max(next))) {
+                    result.push(self.walk_chain(start, next, &mut visited));
+                }
+            }
+        }
+
+        for start in 0..self.points.len() {
+            if self.adj[start].len() == 2
+                && !visited
+                    .contains(&(start.min(self.adj[start][0]), start.max(self.adj[start][0])))
+            {
+                result.push(self.walk_chain(start, self.adj[start][0], &mut visited));
+            }
+        }
+
+        result
+    }
+
+    fn chain_length(&self, chain: &[usize]) -> T {
+        chain.windows(2).fold(T::zero(), |acc, w| {
+            acc + (self.points[w[1]].pos - self.points[w[0]].pos).length()
+        })
+    }
+
+    /// Remove branches shorter than `min_length` that have a free end and merge junctions
+    /// connected by a branch shorter than `min_length` (features smaller than the boundary
+    /// sampling).
+    fn remove_short_features(&mut self, min_length: T) {
+        'restart: loop {
+            for chain in self.chains() {
+                let first = chain[0];
+                let last = *chain.last().unwrap();
+                if self.chain_length(&chain) >= min_length {
+                    continue;
+                }
+
+                let (first_degree, last_degree) = (self.adj[first].len(), self.adj[last].len());
+                // chains end at vertexes that are not degree 2 (except closed loops)
+                let is_spur = first_degree == 1 || last_degree == 1;
+                let is_bridge = first != last && first_degree > 2 && last_degree > 2;
+                if !is_spur && !is_bridge {
+                    continue;
+                }
+
+                for w in chain.windows(2) {
+                    self.remove_edge(w[0], w[1]);
+                }
+                if is_bridge {
+                    for other in std::mem::take(&mut self.adj[last]) {
+                        self.adj[other].retain(|&x| x != last);
+                        if other != first && !self.adj[first].contains(&other) {
+                            self.adj[first].push(other);
+                            self.adj[other].push(first);
+                        }
+                    }
+                }
+                continue 'restart;
+            }
+            break;
+        }
+    }
+}
+
+/// Drop points of the chain that lie on the line between their neighbors (within `eps`) with a
+/// linearly varying radius.
+fn remove_collinear<T>(points: Vec<MedialAxisPoint<T>>, eps: T) -> Vec<MedialAxisPoint<T>>
+where
+    T: Real,
+{
+    let mut result: Vec<MedialAxisPoint<T>> = Vec::with_capacity(points.len());
+    for p in points {
+        while result.len() >= 2 {
+            let a = result[result.len() - 2];
+            let m = result[result.len() - 1];
+            let closest = line_seg_closest_point(a.pos, p.pos, m.pos);
+            let total = (p.pos - a.pos).length();
+            let t = if total > eps {
+                (closest - a.pos).length() / total
+            } else {
+                T::zero()
+            };
+            let radius = a.radius + (p.radius - a.radius) * t;
+            if (closest - m.pos).length() < eps
+                && num_traits::real::Real::abs(radius - m.radius) < eps
+            {
+                result.pop();
+            } else {
+                break;
+            }
+        }
+        result.push(p);
+    }
+    result
+}
+
+/// Compute the approximate medial axis of the region bounded by the closed polylines in `loops`
+/// (points with non-zero total winding number are inside).
+///
+/// See [`PlineSource::medial_axis_opt`] for more information.
+pub fn medial_axis<P, T>(
+    loops: &[&P],
+    sample_distance: T,
+    options: &PlineMedialAxisOptions<T>,
+) -> MedialAxis<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let loops = loops
+        .iter()
+        .copied()
+        .filter(|p| p.is_closed() && p.vertex_count() > 1)
+        .collect::<Vec<_>>();
+    if loops.is_empty()
+        || sample_distance.partial_cmp(&T::zero()) != Some(std::cmp::Ordering::Greater)
+    {
+        return MedialAxis::empty();
+    }
+
+    let mut samples = Vec::new();
+    for pline in &loops {
+        sample_boundary(*pline, sample_distance, &mut samples);
+    }
+    if samples.len() < 3 {
+        return MedialAxis::empty();
+    }
+
+    let triangulation = Triangulation::new(&samples, eps);
+    let points = &triangulation.points;
+    let triangles = &triangulation.triangles;
+    let is_inside = |p: Vector2<T>| loops.iter().map(|l| l.winding_number(p)).sum::<i32>() != 0;
+
+    // circumcenters of the triangles (not touching the super triangle) that are inside
+    let centers: Vec<Option<Vector2<T>>> = triangles
+        .iter()
+        .map(|t| {
+            if !t.alive || t.v.iter().any(|&i| i < 3) {
+                return None;
+            }
+            let [a, b, c] = t.v.map(|i| points[i]);
+            // skip degenerate (collinear) triangles
+            if orient(a, b, c) <= eps * eps {
+                return None;
+            }
+            Some(circumcenter(a, b, c)).filter(|&c| is_inside(c))
+        })
+        .collect();
+
+    // merge circumcenters that are at the same position (co-circular samples)
+    let mut groups = (0..triangles.len()).collect::<Vec<_>>();
+    for t in 0..triangles.len() {
+        let Some(c1) = centers[t] else { continue };
+        for n in triangles[t].adj {
+            if n == NONE || n < t {
+                continue;
+            }
+            if let Some(c2) = centers[n]
+                && c1.fuzzy_eq_eps(c2, eps)
+            {
+                let (r1, r2) = (find_root(&mut groups, t), find_root(&mut groups, n));
+                groups[r2] = r1;
+            }
+        }
+    }
+
+    let mut vertex_of_group = HashMap::new();
+    let mut graph = AxisGraph {
+        points: Vec::new(),
+        adj: Vec::new(),
+    };
+    let mut vertex = |t: usize, graph: &mut AxisGraph<T>, groups: &mut Vec<usize>| {
+        let root = find_root(groups, t);
+        *vertex_of_group.entry(root).or_insert_with(|| {
+            let pos = centers[root].unwrap();
+            let radius = loops
+                .iter()
+                .filter_map(|l| l.closest_point(pos, eps))
+                .map(|r| r.distance)
+                .fold(
+                    num_traits::Bounded::max_value(),
+                    num_traits::real::Real::min,
+                );
+            graph.points.push(MedialAxisPoint { pos, radius });
+            graph.adj.push(Vec::new());
+            graph.points.len() - 1
+        })
+    };
+
+    for t in 0..triangles.len() {
+        let Some(c1) = centers[t] else { continue };
+        for (i, &n) in triangles[t].adj.iter().enumerate() {
+            if n == NONE || n < t {
+                continue;
+            }
+            let Some(c2) = centers[n] else { continue };
+            if find_root(&mut groups, t) == find_root(&mut groups, n) {
+                continue;
+            }
+
+            let (a, b) = triangles[t].edge(i);
+            let mid = (c1 + c2).scale(T::one() / T::two());
+            let (da, db) = (points[a] - mid, points[b] - mid);
+            let subtended = num_traits::real::Real::atan2(
+                num_traits::real::Real::abs(da.perp_dot(db)),
+                da.dot(db),
+            );
+            if subtended < options.min_angle {
+                continue;
+            }
+
+            let v1 = vertex(t, &mut graph, &mut groups);
+            let v2 = vertex(n, &mut graph, &mut groups);
+            if v1 != v2 && !graph.adj[v1].contains(&v2) {
+                graph.adj[v1].push(v2);
+                graph.adj[v2].push(v1);
+            }
+        }
+    }
+
+    graph.remove_short_features(sample_distance);
+
+    // no edges (e.g. a circle) the medial axis is the circumcenter furthest from the boundary
+    if graph.adj.iter().all(Vec::is_empty) {
+        for (t, center) in centers.iter().enumerate() {
+            if center.is_some() {
+                vertex(t, &mut graph, &mut groups);
+            }
+        }
+        let Some(center) = graph
+            .points
+            .iter()
+            .max_by(|a, b| a.radius.total_cmp(&b.radius))
+        else {
+            return MedialAxis::empty();
+        };
+        return MedialAxis {
+            nodes: vec![*center],
+            edges: Vec::new(),
+        };
+    }
+
+    let mut result = MedialAxis::empty();
+    let mut node_of_vertex = HashMap::new();
+    for chain in graph.chains() {
+        let mut node = |v: usize| {
+            *node_of_vertex.entry(v).or_insert_with(|| {
+                result.nodes.push(graph.points[v]);
+                result.nodes.len() - 1
+            })
+        };
+        let start = node(chain[0]);
+        let end = node(*chain.last().unwrap());
+        let chain_points = chain.iter().map(|&v| graph.points[v]).collect();
+        result.edges.push(MedialAxisEdge {
+            start,
+            end,
+            points: remove_collinear(chain_points, eps),
+        });
+    }
+
+    result
+}
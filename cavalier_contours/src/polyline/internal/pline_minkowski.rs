@@ -0,0 +1,248 @@
+//! Minkowski sum of a path polyline with a convex shape.
+//!
+//! The sum is the union of the shape swept along every segment of the path. The shape is first
+//! replaced by its convex hull (counter clockwise) so the sweep along a line segment is the convex
+//! hull of the shape placed at both segment end points.
+//!
+//! Along an arc segment the shape is swept exactly when it only has line segments: every point of
+//! the swept region is either in the shape placed at the arc start or on the boundary of the shape
+//! at some position along the arc, so the region is the union of the shape at the arc start with
+//! every shape edge swept along the arc. The arc is first split where its tangent is parallel to a
+//! shape edge, each edge then sweeps a region bounded by two copies of the sub arc and two copies
+//! of the edge that does not cross itself. Shapes with arc segments are swept along line
+//! approximations of the path arcs instead.
+//!
+//! All the pieces are merged by boolean union.
+
+use crate::{
+    core::{
+        math::{
+            Vector2, angle, angle_from_bulge, bulge_from_angle, normalize_radians, point_on_circle,
+        },
+        traits::Real,
+    },
+    polyline::{
+        BooleanOp, PlineCreation, PlineMinkowskiOptions, PlineSource, PlineSourceMut, PlineVertex,
+        Polyline, seg_arc_radius_and_center,
+    },
+    shape_algorithms::{Shape, ShapeBooleanOptions},
+};
+
+use super::pline_convex_hull::{add_pline_elements, convex_hull};
+
+fn translated<T>(v: PlineVertex<T>, offset: Vector2<T>) -> PlineVertex<T>
+where
+    T: Real,
+{
+    PlineVertex::new(v.x + offset.x, v.y + offset.y, v.bulge)
+}
+
+/// Convex hull of `hull` placed at both `start` and `end`.
+fn line_sweep<T>(hull: &Polyline<T>, start: Vector2<T>, end: Vector2<T>, eps: T) -> Polyline<T>
+where
+    T: Real,
+{
+    let mut points = Vec::with_capacity(2 * hull.vertex_count());
+    let mut arcs = Vec::new();
+    for offset in [start, end] {
+        points.extend(hull.iter_vertexes().map(|v| v.pos() + offset));
+        arcs.extend(
+            hull.iter_segments()
+                .filter(|(v1, _)| !v1.bulge_is_zero())
+                .map(|(v1, v2)| (translated(v1, offset), translated(v2, offset))),
+        );
+    }
+
+    convex_hull(&points, &arcs, eps)
+}
+
+/// Split the arc segment from `v1` to `v2` at every point where its tangent is parallel to one of
+/// the `directions` (angles of the shape edges), returns the sub arcs as start vertex (with bulge)
+/// and end position.
+fn split_arc_at_directions<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    directions: &[T],
+    eps: T,
+) -> Vec<(PlineVertex<T>, Vector2<T>)>
+where
+    T: Real,
+{
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, v1.pos());
+    let sweep = angle_from_bulge(v1.bulge);
+    let total = num_traits::real::Real::abs(sweep);
+    // angle along the arc that is considered the same split point
+    let angle_eps = eps / radius;
+
+    // tangent is parallel to a direction where the radial direction is perpendicular to it
+    let mut splits = directions
+        .iter()
+        .flat_map(|&d| [d + T::pi() / T::two(), d - T::pi() / T::two()])
+        .map(|a| {
+            if v1.bulge_is_pos() {
+                normalize_radians(a - start_angle)
+            } else {
+                normalize_radians(start_angle - a)
+            }
+        })
+        .filter(|&t| t > angle_eps && t < total - angle_eps)
+        .collect::<Vec<_>>();
+    splits.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    splits.dedup_by(|a, b| *a - *b < angle_eps);
+    splits.push(total);
+
+    let sign = if v1.bulge_is_pos() {
+        T::one()
+    } else {
+        -T::one()
+    };
+    let mut result = Vec::with_capacity(splits.len());
+    let mut prev = T::zero();
+    let mut prev_pos = v1.pos();
+    for t in splits {
+        let end_pos = if t < total {
+            point_on_circle(radius, center, start_angle + sign * t)
+        } else {
+            v2.pos()
+        };
+        result.push((
+            PlineVertex::from_vector2(prev_pos, bulge_from_angle(sign * (t - prev))),
+            end_pos,
+        ));
+        prev = t;
+        prev_pos = end_pos;
+    }
+
+    result
+}
+
+/// Region swept by the shape edge from `k1` to `k2` along the sub arc starting at `arc_start` and
+/// ending at `arc_end`, returned counter clockwise.
+fn edge_arc_sweep<T>(
+    arc_start: PlineVertex<T>,
+    arc_end: Vector2<T>,
+    k1: Vector2<T>,
+    k2: Vector2<T>,
+) -> Polyline<T>
+where
+    T: Real,
+{
+    let mut result = Polyline::with_capacity(4, true);
+    result.add_vertex(PlineVertex::from_vector2(
+        arc_start.pos() + k1,
+        arc_start.bulge,
+    ));
+    result.add_vertex(PlineVertex::from_vector2(arc_end + k1, T::zero()));
+    result.add_vertex(PlineVertex::from_vector2(arc_end + k2, -arc_start.bulge));
+    result.add_vertex(PlineVertex::from_vector2(arc_start.pos() + k2, T::zero()));
+    if result.area() < T::zero() {
+        result.invert_direction_mut();
+    }
+    result
+}
+
+/// Merge all the `pieces` (counter clockwise closed polylines) by boolean union.
+fn union_all<T>(pieces: Vec<Polyline<T>>, pos_equal_eps: T) -> Shape<T>
+where
+    T: Real,
+{
+    let options = ShapeBooleanOptions {
+        pos_equal_eps,
+        ..Default::default()
+    };
+    let mut shapes = pieces
+        .into_iter()
+        .map(|p| Shape::from_plines(std::iter::once(p)))
+        .collect::<Vec<_>>();
+
+    // merge pairs so each union is between shapes of similar size
+    while shapes.len() > 1 {
+        let mut merged = Vec::with_capacity(shapes.len().div_ceil(2));
+        let mut iter = shapes.into_iter();
+        while let Some(a) = iter.next() {
+            match iter.next() {
+                Some(b) => merged.push(a.boolean_opt(&b, BooleanOp::Or, &options)),
+                None => merged.push(a),
+            }
+        }
+        shapes = merged;
+    }
+
+    shapes.pop().unwrap_or_else(Shape::empty)
+}
+
+/// Compute the Minkowski sum of `path` with the convex hull of `shape`.
+///
+/// See [`PlineSource::minkowski_sum_opt`] for more information.
+pub fn minkowski_sum<P, S, T, O>(path: &P, shape: &S, options: &PlineMinkowskiOptions<T>) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    S: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let eps = options.pos_equal_eps;
+    let hull = {
+        let mut points = Vec::new();
+        let mut arcs = Vec::new();
+        add_pline_elements(shape, &mut points, &mut arcs);
+        convex_hull::<Polyline<T>, T>(&points, &arcs, eps)
+    };
+
+    if path.vertex_count() == 0 || hull.vertex_count() < 2 || hull.area() <= eps * eps {
+        return Vec::new();
+    }
+
+    let hull_has_arcs = hull.iter_vertexes().any(|v| !v.bulge_is_zero());
+    let source = path;
+    let path = match source.remove_repeat_pos(eps) {
+        Some(cleaned) => Polyline::create_from(&cleaned),
+        None => Polyline::create_from(source),
+    };
+    let path = if hull_has_arcs {
+        path.arcs_to_approx_lines(options.arc_approx_error)
+            .unwrap_or(path)
+    } else {
+        path
+    };
+
+    let edge_directions = hull
+        .iter_segments()
+        .map(|(v1, v2)| angle(v1.pos(), v2.pos()))
+        .collect::<Vec<_>>();
+
+    let mut pieces = Vec::new();
+    if path.vertex_count() == 1 {
+        pieces.push(line_sweep(&hull, path[0].pos(), path[0].pos(), eps));
+    }
+
+    for (v1, v2) in path.iter_segments() {
+        if v1.bulge_is_zero() {
+            pieces.push(line_sweep(&hull, v1.pos(), v2.pos(), eps));
+            continue;
+        }
+
+        pieces.push(line_sweep(&hull, v1.pos(), v1.pos(), eps));
+        for (arc_start, arc_end) in split_arc_at_directions(v1, v2, &edge_directions, eps) {
+            for (k1, k2) in hull.iter_segments() {
+                pieces.push(edge_arc_sweep(arc_start, arc_end, k1.pos(), k2.pos()));
+            }
+        }
+    }
+
+    let result_shape = union_all(pieces, eps);
+    result_shape
+        .ccw_plines
+        .into_iter()
+        .chain(result_shape.cw_plines)
+        .map(|p| {
+            let mut pline = match p.polyline.remove_redundant(eps) {
+                Some(cleaned) => O::create_from(&cleaned),
+                None => O::create_from(&p.polyline),
+            };
+            pline.set_userdata_values(source.get_userdata_values());
+            pline
+        })
+        .collect()
+}
@@ -35,10 +35,11 @@ use crate::{
         traits::Real,
     },
     polyline::{
-        CoincidentSegmentBehavior, FindIntersectsOptions, PlineBasicIntersect, PlineCreation,
-        PlineIntersect, PlineIntersectFilterItem, PlineIntersectVisitor, PlineOffsetOptions,
-        PlineOverlappingIntersect, PlineSegIntr, PlineSource, PlineVertex, PlineViewData,
-        TouchingLoopBehavior, TwoPlinesIntersectFilterItem, dist_from_segment_start,
+        CoincidentSegmentBehavior, FindIntersectsOptions, OffsetCapType, OffsetJoinType,
+        PlineBasicIntersect, PlineCreation, PlineIntersect, PlineIntersectFilterItem,
+        PlineIntersectVisitor, PlineOffsetOptions, PlineOverlappingIntersect, PlineSegIntr,
+        PlineSource, PlineVertex, PlineViewData, TouchingLoopBehavior,
+        TwoPlinesIntersectFilterItem, dist_from_segment_start,
         internal::{
             pline_intersects::{
                 find_intersects, find_intersects_filtered, visit_global_self_intersects,
@@ -52,13 +53,28 @@ use crate::{
 };
 use static_aabb2d_index::{Control as AabbControl, StaticAABB2DIndex, StaticAABB2DIndexBuilder};
 
+/// Offset distance with the join and cap types that shape the region an offset covers around its
+/// source polyline.
+#[derive(Debug, Clone, Copy)]
+pub struct OffsetShape<T> {
+    /// Offset distance (only the magnitude is used).
+    pub offset: T,
+    /// Join type at the source polyline vertexes.
+    pub join_type: OffsetJoinType<T>,
+    /// Cap type at the ends of an open source polyline.
+    pub cap_type: OffsetCapType,
+}
+
 /// Returns whether `point` is farther from every source segment than the allowed offset distance.
 /// Invalid raw segment flags handle local folds, so `offset_tol` applies only to global distance
 /// checks.
+///
+/// For miter and bevel joins, or square and butt caps on open polylines, the region around each
+/// source vertex follows the join or cap shape rather than a circle.
 #[inline]
 pub fn point_valid_for_offset<P, T>(
     polyline: &P,
-    offset: T,
+    shape: OffsetShape<T>,
     aabb_index: &StaticAABB2DIndex<T>,
     point: Vector2<T>,
     query_stack: &mut Vec<usize>,
@@ -69,17 +85,113 @@ where
     P: PlineSource<Num = T> + ?Sized,
     T: Real,
 {
-    let abs_offset = offset.abs() - offset_tol;
-    let mut point_valid = true;
-    let mut visitor = |i: usize| {
+    let abs_offset = shape.offset.abs() - offset_tol;
+    let round_caps = polyline.is_closed() || shape.cap_type == OffsetCapType::Round;
+    if !matches!(shape.join_type, OffsetJoinType::Round) || !round_caps {
+        let shape = OffsetShape {
+            offset: abs_offset,
+            ..shape
+        };
+        return point_valid_for_shaped_offset(
+            polyline,
+            shape,
+            aabb_index,
+            point,
+            query_stack,
+            pos_equal_eps,
+        );
+    }
+
+    point_clear_of_segments(aabb_index, point, abs_offset, query_stack, |i| {
         let j = polyline.next_wrapping_index(i);
-        point_valid = seg_distance_is_greater_than(
+        seg_distance_is_greater_than(
             polyline.at(i),
             polyline.at(j),
             point,
             abs_offset,
             pos_equal_eps,
-        );
+        )
+    })
+}
+
+/// Returns whether `point` lies outside the region covered by offsetting `polyline` by the
+/// (positive) `shape.offset` distance with the join and cap types given.
+///
+/// The region is the union of a band of half width `shape.offset` along each segment (bounded by
+/// the segment end normals), the join shape at each vertex, and the cap shape at each end of an
+/// open polyline. Points on the band end normals are not covered so a bevel across a reversal or a
+/// butt cap remains valid.
+fn point_valid_for_shaped_offset<P, T>(
+    polyline: &P,
+    shape: OffsetShape<T>,
+    aabb_index: &StaticAABB2DIndex<T>,
+    point: Vector2<T>,
+    query_stack: &mut Vec<usize>,
+    pos_equal_eps: T,
+) -> bool
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let OffsetShape {
+        offset: dist,
+        join_type,
+        cap_type,
+    } = shape;
+    let query_dist = match join_type {
+        OffsetJoinType::Miter { limit } if limit > T::one() => dist * limit,
+        _ => dist,
+    };
+    // Square caps extend diagonally past the end point.
+    let query_dist = if cap_type == OffsetCapType::Square && !polyline.is_closed() {
+        num_traits::real::Real::max(query_dist, dist * T::two().sqrt())
+    } else {
+        query_dist
+    };
+    let is_closed = polyline.is_closed();
+    let last_index = polyline.vertex_count() - 1;
+
+    point_clear_of_segments(aabb_index, point, query_dist, query_stack, |i| {
+        let j = polyline.next_wrapping_index(i);
+        let v1 = polyline.at(i);
+        let v2 = polyline.at(j);
+        !point_within_seg_band(v1, v2, point, dist, pos_equal_eps)
+            && if is_closed || i != 0 {
+                !point_within_join(
+                    polyline.at(polyline.prev_wrapping_index(i)),
+                    v1,
+                    v2,
+                    point,
+                    dist,
+                    join_type,
+                )
+            } else {
+                let outward = -seg_tangent_vector(v1, v2, v1.pos());
+                !point_within_cap(v1.pos(), outward, point, dist, cap_type, pos_equal_eps)
+            }
+            && (is_closed || j != last_index || {
+                let outward = seg_tangent_vector(v1, v2, v2.pos());
+                !point_within_cap(v2.pos(), outward, point, dist, cap_type, pos_equal_eps)
+            })
+    })
+}
+
+/// Returns whether `segment_is_clear` holds for every source segment (given by its start index)
+/// with a bounding box within `query_dist` of `point`, stopping at the first segment it fails for.
+pub(crate) fn point_clear_of_segments<T, F>(
+    aabb_index: &StaticAABB2DIndex<T>,
+    point: Vector2<T>,
+    query_dist: T,
+    query_stack: &mut Vec<usize>,
+    mut segment_is_clear: F,
+) -> bool
+where
+    T: Real,
+    F: FnMut(usize) -> bool,
+{
+    let mut point_valid = true;
+    let mut visitor = |i: usize| {
+        point_valid = segment_is_clear(i);
         if point_valid {
             AabbControl::Continue
         } else {
@@ -88,16 +200,151 @@ where
     };
 
     aabb_index.visit_query_with_stack(
-        point.x - abs_offset,
-        point.y - abs_offset,
-        point.x + abs_offset,
-        point.y + abs_offset,
+        point.x - query_dist,
+        point.y - query_dist,
+        point.x + query_dist,
+        point.y + query_dist,
         &mut visitor,
         query_stack,
     );
     point_valid
 }
 
+/// Returns whether `point` is within the cap shape at the open polyline `end` point, `outward` is
+/// the tangent direction pointing away from the polyline.
+fn point_within_cap<T>(
+    end: Vector2<T>,
+    outward: Vector2<T>,
+    point: Vector2<T>,
+    dist: T,
+    cap_type: OffsetCapType,
+    pos_equal_eps: T,
+) -> bool
+where
+    T: Real,
+{
+    let point_vector = point - end;
+    match cap_type {
+        OffsetCapType::Round => point_vector.length_squared() < dist * dist,
+        OffsetCapType::Square => {
+            let outward = outward.normalize();
+            let along = point_vector.dot(outward);
+            along > -pos_equal_eps && along < dist && point_vector.perp_dot(outward).abs() < dist
+        }
+        OffsetCapType::Butt => false,
+    }
+}
+
+/// Returns whether `point` is closer than `dist` to the segment from `v1` to `v2` while strictly
+/// between the segment end normals (by more than `pos_equal_eps`).
+fn point_within_seg_band<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    point: Vector2<T>,
+    dist: T,
+    pos_equal_eps: T,
+) -> bool
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let direction = v2.pos() - v1.pos();
+        let length = direction.length();
+        if length < pos_equal_eps {
+            return false;
+        }
+        let point_vector = point - v1.pos();
+        let along = point_vector.dot(direction) / length;
+        let across = point_vector.perp_dot(direction) / length;
+        return along > pos_equal_eps && along < length - pos_equal_eps && across.abs() < dist;
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let point_vector = point - center;
+    let point_radius = point_vector.length();
+    // Points this close to the center are within `dist` of the whole arc.
+    if point_radius < dist - radius {
+        return true;
+    }
+    if (point_radius - radius).abs() >= dist {
+        return false;
+    }
+
+    // Strictly within the sweep: inside both boundary rays for sweeps up to a half circle, or
+    // inside either of them for larger sweeps.
+    let ray_eps = pos_equal_eps * radius;
+    let (start_cross, end_cross) = if v1.bulge_is_neg() {
+        (
+            -(v1.pos() - center).perp_dot(point_vector),
+            (v2.pos() - center).perp_dot(point_vector),
+        )
+    } else {
+        (
+            (v1.pos() - center).perp_dot(point_vector),
+            -(v2.pos() - center).perp_dot(point_vector),
+        )
+    };
+    if v1.bulge.abs() > T::one() {
+        start_cross > ray_eps || end_cross > ray_eps
+    } else {
+        start_cross > ray_eps && end_cross > ray_eps
+    }
+}
+
+/// Returns whether `point` is within the join shape at the vertex `v1` shared by the segment from
+/// `prev` to `v1` and the segment from `v1` to `v2`. Miter and bevel shapes lie on the outer side
+/// of the vertex.
+fn point_within_join<T>(
+    prev: PlineVertex<T>,
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    point: Vector2<T>,
+    dist: T,
+    join_type: OffsetJoinType<T>,
+) -> bool
+where
+    T: Real,
+{
+    let point_vector = point - v1.pos();
+    if matches!(join_type, OffsetJoinType::Round) {
+        return point_vector.length_squared() < dist * dist;
+    }
+
+    let incoming = seg_tangent_vector(prev, v1, v1.pos()).normalize();
+    let outgoing = seg_tangent_vector(v1, v2, v1.pos()).normalize();
+
+    // Only the cone beyond both adjacent bands is covered by the join.
+    if point_vector.dot(incoming) <= T::zero() || point_vector.dot(outgoing) >= T::zero() {
+        return false;
+    }
+
+    // Outer normals point away from the turn direction.
+    let turn = incoming.perp_dot(outgoing);
+    if turn.abs() <= T::fuzzy_epsilon() {
+        return false;
+    }
+    let (normal1, normal2) = if turn > T::zero() {
+        (-incoming.perp(), -outgoing.perp())
+    } else {
+        (incoming.perp(), outgoing.perp())
+    };
+
+    // |bisector| = 2 * cos(turn / 2), and the miter tip ratio is 1 / cos(turn / 2).
+    let bisector = normal1 + normal2;
+    let bisector_len_squared = bisector.length_squared();
+    if bisector_len_squared <= T::fuzzy_epsilon() {
+        return false;
+    }
+
+    if let OffsetJoinType::Miter { limit } = join_type
+        && limit * limit * bisector_len_squared >= T::four()
+    {
+        return point_vector.dot(normal1) < dist && point_vector.dot(normal2) < dist;
+    }
+
+    point_vector.dot(bisector) < dist * bisector_len_squared / T::two()
+}
+
 /// Returns whether the slice includes a raw segment marked locally invalid.
 ///
 /// `invalid_segments` is sorted. A non-wrapping slice needs one range lookup. A wrapping closed
@@ -144,9 +391,11 @@ where
     occurrences: &'a [ContactOccurrence<T>],
     /// Cached source-distance validity for repeated slice-boundary nodes.
     node_validity: Vec<Option<bool>>,
-    offset: T,
+    shape: OffsetShape<T>,
     pos_equal_eps: T,
     offset_dist_eps: T,
+    /// Open source end points when butt caps are used, slices may touch the source there.
+    butt_cap_ends: Option<[Vector2<T>; 2]>,
 }
 
 impl<'a, P, R, T> OffsetSliceValidator<'a, P, R, T>
@@ -177,9 +426,21 @@ where
             } else {
                 Vec::new()
             },
-            offset,
+            shape: OffsetShape {
+                offset,
+                join_type: options.join_type,
+                cap_type: options.cap_type,
+            },
             pos_equal_eps: options.pos_equal_eps,
             offset_dist_eps: options.offset_dist_eps,
+            butt_cap_ends: (options.cap_type == OffsetCapType::Butt
+                && !original_polyline.is_closed())
+            .then(|| {
+                [
+                    original_polyline.at(0).pos(),
+                    original_polyline.last().unwrap().pos(),
+                ]
+            }),
         }
     }
 
@@ -187,7 +448,7 @@ where
     fn point_is_valid(&self, point: Vector2<T>, query_stack: &mut Vec<usize>) -> bool {
         point_valid_for_offset(
             self.original_polyline,
-            self.offset,
+            self.shape,
             self.orig_polyline_index,
             point,
             query_stack,
@@ -229,19 +490,33 @@ where
         let approx_bb = seg_fast_approx_bounding_box(v1, v2);
         let original_polyline = self.original_polyline;
         let pos_equal_eps = self.pos_equal_eps;
+        // A butt cap runs across the source end point so touching it there is not a crossing.
+        let is_butt_cap_end = |point: Vector2<T>| {
+            self.butt_cap_ends.is_some_and(|ends| {
+                ends.iter()
+                    .any(|end| end.fuzzy_eq_eps(point, pos_equal_eps))
+            })
+        };
         let mut has_intersect = false;
         let mut visitor = |i: usize| {
             let j = original_polyline.next_wrapping_index(i);
-            has_intersect = !matches!(
-                pline_seg_intr(
+            has_intersect =
+                match pline_seg_intr(
                     v1,
                     v2,
                     original_polyline.at(i),
                     original_polyline.at(j),
-                    pos_equal_eps
-                ),
-                PlineSegIntr::NoIntersect
-            );
+                    pos_equal_eps,
+                ) {
+                    PlineSegIntr::NoIntersect => false,
+                    PlineSegIntr::TangentIntersect { point }
+                    | PlineSegIntr::OneIntersect { point } => !is_butt_cap_end(point),
+                    PlineSegIntr::TwoIntersects { point1, point2 } => {
+                        !is_butt_cap_end(point1) || !is_butt_cap_end(point2)
+                    }
+                    PlineSegIntr::OverlappingLines { .. }
+                    | PlineSegIntr::OverlappingArcs { .. } => true,
+                };
             if has_intersect {
                 AabbControl::Break(())
             } else {
@@ -1552,9 +1827,95 @@ fn visit_circle_intersects<P, T, F, V>(
     );
 }
 
+/// Visits intersections between polyline segments and the line segments of the open `path` after
+/// resolving each spatial-index item with `filter`.
+///
+/// Intersects at the start of a polyline segment are skipped, matching
+/// [`visit_circle_intersects`].
+fn visit_line_path_intersects<P, T, F, V>(
+    pline: &P,
+    path: &[Vector2<T>],
+    aabb_index: &StaticAABB2DIndex<T>,
+    pos_equal_eps: T,
+    query_stack: &mut Vec<usize>,
+    filter: &F,
+    visitor: &mut V,
+) where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    F: Fn(usize) -> Option<usize>,
+    V: FnMut(usize, Vector2<T>),
+{
+    for window in path.windows(2) {
+        let u1 = PlineVertex::from_vector2(window[0], T::zero());
+        let u2 = PlineVertex::from_vector2(window[1], T::zero());
+        let mut query_visitor = |item: usize| {
+            let Some(start_index) = filter(item) else {
+                return;
+            };
+            let v1 = pline.at(start_index);
+            let v2 = pline.at(pline.next_wrapping_index(start_index));
+            let mut visit_point = |point: Vector2<T>| {
+                if !v1.pos().fuzzy_eq_eps(point, pos_equal_eps) {
+                    visitor(start_index, point);
+                }
+            };
+            match pline_seg_intr(v1, v2, u1, u2, pos_equal_eps) {
+                PlineSegIntr::NoIntersect => {}
+                PlineSegIntr::TangentIntersect { point } | PlineSegIntr::OneIntersect { point } => {
+                    visit_point(point);
+                }
+                PlineSegIntr::TwoIntersects { point1, point2 }
+                | PlineSegIntr::OverlappingLines { point1, point2 }
+                | PlineSegIntr::OverlappingArcs { point1, point2 } => {
+                    visit_point(point1);
+                    visit_point(point2);
+                }
+            }
+        };
+
+        let (min_x, max_x) = min_max(window[0].x, window[1].x);
+        let (min_y, max_y) = min_max(window[0].y, window[1].y);
+        aabb_index.visit_query_with_stack(
+            min_x - pos_equal_eps,
+            min_y - pos_equal_eps,
+            max_x + pos_equal_eps,
+            max_y + pos_equal_eps,
+            &mut query_visitor,
+            query_stack,
+        );
+    }
+}
+
+/// Returns the clipping path across an open source end for square and butt caps.
+///
+/// `end` is the source end point, `outward` is the unit tangent pointing away from the source, and
+/// `dist` is the offset distance. Butt caps clip along the end normal, square caps clip along the
+/// three sides of the square extending past the end.
+fn cap_clip_path<T>(
+    end: Vector2<T>,
+    outward: Vector2<T>,
+    dist: T,
+    cap_type: OffsetCapType,
+) -> SmallVec<[Vector2<T>; 4]>
+where
+    T: Real,
+{
+    let normal = outward.perp().scale(dist);
+    let extension = outward.scale(dist);
+    let mut path = SmallVec::new();
+    path.push(end + normal);
+    if cap_type == OffsetCapType::Square {
+        path.push(end + normal + extension);
+        path.push(end - normal + extension);
+    }
+    path.push(end - normal);
+    path
+}
+
 /// Builds validated dissected slices when the opposite-side raw offset supplies clipping contacts.
 ///
-/// Primary/dual intersections and open-end circle contacts add only primary occurrences. They split
+/// Primary/dual intersections and open-end cap contacts add only primary occurrences. They split
 /// the primary raw offset but add no relation, so routing cannot move onto clipping geometry.
 /// Primary/dual overlap reports from collapsed regions do not add primary contact data.
 fn slices_from_dual_raw_offsets<P, R, T>(
@@ -1616,7 +1977,34 @@ where
 
     let mut query_stack = Vec::with_capacity(8);
 
-    if !original_polyline.is_closed() {
+    if !original_polyline.is_closed() && options.cap_type != OffsetCapType::Round {
+        // add intersects between cap shapes at original open polyline end points and raw offset
+        // polyline
+        let include_segment =
+            |item| resolve_valid_segment(item, raw_index_to_segment.as_deref(), invalid_segments);
+        let first = original_polyline.at(0);
+        let last_index = original_polyline.vertex_count() - 1;
+        let last = original_polyline.at(last_index);
+        let start_outward =
+            -seg_tangent_vector(first, original_polyline.at(1), first.pos()).normalize();
+        let end_outward =
+            seg_tangent_vector(original_polyline.at(last_index - 1), last, last.pos()).normalize();
+        for (end, outward) in [(first.pos(), start_outward), (last.pos(), end_outward)] {
+            let path = cap_clip_path(end, outward, offset.abs(), options.cap_type);
+            let mut add_intr = |segment_index, point| {
+                topology.add_clip(raw_offset_polyline, segment_index, point, pos_equal_eps);
+            };
+            visit_line_path_intersects(
+                raw_offset_polyline,
+                &path,
+                &raw_offset_index,
+                pos_equal_eps,
+                &mut query_stack,
+                &include_segment,
+                &mut add_intr,
+            );
+        }
+    } else if !original_polyline.is_closed() {
         // add intersects between circles generated at original open polyline end points and raw
         // offset polyline
         let circle_radius = offset.abs();
@@ -2096,7 +2484,8 @@ where
         &constructed_index
     };
 
-    let raw_offset: RawOffsetResult<O> = create_raw_offset(polyline, offset, options.pos_equal_eps);
+    let raw_offset: RawOffsetResult<O> =
+        create_raw_offset(polyline, offset, options.join_type, options.pos_equal_eps);
     if raw_offset.polyline.is_empty() {
         Vec::new()
     } else if polyline.is_closed() && !options.handle_self_intersects {
@@ -2104,7 +2493,7 @@ where
         stitch_slices_together(&raw_offset.polyline, &slices, true, options)
     } else {
         let dual_raw_offset: RawOffsetResult<O> =
-            create_raw_offset(polyline, -offset, options.pos_equal_eps);
+            create_raw_offset(polyline, -offset, options.join_type, options.pos_equal_eps);
         let slices = slices_from_dual_raw_offsets(
             polyline,
             &raw_offset,
@@ -2137,14 +2526,19 @@ where
         return vec![O::create_from(polyline)];
     }
 
-    // Sanitize repeat positions to prevent unstable/degenerate segments.
+    // Sanitize repeat positions to prevent unstable/degenerate segments and split arcs larger than
+    // a half circle.
     let mut result = if let Some(cleaned) = polyline.remove_repeat_pos(options.pos_equal_eps) {
         if cleaned.vertex_count() < 2 {
             Vec::<O>::new()
+        } else if let Some(split) = cleaned.split_large_arcs(options.pos_equal_eps) {
+            parallel_offset_for_source(&split, offset, options, false)
         } else {
             // user-provided aabb index is tied to the original polyline, rebuild for cleaned source
             parallel_offset_for_source(&cleaned, offset, options, false)
         }
+    } else if let Some(split) = polyline.split_large_arcs(options.pos_equal_eps) {
+        parallel_offset_for_source(&split, offset, options, false)
     } else {
         parallel_offset_for_source(polyline, offset, options, true)
     };
@@ -2156,6 +2550,241 @@ where
     result
 }
 
+/// Appends the cap from the `from` side point to the `to` side point around the open polyline
+/// `end` point, `outward` is the unit tangent pointing away from the polyline.
+///
+/// The vertex at `from` must already be the last vertex of `outline`. One flag is pushed to
+/// `invalid_segments` for each cap segment except the one ending at `to`, which is pushed by the
+/// caller (it either starts the next side or closes the outline).
+fn append_stroke_cap<O, T>(
+    outline: &mut O,
+    invalid_segments: &mut Vec<bool>,
+    from: Vector2<T>,
+    to: Vector2<T>,
+    outward: Vector2<T>,
+    distance: T,
+    cap_type: OffsetCapType,
+) where
+    O: PlineCreation<Num = T>,
+    T: Real,
+{
+    match cap_type {
+        OffsetCapType::Round => {
+            // half circle turning counter clockwise around the end point
+            outline.set_last(PlineVertex::from_vector2(from, T::one()));
+        }
+        OffsetCapType::Square => {
+            let extension = outward.scale(distance);
+            outline.set_last(PlineVertex::from_vector2(from, T::zero()));
+            outline.add_vertex(PlineVertex::from_vector2(from + extension, T::zero()));
+            outline.add_vertex(PlineVertex::from_vector2(to + extension, T::zero()));
+            invalid_segments.extend([false, false]);
+        }
+        OffsetCapType::Butt => {
+            outline.set_last(PlineVertex::from_vector2(from, T::zero()));
+        }
+    }
+}
+
+/// Creates the closed raw outline of an open polyline stroked with half width `distance`.
+///
+/// The outline runs counter clockwise: right side raw offset, end cap, reversed left side raw
+/// offset, then start cap. Invalid raw segment flags carry over from each side.
+fn create_stroke_outline<P, T, O>(
+    polyline: &P,
+    distance: T,
+    options: &PlineOffsetOptions<T>,
+) -> RawOffsetResult<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let first = polyline.at(0);
+    let last_index = polyline.vertex_count() - 1;
+    let last = polyline.at(last_index);
+    let start_tangent = seg_tangent_vector(first, polyline.at(1), first.pos()).normalize();
+    let end_tangent = seg_tangent_vector(polyline.at(last_index - 1), last, last.pos()).normalize();
+    let start_left = first.pos() + start_tangent.perp().scale(distance);
+    let start_right = first.pos() - start_tangent.perp().scale(distance);
+    let end_left = last.pos() + end_tangent.perp().scale(distance);
+    let end_right = last.pos() - end_tangent.perp().scale(distance);
+
+    let right: RawOffsetResult<O> = create_raw_offset(
+        polyline,
+        -distance,
+        options.join_type,
+        options.pos_equal_eps,
+    );
+    let mut left: RawOffsetResult<O> =
+        create_raw_offset(polyline, distance, options.join_type, options.pos_equal_eps);
+    left.polyline.invert_direction_mut();
+    left.invalid_segments.reverse();
+
+    let capacity = right.polyline.vertex_count() + left.polyline.vertex_count() + 4;
+    let mut outline = O::with_capacity(capacity, true);
+    let mut invalid_segments = Vec::with_capacity(capacity);
+
+    // A side that fully collapsed (e.g. a lone arc with radius less than the distance) falls back
+    // to a straight invalid segment so the outline remains connected.
+    let append_side = |outline: &mut O,
+                       invalid_segments: &mut Vec<bool>,
+                       side: &RawOffsetResult<O>,
+                       side_start: Vector2<T>,
+                       side_end: Vector2<T>| {
+        if side.polyline.vertex_count() < 2 {
+            outline.add_vertex(PlineVertex::from_vector2(side_start, T::zero()));
+            outline.add_vertex(PlineVertex::from_vector2(side_end, T::zero()));
+            invalid_segments.push(true);
+        } else {
+            for i in 0..side.polyline.vertex_count() {
+                outline.add_vertex(side.polyline.at(i));
+            }
+            invalid_segments.extend_from_slice(&side.invalid_segments);
+        }
+    };
+
+    append_side(
+        &mut outline,
+        &mut invalid_segments,
+        &right,
+        start_right,
+        end_right,
+    );
+    append_stroke_cap(
+        &mut outline,
+        &mut invalid_segments,
+        end_right,
+        end_left,
+        end_tangent,
+        distance,
+        options.cap_type,
+    );
+    invalid_segments.push(false);
+    append_side(
+        &mut outline,
+        &mut invalid_segments,
+        &left,
+        end_left,
+        start_left,
+    );
+    append_stroke_cap(
+        &mut outline,
+        &mut invalid_segments,
+        start_left,
+        start_right,
+        -start_tangent,
+        distance,
+        options.cap_type,
+    );
+    invalid_segments.push(false);
+
+    debug_assert_eq!(invalid_segments.len(), outline.segment_count());
+    let invalid_segment_indexes = invalid_segments
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &invalid)| invalid.then_some(i))
+        .collect();
+
+    RawOffsetResult {
+        polyline: outline,
+        invalid_segments,
+        invalid_segment_indexes,
+    }
+}
+
+/// Runs the stroke stages for a prepared open source polyline and its spatial index.
+fn stroke_for_open_source<P, T, O>(
+    polyline: &P,
+    distance: T,
+    options: &PlineOffsetOptions<T>,
+    allow_external_index: bool,
+) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let constructed_index;
+    let index = match options.aabb_index {
+        Some(x) if allow_external_index => x,
+        _ => {
+            constructed_index = polyline.create_approx_aabb_index();
+            &constructed_index
+        }
+    };
+
+    let outline: RawOffsetResult<O> = create_stroke_outline(polyline, distance, options);
+    let slices = slices_from_raw_offset(polyline, &outline, index, distance, options);
+    stitch_slices_together(&outline.polyline, &slices, true, options)
+}
+
+/// Builds the closed outline of a polyline stroked with half width `distance` on both sides.
+///
+/// An open polyline produces the outline of both side offsets connected by end caps
+/// ([`PlineOffsetOptions::cap_type`]). A closed polyline produces its outward and inward offsets.
+/// Outer boundaries are counter clockwise and holes are clockwise. Segment joins follow
+/// [`PlineOffsetOptions::join_type`]. A `distance` that is not positive returns no polylines.
+pub fn stroke<P, T, O>(polyline: &P, distance: T, options: &PlineOffsetOptions<T>) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    if polyline.vertex_count() < 2 || distance <= T::zero() {
+        return Vec::new();
+    }
+
+    if polyline.is_closed() {
+        let is_ccw = polyline.area() >= T::zero();
+        let outward_offset = if is_ccw { -distance } else { distance };
+        let mut result: Vec<O> = parallel_offset(polyline, outward_offset, options);
+        let outer_count = result.len();
+        result.extend(parallel_offset::<_, _, O>(
+            polyline,
+            -outward_offset,
+            options,
+        ));
+        // offsets keep the source direction, flip so outer loops are counter clockwise and
+        // inner loops are clockwise
+        let flip_range = if is_ccw {
+            outer_count..result.len()
+        } else {
+            0..outer_count
+        };
+        for pline in &mut result[flip_range] {
+            pline.invert_direction_mut();
+        }
+        return result;
+    }
+
+    // Sanitize repeat positions to prevent unstable/degenerate segments and split arcs larger than
+    // a half circle.
+    let mut result = if let Some(cleaned) = polyline.remove_repeat_pos(options.pos_equal_eps) {
+        if cleaned.vertex_count() < 2 {
+            Vec::<O>::new()
+        } else if let Some(split) = cleaned.split_large_arcs(options.pos_equal_eps) {
+            stroke_for_open_source(&split, distance, options, false)
+        } else {
+            stroke_for_open_source(&cleaned, distance, options, false)
+        }
+    } else if let Some(split) = polyline.split_large_arcs(options.pos_equal_eps) {
+        stroke_for_open_source(&split, distance, options, false)
+    } else {
+        stroke_for_open_source(polyline, distance, options, true)
+    };
+
+    for pline in &mut result {
+        // square caps and straight sides leave collinear vertexes where they meet
+        if let Some(cleaned) = pline.remove_redundant(options.pos_equal_eps) {
+            *pline = O::create_from(&cleaned);
+        }
+        pline.set_userdata_values(polyline.get_userdata_values());
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
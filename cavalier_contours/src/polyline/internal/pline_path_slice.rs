@@ -0,0 +1,130 @@
+//! Slicing a polyline between two path length positions and finding the path length position of a
+//! point.
+
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{PlineSource, PlineViewData, seg_length, seg_split_at_point},
+};
+
+use super::pline_sample::sample_at_path_lengths;
+
+/// Point and the index of the segment it lies on at `path_length` along `pline`.
+fn point_at<P, T>(pline: &P, path_length: T, pos_equal_eps: T) -> Option<(usize, Vector2<T>)>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    sample_at_path_lengths(pline, [path_length], pos_equal_eps)
+        .first()
+        .map(|s| (s.seg_index, s.point))
+}
+
+/// Create view data for the part of `pline` between the path lengths `start` and `end`.
+///
+/// See [`PlineSource::slice_by_path_length_opt`] for more information.
+pub fn slice_by_path_length<P, T>(
+    pline: &P,
+    start: T,
+    end: T,
+    pos_equal_eps: T,
+) -> Option<PlineViewData<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if pline.vertex_count() < 2 || !start.is_finite() || !end.is_finite() {
+        return None;
+    }
+
+    let total = pline.path_length();
+    if total.fuzzy_eq_zero_eps(pos_equal_eps) {
+        return None;
+    }
+
+    if !pline.is_closed() {
+        let start = num_traits::clamp(start, T::zero(), total);
+        let end = num_traits::clamp(end, T::zero(), total);
+        if end - start <= pos_equal_eps {
+            return None;
+        }
+
+        let (start_index, start_point) = point_at(pline, start, pos_equal_eps)?;
+        let (end_index, end_point) = point_at(pline, end, pos_equal_eps)?;
+        return PlineViewData::from_slice_points(
+            pline,
+            start_point,
+            start_index,
+            end_point,
+            end_index,
+            pos_equal_eps,
+        );
+    }
+
+    let wrap = |length: T| {
+        let wrapped = length % total;
+        if wrapped < T::zero() {
+            wrapped + total
+        } else {
+            wrapped
+        }
+    };
+
+    let (start_index, start_point) = point_at(pline, wrap(start), pos_equal_eps)?;
+    if end - start >= total - pos_equal_eps {
+        // entire loop from the start point
+        return PlineViewData::from_new_start(pline, start_point, start_index, pos_equal_eps);
+    }
+
+    let end = wrap(end);
+    // end at the start of the loop is the end of the last segment
+    let end = if end.fuzzy_eq_zero_eps(pos_equal_eps) {
+        total
+    } else {
+        end
+    };
+
+    if (end - wrap(start)).fuzzy_eq_zero_eps(pos_equal_eps) {
+        return None;
+    }
+
+    let (end_index, end_point) = point_at(pline, end, pos_equal_eps)?;
+    PlineViewData::from_slice_points(
+        pline,
+        start_point,
+        start_index,
+        end_point,
+        end_index,
+        pos_equal_eps,
+    )
+}
+
+/// Find the path length along `pline` to the closest point on `pline` to `point`.
+///
+/// See [`PlineSource::find_path_length_at_point`] for more information.
+pub fn find_path_length_at_point<P, T>(pline: &P, point: Vector2<T>, pos_equal_eps: T) -> Option<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let closest = pline.closest_point(point, pos_equal_eps)?;
+    if pline.vertex_count() < 2 {
+        return Some(T::zero());
+    }
+
+    let i = closest.seg_start_index;
+    let before = pline
+        .iter_segments()
+        .take(i)
+        .fold(T::zero(), |acc, (v1, v2)| acc + seg_length(v1, v2));
+
+    let v1 = pline.at(i);
+    let v2 = pline.at(pline.next_wrapping_index(i));
+    let partial = if v1.pos().fuzzy_eq_eps(closest.seg_point, pos_equal_eps) {
+        T::zero()
+    } else {
+        let split = seg_split_at_point(v1, v2, closest.seg_point, pos_equal_eps);
+        seg_length(split.updated_start, split.split_vertex)
+    };
+
+    Some(before + partial)
+}
@@ -0,0 +1,132 @@
+//! Repairing messy (e.g. imported) polylines into valid non-self-intersecting closed polylines.
+//!
+//! The repair is done in stages:
+//! 1. vertexes with NaN or infinite values are discarded,
+//! 2. open polylines that nearly close are closed (others are discarded),
+//! 3. repeat positions and spikes (line segments doubling back on the previous line segment) are
+//!    removed, then redundant vertexes are removed,
+//! 4. self intersects are resolved using the fill rule (splitting figure eights into separate
+//!    loops and setting orientation), discarding loops with near zero area (slivers).
+
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{
+        PlineCreation, PlineFillOptions, PlineRepairOptions, PlineSource, PlineVertex, Polyline,
+    },
+};
+
+use super::pline_fill::resolve_self_intersects;
+
+/// Repair `polyline` returning valid non-self-intersecting closed polylines.
+///
+/// Result polylines that bound filled area are counter clockwise and polylines that bound holes
+/// are clockwise. Returns an empty vector if the polyline is open and does not nearly close or
+/// nothing with area remains after the repair.
+pub fn repair<P, O, T>(polyline: &P, options: &PlineRepairOptions<T>) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let mut vertexes = polyline
+        .iter_vertexes()
+        .filter(|v| v.x.is_finite() && v.y.is_finite() && v.bulge.is_finite())
+        .collect::<Vec<_>>();
+
+    if vertexes.len() < 2 {
+        return Vec::new();
+    }
+
+    if !polyline.is_closed() {
+        let gap = (vertexes[vertexes.len() - 1].pos() - vertexes[0].pos()).length();
+        if gap > options.close_tolerance {
+            return Vec::new();
+        }
+        // last vertex bulge of an open polyline is not used, close with a line segment
+        vertexes.last_mut().unwrap().bulge = T::zero();
+    }
+
+    let cleaned = remove_repeats_and_spikes(vertexes, options.pos_equal_eps);
+    if cleaned.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut pline = Polyline::from_iter(cleaned.into_iter(), true);
+    if let Some(r) = pline.remove_redundant(options.pos_equal_eps) {
+        pline = r;
+    }
+    // resolve copies the userdata to the result polylines
+    pline.set_userdata_values(polyline.get_userdata_values());
+
+    let fill_options = PlineFillOptions {
+        aabb_index: None,
+        pos_equal_eps: options.pos_equal_eps,
+        collapsed_area_eps: Some(options.collapsed_area_eps),
+    };
+
+    resolve_self_intersects(&pline, options.fill_rule, &fill_options)
+}
+
+/// Returns true if `v2` is the tip of a spike, that is the line segments `v1->v2` and `v2->v3` are
+/// collinear (within `eps` distance) and in opposite directions.
+fn is_spike<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, v3: PlineVertex<T>, eps: T) -> bool
+where
+    T: Real,
+{
+    if !v1.bulge_is_zero() || !v2.bulge_is_zero() {
+        return false;
+    }
+
+    let dir1: Vector2<T> = v2.pos() - v1.pos();
+    let dir2 = v3.pos() - v2.pos();
+    if dir1.dot(dir2) >= T::zero() {
+        return false;
+    }
+
+    // distance of v3 from the line through v1 and v2
+    num_traits::real::Real::abs(dir1.perp_dot(dir2)) <= eps * dir1.length()
+}
+
+/// Remove repeat positions and spikes from the vertexes of a closed polyline, removing a vertex
+/// may create a new spike so removal is repeated until none remain.
+fn remove_repeats_and_spikes<T>(vertexes: Vec<PlineVertex<T>>, eps: T) -> Vec<PlineVertex<T>>
+where
+    T: Real,
+{
+    let mut result: Vec<PlineVertex<T>> = Vec::with_capacity(vertexes.len());
+    for v in vertexes {
+        // each removal changes the last vertex so check again
+        loop {
+            let n = result.len();
+            if n > 0 && result[n - 1].pos().fuzzy_eq_eps(v.pos(), eps) {
+                // zero length segment, v replaces the last vertex
+                result.pop();
+            } else if n > 1 && is_spike(result[n - 2], result[n - 1], v, eps) {
+                result.pop();
+            } else {
+                break;
+            }
+        }
+        result.push(v);
+    }
+
+    // closing segment wraps around to the start
+    loop {
+        let n = result.len();
+        if n < 3 {
+            break;
+        }
+
+        if result[n - 1].pos().fuzzy_eq_eps(result[0].pos(), eps)
+            || is_spike(result[n - 2], result[n - 1], result[0], eps)
+        {
+            result.pop();
+        } else if is_spike(result[n - 1], result[0], result[1], eps) {
+            result.remove(0);
+        } else {
+            break;
+        }
+    }
+
+    result
+}
@@ -0,0 +1,282 @@
+//! Sampling points along a polyline path and resampling a polyline into segments of equal path
+//! length.
+
+use crate::{
+    core::{
+        math::{Vector2, angle, angle_from_bulge, point_on_circle},
+        traits::Real,
+    },
+    polyline::{
+        PathSample, PlineCreation, PlineResampleOptions, PlineSampleOptions, PlineSource,
+        PlineVertex, seg_arc_radius_and_center, seg_length, seg_split_at_point, seg_tangent_vector,
+        three_point_bulge,
+    },
+};
+
+/// Point at parametric value `t` (fraction of the segment length) on the segment `v1` to `v2`.
+fn seg_point_at<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, t: T) -> Vector2<T>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        return v1.pos() + (v2.pos() - v1.pos()).scale(t);
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, v1.pos());
+    point_on_circle(radius, center, start_angle + angle_from_bulge(v1.bulge) * t)
+}
+
+/// Sample `pline` at each of the `path_lengths` given, `path_lengths` must be in ascending order
+/// and values outside of the path are clamped to the path ends. Segments with zero length (within
+/// `pos_equal_eps`) are skipped and no samples are returned if the path has zero length.
+pub fn sample_at_path_lengths<P, T, I>(
+    pline: &P,
+    path_lengths: I,
+    pos_equal_eps: T,
+) -> Vec<PathSample<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    I: IntoIterator<Item = T>,
+{
+    let mut result = Vec::new();
+    let mut segments = pline
+        .iter_segments()
+        .enumerate()
+        .map(|(i, (v1, v2))| (i, v1, v2, seg_length(v1, v2)))
+        .filter(|&(_, _, _, len)| !len.fuzzy_eq_zero_eps(pos_equal_eps));
+
+    let Some(mut current) = segments.next() else {
+        return result;
+    };
+
+    let mut acc_length = T::zero();
+    for target in path_lengths {
+        while acc_length + current.3 < target {
+            let Some(next) = segments.next() else {
+                break;
+            };
+            acc_length = acc_length + current.3;
+            current = next;
+        }
+
+        let (seg_index, v1, v2, len) = current;
+        let t = num_traits::clamp((target - acc_length) / len, T::zero(), T::one());
+        let point = seg_point_at(v1, v2, t);
+        let tangent = seg_tangent_vector(v1, v2, point).normalize();
+        result.push(PathSample {
+            seg_index,
+            path_length: acc_length + t * len,
+            point,
+            tangent,
+            normal: tangent.perp(),
+        });
+    }
+
+    result
+}
+
+/// Sample `count` equally spaced points along `pline`.
+///
+/// See [`PlineSource::sample_points_opt`] for more information.
+pub fn sample_points<P, T>(
+    pline: &P,
+    count: usize,
+    options: &PlineSampleOptions<T>,
+) -> Vec<PathSample<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let total = pline.path_length();
+    if count == 0 || total.fuzzy_eq_zero_eps(eps) {
+        return Vec::new();
+    }
+
+    let divisions = if pline.is_closed() { count } else { count - 1 };
+    let step = if divisions == 0 {
+        T::zero()
+    } else {
+        total / T::from(divisions).unwrap()
+    };
+
+    let lengths = (0..count).map(|k| {
+        if divisions > 0 && k == divisions {
+            total
+        } else {
+            T::from(k).unwrap() * step
+        }
+    });
+
+    sample_at_path_lengths(pline, lengths, eps)
+}
+
+/// Sample points along `pline` at a fixed `spacing`.
+///
+/// See [`PlineSource::sample_at_spacing_opt`] for more information.
+pub fn sample_at_spacing<P, T>(
+    pline: &P,
+    spacing: T,
+    options: &PlineSampleOptions<T>,
+) -> Vec<PathSample<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let eps = options.pos_equal_eps;
+    let total = pline.path_length();
+    if spacing <= T::zero() || total.fuzzy_eq_zero_eps(eps) {
+        return Vec::new();
+    }
+
+    // number of whole steps along the path (within epsilon of the end)
+    let mut steps = num_traits::real::Real::floor(total / spacing);
+    if ((steps + T::one()) * spacing).fuzzy_eq_eps(total, eps) {
+        steps = steps + T::one();
+    }
+
+    if pline.is_closed() && (steps * spacing).fuzzy_eq_eps(total, eps) {
+        // last point coincides with the start
+        steps = steps - T::one();
+    }
+
+    let Some(steps) = steps.to_usize() else {
+        return Vec::new();
+    };
+
+    let lengths = (0..=steps).map(|k| {
+        let length = T::from(k).unwrap() * spacing;
+        if length > total { total } else { length }
+    });
+
+    sample_at_path_lengths(pline, lengths, eps)
+}
+
+/// Resample `pline` into segments of equal path length no longer than `max_segment_length`.
+///
+/// See [`PlineSource::resample_opt`] for more information.
+pub fn resample<P, T, O>(pline: &P, max_segment_length: T, options: &PlineResampleOptions<T>) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let eps = options.pos_equal_eps;
+    let total = pline.path_length();
+    if max_segment_length <= T::zero() || total.fuzzy_eq_zero_eps(eps) {
+        return O::create_from(pline);
+    }
+
+    let min_count = if pline.is_closed() { 2 } else { 1 };
+    let count = num_traits::real::Real::ceil(total / max_segment_length)
+        .to_usize()
+        .unwrap_or(min_count)
+        .max(min_count);
+    let step = total / T::from(count).unwrap();
+    let sample_length = |k: usize| T::from(k).unwrap() * step;
+
+    // polyline split at every sample (same path) with a flag marking the sample vertexes
+    let mut split = Vec::with_capacity(pline.vertex_count() + count);
+    let mut next_sample = 0;
+    let mut acc_length = T::zero();
+    for (v1, v2) in pline.iter_segments() {
+        let len = seg_length(v1, v2);
+        let mut current = v1;
+        let mut current_is_sample =
+            next_sample < count && sample_length(next_sample).fuzzy_eq_eps(acc_length, eps);
+        if current_is_sample {
+            next_sample += 1;
+        }
+
+        while next_sample < count && sample_length(next_sample) < acc_length + len - eps {
+            let point = seg_point_at(v1, v2, (sample_length(next_sample) - acc_length) / len);
+            let split_result = seg_split_at_point(current, v2, point, eps);
+            split.push((split_result.updated_start, current_is_sample));
+            current = split_result.split_vertex;
+            current_is_sample = true;
+            next_sample += 1;
+        }
+
+        split.push((current, current_is_sample));
+        acc_length = acc_length + len;
+    }
+
+    if !pline.is_closed() {
+        split.push((pline.last().unwrap(), true));
+    }
+
+    let mut result = if options.keep_vertexes {
+        let mut result = O::with_capacity(split.len(), pline.is_closed());
+        for &(v, _) in &split {
+            result.add_vertex(v);
+        }
+        result
+    } else {
+        let samples = split
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &(_, is_sample))| is_sample.then_some(i))
+            .collect::<Vec<_>>();
+        let mut result = O::with_capacity(samples.len(), pline.is_closed());
+        for (j, &i) in samples.iter().enumerate() {
+            let next = samples
+                .get(j + 1)
+                .copied()
+                .unwrap_or(samples[0] + split.len());
+            let v = split[i].0;
+            let bulge = span_bulge(&split, i, next, eps);
+            result.add(v.x, v.y, bulge);
+        }
+        result
+    };
+
+    result.set_userdata_values(pline.get_userdata_values());
+    result
+}
+
+/// Bulge of the segment replacing the span of `split` vertexes from index `start` to `end` (indexes
+/// past the end wrap around). The span segment follows the original path if there are no vertexes
+/// between its ends, if the span is all arc segments then it is an arc through the path midpoint of
+/// the span, otherwise it is a line segment.
+fn span_bulge<T>(split: &[(PlineVertex<T>, bool)], start: usize, end: usize, eps: T) -> T
+where
+    T: Real,
+{
+    if end == start + 1 {
+        return split[start].0.bulge;
+    }
+
+    let piece = |k: usize| (split[k % split.len()].0, split[(k + 1) % split.len()].0);
+    if (start..end).any(|k| piece(k).0.bulge_is_zero()) {
+        return T::zero();
+    }
+
+    let start_pos = split[start].0.pos();
+    let end_pos = split[end % split.len()].0.pos();
+    if start_pos.fuzzy_eq_eps(end_pos, eps) {
+        return T::zero();
+    }
+
+    let half_length = (start..end)
+        .map(|k| {
+            let (v1, v2) = piece(k);
+            seg_length(v1, v2)
+        })
+        .fold(T::zero(), |acc, len| acc + len)
+        / T::two();
+    let mut acc_length = T::zero();
+    let mut midpoint = end_pos;
+    for k in start..end {
+        let (v1, v2) = piece(k);
+        let len = seg_length(v1, v2);
+        if acc_length + len >= half_length && !len.fuzzy_eq_zero_eps(eps) {
+            midpoint = seg_point_at(v1, v2, (half_length - acc_length) / len);
+            break;
+        }
+        acc_length = acc_length + len;
+    }
+
+    three_point_bulge(start_pos, midpoint, end_pos)
+}
@@ -0,0 +1,416 @@
+//! Simplifying a polyline by removing vertexes while staying within a distance of the original.
+//!
+//! Both algorithms decide which vertexes of the original polyline to keep. A span of the original
+//! polyline between two kept vertexes is replaced by a line segment (or left unchanged if it is a
+//! single segment, keeping arcs as arcs). A span may only be replaced if the original path, including
+//! the arc segments along it, lies within the tolerance of the line segment replacing it.
+//!
+//! To preserve topology the result is checked for self intersects and the vertex furthest from the
+//! segment replacing each span involved in a self intersect is kept, repeating until no self
+//! intersects remain.
+
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::{
+    core::{
+        math::{Vector2, line_seg_closest_point, point_within_arc_sweep},
+        traits::Real,
+    },
+    polyline::{
+        PlineCreation, PlineIntersect, PlineSelfIntersectOptions, PlineSimplifyOptions,
+        PlineSource, PlineSourceMut, Polyline, SelfIntersectsInclude, SimplifyMethod,
+        seg_arc_radius_and_center,
+    },
+};
+
+/// Simplify `polyline` so the original path is within `tolerance` of the result.
+pub fn simplify<P, O, T>(polyline: &P, tolerance: T, options: &PlineSimplifyOptions<T>) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let vc = polyline.vertex_count();
+    if vc <= min_vertex_count(polyline) {
+        return O::create_from(polyline);
+    }
+
+    let tolerance = num_traits::real::Real::abs(tolerance);
+    let eps = options.pos_equal_eps;
+    let mut keep = match options.method {
+        SimplifyMethod::DouglasPeucker => douglas_peucker(polyline, tolerance, eps),
+        SimplifyMethod::Visvalingam => visvalingam(polyline, tolerance, eps),
+    };
+
+    let mut result = build_result::<P, O, T>(polyline, &keep);
+    if !options.preserve_topology {
+        return result;
+    }
+
+    let self_intersect_options = PlineSelfIntersectOptions {
+        aabb_index: None,
+        pos_equal_eps: eps,
+        include: SelfIntersectsInclude::All,
+    };
+    if polyline.scan_for_self_intersect_opt(&self_intersect_options) {
+        // nothing to preserve
+        return result;
+    }
+
+    while result.scan_for_self_intersect_opt(&self_intersect_options) {
+        if !keep_at_self_intersects(polyline, &result, &mut keep, &self_intersect_options) {
+            break;
+        }
+        result = build_result::<P, O, T>(polyline, &keep);
+    }
+
+    result
+}
+
+/// Minimum vertex count of the simplified polyline.
+fn min_vertex_count<P>(polyline: &P) -> usize
+where
+    P: PlineSource + ?Sized,
+{
+    if polyline.is_closed() { 3 } else { 2 }
+}
+
+/// Returns the span end index of the segment starting at `kept[k]` (may be greater than the vertex
+/// count for the closing segment of a closed polyline) or `None` if it is the last vertex of an open
+/// polyline.
+fn span_end<P>(polyline: &P, kept: &[usize], k: usize) -> Option<usize>
+where
+    P: PlineSource + ?Sized,
+{
+    if k + 1 < kept.len() {
+        Some(kept[k + 1])
+    } else if polyline.is_closed() {
+        Some(kept[0] + polyline.vertex_count())
+    } else {
+        None
+    }
+}
+
+/// Create the result polyline from the vertexes to keep.
+fn build_result<P, O, T>(polyline: &P, keep: &[bool]) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let kept = kept_indexes(keep);
+    let mut result = O::with_capacity(kept.len(), polyline.is_closed());
+    result.set_userdata_values(polyline.get_userdata_values());
+    for (k, &i) in kept.iter().enumerate() {
+        let v = polyline.at(i);
+        match span_end(polyline, &kept, k) {
+            // single segment span is kept unchanged
+            Some(j) if j == i + 1 => result.add_vertex(v),
+            Some(_) => result.add(v.x, v.y, T::zero()),
+            None => result.add_vertex(v),
+        }
+    }
+
+    result
+}
+
+fn kept_indexes(keep: &[bool]) -> Vec<usize> {
+    keep.iter()
+        .enumerate()
+        .filter_map(|(i, &k)| k.then_some(i))
+        .collect()
+}
+
+/// Distance from `point` to the line segment from `start` to `end`.
+fn dist_to_chord<T>(start: Vector2<T>, end: Vector2<T>, point: Vector2<T>) -> T
+where
+    T: Real,
+{
+    (line_seg_closest_point(start, end, point) - point).length()
+}
+
+/// Max distance from the interior of the arc segment starting at vertex `k` to the line segment
+/// from `start` to `end`, zero if the segment is a line.
+fn arc_deviation<P, T>(polyline: &P, start: Vector2<T>, end: Vector2<T>, k: usize, eps: T) -> T
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let vc = polyline.vertex_count();
+    let v1 = polyline.at(k % vc);
+    let v2 = polyline.at((k + 1) % vc);
+    if v1.bulge_is_zero() {
+        return T::zero();
+    }
+
+    // furthest points on the arc from the chord are at the arc end points, perpendicular to the
+    // chord, or opposite of the chord end points (if within the arc sweep)
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let chord_dir = end - start;
+    let mut directions = Vec::with_capacity(4);
+    if chord_dir.length() > eps {
+        let normal = chord_dir.unit_perp();
+        directions.push(normal);
+        directions.push(-normal);
+    }
+    for p in [start, end] {
+        let d = center - p;
+        let d_length = d.length();
+        if d_length > eps {
+            directions.push(d.scale(T::one() / d_length));
+        }
+    }
+
+    directions
+        .into_iter()
+        .map(|d| center + d.scale(radius))
+        .filter(|&p| point_within_arc_sweep(center, v1.pos(), v2.pos(), v1.bulge_is_neg(), p, eps))
+        .map(|p| dist_to_chord(start, end, p))
+        .fold(T::zero(), num_traits::real::Real::max)
+}
+
+/// Max distance from the original path between vertex `i` and vertex `j` (`j` may be greater than
+/// the vertex count to wrap around a closed polyline) to the line segment between them.
+fn span_deviation<P, T>(polyline: &P, i: usize, j: usize, eps: T) -> T
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let vc = polyline.vertex_count();
+    let start = polyline.at(i % vc).pos();
+    let end = polyline.at(j % vc).pos();
+    (i..j)
+        .map(|k| {
+            let vertex_dist = dist_to_chord(start, end, polyline.at((k + 1) % vc).pos());
+            num_traits::real::Real::max(vertex_dist, arc_deviation(polyline, start, end, k, eps))
+        })
+        .fold(T::zero(), num_traits::real::Real::max)
+}
+
+/// Returns the index of the vertex between `i` and `j` (exclusive) furthest from the line segment
+/// between them or `None` if there are no vertexes between them.
+///
+/// Vertexes at the end points of an arc segment are as far as the furthest point on the arc so the
+/// arc segment is split out of the span.
+fn furthest_vertex<P, T>(polyline: &P, i: usize, j: usize, eps: T) -> Option<usize>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let vc = polyline.vertex_count();
+    let start = polyline.at(i % vc).pos();
+    let end = polyline.at(j % vc).pos();
+    (i + 1..j)
+        .map(|k| {
+            let dist = [
+                dist_to_chord(start, end, polyline.at(k % vc).pos()),
+                arc_deviation(polyline, start, end, k - 1, eps),
+                arc_deviation(polyline, start, end, k, eps),
+            ]
+            .into_iter()
+            .fold(T::zero(), num_traits::real::Real::max);
+            (k, dist)
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(k, _)| k % vc)
+}
+
+/// Returns the vertexes to keep using the Douglas-Peucker algorithm.
+fn douglas_peucker<P, T>(polyline: &P, tolerance: T, eps: T) -> Vec<bool>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let vc = polyline.vertex_count();
+    let mut keep = vec![false; vc];
+    keep[0] = true;
+
+    let mut stack = Vec::new();
+    if polyline.is_closed() {
+        // split closed polyline at the vertex furthest from the start
+        let start = polyline.at(0).pos();
+        let far = (1..vc)
+            .max_by(|&a, &b| {
+                let da = (polyline.at(a).pos() - start).length_squared();
+                let db = (polyline.at(b).pos() - start).length_squared();
+                da.total_cmp(&db)
+            })
+            .unwrap();
+        keep[far] = true;
+        stack.push((0, far));
+        stack.push((far, vc));
+    } else {
+        keep[vc - 1] = true;
+        stack.push((0, vc - 1));
+    }
+
+    while let Some((i, j)) = stack.pop() {
+        if j - i < 2 || span_deviation(polyline, i, j, eps) <= tolerance {
+            continue;
+        }
+        let k = furthest_vertex(polyline, i, j, eps).unwrap();
+        keep[k] = true;
+        stack.push((i, k));
+        stack.push((k, j));
+    }
+
+    if polyline.is_closed() {
+        // avoid collapsing a closed polyline to a line
+        while keep.iter().filter(|&&k| k).count() < 3 {
+            let kept = kept_indexes(&keep);
+            let Some(k) = (0..kept.len()).find_map(|k| {
+                furthest_vertex(polyline, kept[k], span_end(polyline, &kept, k)?, eps)
+            }) else {
+                break;
+            };
+            keep[k] = true;
+        }
+    }
+
+    keep
+}
+
+/// Vertex removal candidate for the Visvalingam-Whyatt algorithm, ordered so the smallest cost is
+/// popped first from a [`BinaryHeap`].
+struct RemovalCandidate<T> {
+    cost: T,
+    index: usize,
+    version: u32,
+}
+
+impl<T: Real> PartialEq for RemovalCandidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Real> Eq for RemovalCandidate<T> {}
+
+impl<T: Real> PartialOrd for RemovalCandidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Real> Ord for RemovalCandidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+/// Returns the vertexes to keep using the Visvalingam-Whyatt algorithm.
+fn visvalingam<P, T>(polyline: &P, tolerance: T, eps: T) -> Vec<bool>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let vc = polyline.vertex_count();
+    let is_closed = polyline.is_closed();
+    let mut prev = (0..vc).map(|i| (i + vc - 1) % vc).collect::<Vec<_>>();
+    let mut next = (0..vc).map(|i| (i + 1) % vc).collect::<Vec<_>>();
+    let mut versions = vec![0_u32; vc];
+    let mut keep = vec![true; vc];
+    let mut remaining = vc;
+    let mut scratch = Polyline::new_closed();
+
+    // span replacing vertex `v` if removed, end index wraps past the vertex count for closed
+    // polylines
+    let span = |v: usize, prev: &[usize], next: &[usize]| {
+        let i = prev[v];
+        let j = next[v];
+        if j > i { (i, j) } else { (i, j + vc) }
+    };
+
+    let mut candidate = |v: usize, prev: &[usize], next: &[usize], version: u32| {
+        if !is_closed && (v == 0 || v == vc - 1) {
+            return None;
+        }
+        let (i, j) = span(v, prev, next);
+        if span_deviation(polyline, i, j, eps) > tolerance {
+            return None;
+        }
+
+        // effective area is the area enclosed by the span and the line segment replacing it
+        scratch.clear();
+        for k in i..j {
+            scratch.add_vertex(polyline.at(k % vc));
+        }
+        scratch.add_vertex(polyline.at(j % vc).with_bulge(T::zero()));
+        Some(RemovalCandidate {
+            cost: num_traits::real::Real::abs(scratch.area()),
+            index: v,
+            version,
+        })
+    };
+
+    let mut heap = (0..vc)
+        .filter_map(|v| candidate(v, &prev, &next, 0))
+        .collect::<BinaryHeap<_>>();
+
+    let min_count = min_vertex_count(polyline);
+    while let Some(c) = heap.pop() {
+        if remaining <= min_count {
+            break;
+        }
+        let v = c.index;
+        if !keep[v] || c.version != versions[v] {
+            // stale candidate
+            continue;
+        }
+
+        keep[v] = false;
+        remaining -= 1;
+        let p = prev[v];
+        let n = next[v];
+        next[p] = n;
+        prev[n] = p;
+        for u in [p, n] {
+            versions[u] += 1;
+            if let Some(c) = candidate(u, &prev, &next, versions[u]) {
+                heap.push(c);
+            }
+        }
+    }
+
+    keep
+}
+
+/// Keep the furthest vertex in each span of `result` involved in a self intersect, returns false if
+/// no vertex was added.
+fn keep_at_self_intersects<P, R, T>(
+    polyline: &P,
+    result: &R,
+    keep: &mut [bool],
+    options: &PlineSelfIntersectOptions<T>,
+) -> bool
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut segments = Vec::new();
+    result.visit_self_intersects_opt(
+        &mut |intr: PlineIntersect<T>| match intr {
+            PlineIntersect::Basic(b) => segments.extend([b.start_index1, b.start_index2]),
+            PlineIntersect::Overlapping(o) => segments.extend([o.start_index1, o.start_index2]),
+        },
+        options,
+    );
+
+    let kept = kept_indexes(keep);
+    let mut added = false;
+    for k in segments {
+        let Some(j) = span_end(polyline, &kept, k) else {
+            continue;
+        };
+        if let Some(v) = furthest_vertex(polyline, kept[k], j, options.pos_equal_eps) {
+            added |= !keep[v];
+            keep[v] = true;
+        }
+    }
+
+    added
+}
@@ -0,0 +1,169 @@
+//! Affine transform of polylines.
+//!
+//! Line segments and arc segments under a transform that maps circles to circles (uniform scale,
+//! rotation, mirroring, and translation) are transformed exactly by transforming the vertex
+//! positions and negating the bulge values if the transform mirrors.
+//!
+//! Other transforms (non-uniform scale or shear) turn arc segments into ellipse arcs. Each ellipse
+//! arc is split at equal steps of the original arc angle into pieces of at most a quarter turn,
+//! each piece is then replaced by the arc passing through its end points and its parametric
+//! midpoint, recursively splitting the piece in half while the ellipse points at the quarter
+//! parameters are further than the allowed error from the arc.
+
+use crate::{
+    core::{
+        math::{
+            Affine2, Vector2, angle, angle_from_bulge, line_seg_closest_point, point_on_circle,
+        },
+        traits::Real,
+    },
+    polyline::{
+        PlineCreation, PlineSource, PlineSourceMut, PlineTransformOptions, PlineVertex,
+        seg_arc_radius_and_center, three_point_bulge,
+    },
+};
+
+/// Maximum number of times a piece of an ellipse arc is split in half.
+const MAX_SPLIT_DEPTH: usize = 16;
+
+/// Returns true if `transform` maps circles to circles (relative to the scale of the transform).
+fn maps_circles_to_circles<T>(transform: &Affine2<T>) -> bool
+where
+    T: Real,
+{
+    let scale = num_traits::real::Real::max(
+        transform.a * transform.a + transform.b * transform.b,
+        transform.c * transform.c + transform.d * transform.d,
+    );
+    transform.is_similarity_eps(T::fuzzy_epsilon() * scale)
+}
+
+/// Distance from `point` to the segment starting at `p0` with `bulge` and ending at `p1`, `point`
+/// is expected to be within the sweep of the segment.
+fn dist_to_seg<T>(p0: Vector2<T>, bulge: T, p1: Vector2<T>, point: Vector2<T>) -> T
+where
+    T: Real,
+{
+    if bulge.is_zero() {
+        return (point - line_seg_closest_point(p0, p1, point)).length();
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(
+        PlineVertex::from_vector2(p0, bulge),
+        PlineVertex::from_vector2(p1, T::zero()),
+    );
+    num_traits::real::Real::abs((point - center).length() - radius)
+}
+
+/// Arc segment mapped to an ellipse arc by a transform.
+struct EllipseArc<'a, T> {
+    center: Vector2<T>,
+    radius: T,
+    start_angle: T,
+    sweep: T,
+    transform: &'a Affine2<T>,
+    error: T,
+}
+
+impl<T> EllipseArc<'_, T>
+where
+    T: Real,
+{
+    /// Point on the ellipse arc at parameter `t` (0 at the start and 1 at the end).
+    fn point_at(&self, t: T) -> Vector2<T> {
+        self.transform.transform_point(point_on_circle(
+            self.radius,
+            self.center,
+            self.start_angle + t * self.sweep,
+        ))
+    }
+
+    /// Add arc segment vertexes approximating the ellipse arc from parameter `t0` (at `p0`) to
+    /// parameter `t1` (at `p1`), the vertex at `p1` is not added.
+    fn add_approx<O>(
+        &self,
+        result: &mut O,
+        (t0, p0): (T, Vector2<T>),
+        (t1, p1): (T, Vector2<T>),
+        depth: usize,
+    ) where
+        O: PlineSourceMut<Num = T>,
+    {
+        let tm = (t0 + t1) / T::two();
+        let pm = self.point_at(tm);
+        let bulge = three_point_bulge(p0, pm, p1);
+
+        if depth < MAX_SPLIT_DEPTH && !p0.fuzzy_eq(p1) {
+            let exceeds_error = [(t0 + tm) / T::two(), (tm + t1) / T::two()]
+                .into_iter()
+                .any(|t| dist_to_seg(p0, bulge, p1, self.point_at(t)) > self.error);
+            if exceeds_error {
+                self.add_approx(result, (t0, p0), (tm, pm), depth + 1);
+                self.add_approx(result, (tm, pm), (t1, p1), depth + 1);
+                return;
+            }
+        }
+
+        result.add(p0.x, p0.y, bulge);
+    }
+}
+
+/// Apply an affine `transform` to `pline`.
+///
+/// See [`PlineSource::transform_opt`] for more information.
+pub fn transform<P, T, O>(
+    pline: &P,
+    transform: &Affine2<T>,
+    options: &PlineTransformOptions<T>,
+) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let vc = pline.vertex_count();
+    let mut result = O::with_capacity(vc, pline.is_closed());
+    let mirrors = transform.determinant() < T::zero();
+    let exact = !options.approx_ellipse_arcs || maps_circles_to_circles(transform);
+
+    for i in 0..vc {
+        let v = pline.at(i);
+        let pos = transform.transform_point(v.pos());
+        let bulge = if mirrors { -v.bulge } else { v.bulge };
+        let is_last_open = i == vc - 1 && !pline.is_closed();
+        let next = pline.at(pline.next_wrapping_index(i));
+        if exact || is_last_open || v.bulge_is_zero() || v.pos().fuzzy_eq(next.pos()) {
+            result.add(pos.x, pos.y, bulge);
+            continue;
+        }
+
+        let (radius, center) = seg_arc_radius_and_center(v, next);
+        let sweep = angle_from_bulge(v.bulge);
+        let arc = EllipseArc {
+            center,
+            radius,
+            start_angle: angle(center, v.pos()),
+            sweep,
+            transform,
+            error: num_traits::real::Real::abs(options.ellipse_approx_error),
+        };
+
+        let quarter_turns =
+            num_traits::real::Real::ceil(num_traits::real::Real::abs(sweep) / (T::pi() / T::two()));
+        let count = quarter_turns.to_usize().unwrap_or(1).max(1);
+        let end_pos = transform.transform_point(next.pos());
+        let mut start = (T::zero(), pos);
+        for k in 1..=count {
+            let end = if k == count {
+                (T::one(), end_pos)
+            } else {
+                let t = T::from(k).unwrap() / T::from(count).unwrap();
+                (t, arc.point_at(t))
+            };
+            arc.add_approx(&mut result, start, end, 0);
+            start = end;
+        }
+    }
+
+    result
+}
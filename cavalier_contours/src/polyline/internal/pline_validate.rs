@@ -0,0 +1,116 @@
+//! Validating a polyline before using it as input to other operations.
+//!
+//! Each check records the indexes of the offending vertexes so problems can be located and fixed.
+//! Checks that require finite values (self intersects and area) are skipped if any vertex has a NaN
+//! or infinite value.
+
+use crate::{
+    core::traits::Real,
+    polyline::{
+        PlineIntersect, PlineIntersectsCollection, PlineSelfIntersectOptions, PlineSource,
+        PlineValidateOptions, PlineValidationReport, SelfIntersectsInclude,
+    },
+};
+
+/// Validate `polyline` returning a report of all the problems found.
+pub fn validate<P, T>(polyline: &P, options: &PlineValidateOptions<T>) -> PlineValidationReport<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut report = PlineValidationReport {
+        non_finite_vertexes: Vec::new(),
+        out_of_range_bulges: Vec::new(),
+        repeated_positions: Vec::new(),
+        zero_length_segments: Vec::new(),
+        self_intersects: PlineIntersectsCollection::new_empty(),
+        near_zero_area: false,
+    };
+
+    let vc = polyline.vertex_count();
+    let seg_count = polyline.segment_count();
+    for (i, v) in polyline.iter_vertexes().enumerate() {
+        if !v.x.is_finite() || !v.y.is_finite() || !v.bulge.is_finite() {
+            report.non_finite_vertexes.push(i);
+        } else if i < seg_count && num_traits::real::Real::abs(v.bulge) > options.max_abs_bulge {
+            report.out_of_range_bulges.push(i);
+        }
+    }
+
+    for (i, j) in polyline.iter_segment_indexes() {
+        if polyline
+            .at(i)
+            .pos()
+            .fuzzy_eq_eps(polyline.at(j).pos(), options.pos_equal_eps)
+        {
+            report.zero_length_segments.push(i);
+        }
+    }
+
+    report.repeated_positions = find_repeated_positions(polyline, options.pos_equal_eps);
+
+    if !report.non_finite_vertexes.is_empty() {
+        return report;
+    }
+
+    if vc > 1 {
+        let self_intersect_options = PlineSelfIntersectOptions {
+            aabb_index: options.aabb_index,
+            pos_equal_eps: options.pos_equal_eps,
+            include: SelfIntersectsInclude::All,
+        };
+        let intersects = &mut report.self_intersects;
+        polyline.visit_self_intersects_opt(
+            &mut |intr: PlineIntersect<T>| match intr {
+                PlineIntersect::Basic(b) => intersects.basic_intersects.push(b),
+                PlineIntersect::Overlapping(o) => intersects.overlapping_intersects.push(o),
+            },
+            &self_intersect_options,
+        );
+    }
+
+    report.near_zero_area = polyline.is_closed()
+        && num_traits::real::Real::abs(polyline.area()) < options.zero_area_eps;
+
+    report
+}
+
+/// Find all pairs of vertexes at the same position that are not the start and end of the same
+/// segment, vertexes with NaN or infinite positions are ignored.
+fn find_repeated_positions<P, T>(polyline: &P, pos_equal_eps: T) -> Vec<(usize, usize)>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let vc = polyline.vertex_count();
+    let is_segment = |i: usize, j: usize| {
+        j == i + 1 || (polyline.is_closed() && vc > 2 && i == 0 && j == vc - 1)
+    };
+
+    // sort by x to only compare vertexes within eps along x
+    let mut sorted = (0..vc)
+        .filter(|&i| {
+            let v = polyline.at(i);
+            v.x.is_finite() && v.y.is_finite()
+        })
+        .collect::<Vec<_>>();
+    sorted.sort_unstable_by(|&a, &b| polyline.at(a).x.total_cmp(&polyline.at(b).x));
+
+    let mut result = Vec::new();
+    for (k, &a) in sorted.iter().enumerate() {
+        let pos_a = polyline.at(a).pos();
+        for &b in &sorted[k + 1..] {
+            let pos_b = polyline.at(b).pos();
+            if pos_b.x - pos_a.x > pos_equal_eps {
+                break;
+            }
+            let (i, j) = if a < b { (a, b) } else { (b, a) };
+            if !is_segment(i, j) && pos_a.fuzzy_eq_eps(pos_b, pos_equal_eps) {
+                result.push((i, j));
+            }
+        }
+    }
+
+    result.sort_unstable();
+    result
+}
@@ -0,0 +1,635 @@
+//! Variable distance (tapered) parallel offset of polylines.
+//!
+//! Each source vertex has its own offset distance, interpolated linearly along every segment by
+//! path length. The offset is the envelope of circles centered on the polyline with the
+//! interpolated distance as radius. A raw offset polyline is created segment by segment: line
+//! segments offset to line segments exactly, arc segments with the same distance at both ends
+//! offset to arcs exactly, and arc segments with changing distance (which offset to spiral like
+//! curves) are approximated by short arcs within the spiral error tolerance. Vertexes are joined
+//! with round joins.
+//!
+//! The raw offset is then split at its self intersects and intersects with the source polyline
+//! (for open polylines also with the raw offset on the other side and the end cap circles). Slices
+//! whose points are closer to the source than the interpolated distance allows are discarded and
+//! the remaining slices are stitched together.
+
+use static_aabb2d_index::StaticAABB2DIndex;
+
+use crate::{
+    core::{
+        math::{Vector2, angle, angle_from_bulge, bulge_from_angle, dist_squared, point_on_circle},
+        traits::Real,
+    },
+    polyline::{
+        FindIntersectsOptions, PlineCreation, PlineSource, PlineSourceMut,
+        PlineVariableOffsetOptions, PlineVertex, Polyline, seg_arc_radius_and_center,
+        seg_closest_point, seg_is_large_arc, seg_midpoint, seg_tangent_vector, three_point_bulge,
+    },
+};
+
+use super::{
+    pline_boolean::stitch_slices,
+    pline_clip::{slices_at_split_points, sorted_split_points},
+    pline_intersects::{all_self_intersects_as_basic, find_intersects},
+    pline_offset::point_clear_of_segments,
+};
+
+/// Minimum number of approximating arcs used per half circle of sweep when offsetting an arc
+/// segment with changing distance.
+const MIN_SPIRAL_ARCS_PER_HALF_CIRCLE: u32 = 4;
+
+/// Maximum number of approximating arcs used per half circle of sweep when offsetting an arc
+/// segment with changing distance (bounds the work for very small error tolerances).
+const MAX_SPIRAL_ARCS_PER_HALF_CIRCLE: u32 = 1024;
+
+/// Number of pieces per half circle of sweep searched for the minimum distance to a tapered arc
+/// segment.
+const SEARCH_PIECES_PER_HALF_CIRCLE: u32 = 16;
+
+/// Iterations used in golden section searches for the minimum distance to a tapered segment.
+const GOLDEN_SECTION_ITERATIONS: usize = 48;
+
+/// Compute the variable distance parallel offset polylines of `polyline` using the per vertex
+/// `distances` given.
+///
+/// Positive distances offset to the left of the segment tangent vectors and negative distances
+/// offset to the right. All distances must have the same sign (zero is allowed) and `distances`
+/// must have one value per vertex, otherwise an empty vector is returned. All zero distances
+/// return a copy of the polyline. Joins between segments and caps at the ends of open polylines
+/// are always round.
+pub fn variable_parallel_offset<P, T, O>(
+    polyline: &P,
+    distances: &[T],
+    options: &PlineVariableOffsetOptions<T>,
+) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let vc = polyline.vertex_count();
+    if vc < 2 || distances.len() != vc {
+        return Vec::new();
+    }
+
+    let all_non_negative = distances.iter().all(|&d| d >= T::zero());
+    let all_non_positive = distances.iter().all(|&d| d <= T::zero());
+    if !(all_non_negative || all_non_positive) {
+        return Vec::new();
+    }
+    if all_non_negative && all_non_positive {
+        return vec![O::create_from(polyline)];
+    }
+
+    if let Some(split) = polyline.split_large_arcs(options.pos_equal_eps) {
+        // split arcs add vertexes, the distance at an arc midpoint is the average of its ends
+        let mut split_distances = Vec::with_capacity(split.vertex_count());
+        for (i, j) in polyline.iter_segment_indexes() {
+            split_distances.push(distances[i]);
+            if seg_is_large_arc(polyline.at(i), polyline.at(j), options.pos_equal_eps) {
+                split_distances.push((distances[i] + distances[j]) / T::two());
+            }
+        }
+        if !polyline.is_closed() {
+            split_distances.push(distances[vc - 1]);
+        }
+        let split_options = PlineVariableOffsetOptions {
+            aabb_index: None,
+            ..*options
+        };
+        return variable_parallel_offset(&split, &split_distances, &split_options);
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+    let spiral_error_tol = options.spiral_error_tol;
+
+    let constructed_index;
+    let source_index = if let Some(x) = options.aabb_index {
+        x
+    } else {
+        constructed_index = polyline.create_approx_aabb_index();
+        &constructed_index
+    };
+
+    let raw_offset =
+        create_variable_raw_offset(polyline, distances, pos_equal_eps, spiral_error_tol);
+    if raw_offset.vertex_count() < 2 {
+        return Vec::new();
+    }
+    let raw_offset_index = raw_offset.create_approx_aabb_index();
+
+    let mut points = Vec::new();
+    for intr in all_self_intersects_as_basic(&raw_offset, &raw_offset_index, true, pos_equal_eps) {
+        points.push((intr.start_index1, intr.point));
+        points.push((intr.start_index2, intr.point));
+    }
+    push_intersects(
+        &raw_offset,
+        &raw_offset_index,
+        polyline,
+        pos_equal_eps,
+        &mut points,
+    );
+    if !polyline.is_closed() {
+        let negated = distances.iter().map(|&d| -d).collect::<Vec<_>>();
+        let other_side =
+            create_variable_raw_offset(polyline, &negated, pos_equal_eps, spiral_error_tol);
+        push_intersects(
+            &raw_offset,
+            &raw_offset_index,
+            &other_side,
+            pos_equal_eps,
+            &mut points,
+        );
+        for (center, dist) in [
+            (polyline.at(0).pos(), distances[0]),
+            (polyline.at(vc - 1).pos(), distances[vc - 1]),
+        ] {
+            let radius = dist.abs();
+            if radius > pos_equal_eps {
+                let mut circle = Polyline::with_capacity(2, true);
+                circle.add(center.x - radius, center.y, T::one());
+                circle.add(center.x + radius, center.y, T::one());
+                push_intersects(
+                    &raw_offset,
+                    &raw_offset_index,
+                    &circle,
+                    pos_equal_eps,
+                    &mut points,
+                );
+            }
+        }
+    }
+
+    let split_points = sorted_split_points(&raw_offset, points.into_iter(), pos_equal_eps);
+    let slices = slices_at_split_points(&raw_offset, &split_points, pos_equal_eps);
+
+    let max_dist = distances.iter().fold(T::zero(), |acc, &d| {
+        num_traits::real::Real::max(acc, d.abs())
+    });
+    let validator = VariableDistValidator {
+        source: polyline,
+        distances,
+        source_index,
+        max_dist,
+        // approximated spirals may stray into the distance band by up to the error tolerance
+        offset_dist_eps: options.offset_dist_eps + spiral_error_tol,
+    };
+
+    let mut query_stack = Vec::with_capacity(8);
+    let valid_slices = slices
+        .into_iter()
+        .filter(|v_data| {
+            // slice end points lie on split points (which touch the distance band), test segment
+            // midpoints and the vertexes between segments
+            let view = v_data.view(&raw_offset);
+            view.iter_segments().enumerate().all(|(index, (v1, v2))| {
+                (index == 0 || validator.point_is_valid(v1.pos(), &mut query_stack))
+                    && validator.point_is_valid(seg_midpoint(v1, v2), &mut query_stack)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // prefer continuing along the raw offset
+    let slice_count = valid_slices.len();
+    let select = |_: &O, current_idx: usize, candidates: &[usize]| {
+        candidates
+            .iter()
+            .copied()
+            .min_by_key(|&j| (j + slice_count - current_idx - 1) % slice_count)
+    };
+    stitch_slices(
+        slice_count,
+        |i| valid_slices[i].view(&raw_offset),
+        select,
+        pos_equal_eps,
+        None,
+        !polyline.is_closed(),
+    )
+    .into_iter()
+    .map(|(mut pline, _)| {
+        pline.set_userdata_values(polyline.get_userdata_values());
+        pline
+    })
+    .collect()
+}
+
+/// Create the raw variable distance offset polyline (not trimmed at any intersects).
+fn create_variable_raw_offset<P, T>(
+    polyline: &P,
+    distances: &[T],
+    pos_equal_eps: T,
+    spiral_error_tol: T,
+) -> Polyline<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut result = Polyline::with_capacity(polyline.vertex_count(), polyline.is_closed());
+    // tangent of the previous non-degenerate segment at its end vertex
+    let mut prev_tangent: Option<Vector2<T>> = None;
+    let mut first_tangent: Option<Vector2<T>> = None;
+
+    for (i, j) in polyline.iter_segment_indexes() {
+        let v1 = polyline.at(i);
+        let v2 = polyline.at(j);
+        if v1.pos().fuzzy_eq_eps(v2.pos(), pos_equal_eps) {
+            continue;
+        }
+
+        let start_tangent = seg_tangent_vector(v1, v2, v1.pos());
+        let seg_offset = offset_segment(
+            v1,
+            v2,
+            distances[i],
+            distances[j],
+            pos_equal_eps,
+            spiral_error_tol,
+        );
+
+        if let Some(prev_tangent) = prev_tangent {
+            add_round_join(
+                &mut result,
+                v1.pos(),
+                distances[i],
+                prev_tangent,
+                start_tangent,
+                seg_offset[0].pos(),
+                pos_equal_eps,
+            );
+        } else {
+            first_tangent = Some(start_tangent);
+        }
+
+        for v in seg_offset {
+            result.add_or_replace_vertex(v, pos_equal_eps);
+        }
+        prev_tangent = Some(seg_tangent_vector(v1, v2, v2.pos()));
+    }
+
+    if polyline.is_closed()
+        && result.vertex_count() > 1
+        && let (Some(prev_tangent), Some(first_tangent)) = (prev_tangent, first_tangent)
+    {
+        let first_pos = result.at(0).pos();
+        add_round_join(
+            &mut result,
+            polyline.at(0).pos(),
+            distances[0],
+            prev_tangent,
+            first_tangent,
+            first_pos,
+            pos_equal_eps,
+        );
+        if result
+            .last()
+            .unwrap()
+            .pos()
+            .fuzzy_eq_eps(first_pos, pos_equal_eps)
+        {
+            result.remove_last();
+        }
+    }
+
+    result
+}
+
+/// Offset a single segment going from `v1` to `v2` with distance `dist1` at `v1` and `dist2` at
+/// `v2`. Returns the offset vertexes (the last vertex has zero bulge).
+///
+/// The offset is the envelope of the circles centered on the segment with radius interpolated
+/// between the distances, so it is tangent to the round joins at both ends. Each offset point is
+/// the segment point moved along the normal tilted by the rate of change of the distance. Arc
+/// segments with changing distance are approximated by arcs within `spiral_error_tol`.
+fn offset_segment<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    dist1: T,
+    dist2: T,
+    pos_equal_eps: T,
+    spiral_error_tol: T,
+) -> Vec<PlineVertex<T>>
+where
+    T: Real,
+{
+    let radius1 = dist1.abs();
+    let radius2 = dist2.abs();
+    // left offset for positive distances, right offset for negative distances
+    let side = if dist1 + dist2 > T::zero() {
+        T::one()
+    } else {
+        -T::one()
+    };
+    // unit direction from the segment point to the envelope point given the unit tangent
+    let tilted_normal = |tangent: Vector2<T>, slope: T| {
+        let cos = (T::one() - slope * slope).sqrt();
+        tangent.perp().scale(side * cos) - tangent.scale(slope)
+    };
+
+    if v1.bulge_is_zero() {
+        // line offset with linearly changing distance is still a line
+        let chord = v2.pos() - v1.pos();
+        let slope = clamp_unit((radius2 - radius1) / chord.length());
+        let dir = tilted_normal(chord.normalize(), slope);
+        let p1 = v1.pos() + dir.scale(radius1);
+        let p2 = v2.pos() + dir.scale(radius2);
+        return vec![
+            PlineVertex::from_vector2(p1, T::zero()),
+            PlineVertex::from_vector2(p2, T::zero()),
+        ];
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, v1.pos());
+    let sweep = angle_from_bulge(v1.bulge);
+
+    if radius1.fuzzy_eq_eps(radius2, pos_equal_eps) {
+        // left of a counter clockwise arc is toward the center
+        let offset_radius = if v1.bulge_is_pos() == (side > T::zero()) {
+            radius - radius1
+        } else {
+            radius + radius1
+        };
+        let p1 = point_on_circle(offset_radius, center, start_angle);
+        let p2 = point_on_circle(offset_radius, center, start_angle + sweep);
+        // collapsed arc becomes a line (which is never valid)
+        let bulge = if offset_radius > pos_equal_eps {
+            v1.bulge
+        } else {
+            T::zero()
+        };
+        return vec![
+            PlineVertex::from_vector2(p1, bulge),
+            PlineVertex::from_vector2(p2, T::zero()),
+        ];
+    }
+
+    // spiral approximated by arcs through three points on the envelope, the number of arcs is
+    // doubled until the envelope points between the three points are within the error tolerance
+    let slope = clamp_unit((radius2 - radius1) / (radius * sweep.abs()));
+    let point_at = |s: T| {
+        let seg_point = point_on_circle(radius, center, start_angle + s * sweep);
+        let radial = (seg_point - center).normalize();
+        let tangent = if v1.bulge_is_pos() {
+            radial.perp()
+        } else {
+            -radial.perp()
+        };
+        let dist = radius1 + s * (radius2 - radius1);
+        seg_point + tilted_normal(tangent, slope).scale(dist)
+    };
+    let max_count = arc_piece_count(sweep, MAX_SPIRAL_ARCS_PER_HALF_CIRCLE);
+    let mut count = arc_piece_count(sweep, MIN_SPIRAL_ARCS_PER_HALF_CIRCLE);
+    loop {
+        let count_t = T::from(count).unwrap();
+        let mut result = Vec::with_capacity(count + 1);
+        let mut max_error = T::zero();
+        for k in 0..count {
+            let s0 = T::from(k).unwrap() / count_t;
+            let s1 = T::from(k + 1).unwrap() / count_t;
+            let sm = (s0 + s1) / T::two();
+            let p0 = point_at(s0);
+            let p1 = point_at(s1);
+            let bulge = if p0.fuzzy_eq_eps(p1, pos_equal_eps) {
+                T::zero()
+            } else {
+                three_point_bulge(p0, point_at(sm), p1)
+            };
+            let start = PlineVertex::from_vector2(p0, bulge);
+            let end = PlineVertex::from_vector2(p1, T::zero());
+            for s in [(s0 + sm) / T::two(), (sm + s1) / T::two()] {
+                let p = point_at(s);
+                let closest = seg_closest_point(start, end, p, pos_equal_eps);
+                max_error = num_traits::real::Real::max(max_error, (p - closest).length());
+            }
+            result.push(start);
+        }
+
+        if max_error <= spiral_error_tol || count >= max_count {
+            result.push(PlineVertex::from_vector2(point_at(T::one()), T::zero()));
+            return result;
+        }
+        count = (2 * count).min(max_count);
+    }
+}
+
+/// Clamp `value` to the range [-1, 1] (distance changing faster than the path length means one end
+/// circle contains the other, the envelope then degenerates to the segment tangent direction).
+fn clamp_unit<T>(value: T) -> T
+where
+    T: Real,
+{
+    num_traits::real::Real::max(-T::one(), num_traits::real::Real::min(T::one(), value))
+}
+
+/// Number of pieces an arc segment with `sweep` angle is divided into using `per_half_circle`
+/// pieces per half circle of sweep.
+fn arc_piece_count<T>(sweep: T, per_half_circle: u32) -> usize
+where
+    T: Real,
+{
+    (sweep.abs() / T::pi() * T::from(per_half_circle).unwrap())
+        .ceil()
+        .to_usize()
+        .unwrap_or(1)
+        .max(1)
+}
+
+/// Join the end of `result` with `next_start` around the source vertex at `vertex_pos`. Outer
+/// corners get a round arc join, inner corners get a line (which is trimmed away later).
+fn add_round_join<T>(
+    result: &mut Polyline<T>,
+    vertex_pos: Vector2<T>,
+    dist: T,
+    in_tangent: Vector2<T>,
+    out_tangent: Vector2<T>,
+    next_start: Vector2<T>,
+    pos_equal_eps: T,
+) where
+    T: Real,
+{
+    let Some(last) = result.last() else {
+        return;
+    };
+    if last.pos().fuzzy_eq_eps(next_start, pos_equal_eps) {
+        return;
+    }
+
+    // left offset goes clockwise around outer corners, right offset goes counter clockwise, the
+    // offset end points are tilted when the distance changes so the join is needed even where the
+    // segments are tangent
+    let to_start = last.pos() - vertex_pos;
+    let to_end = next_start - vertex_pos;
+    let ccw_sweep = to_start.perp_dot(to_end).atan2(to_start.dot(to_end));
+    let mut sweep = if dist > T::zero() {
+        -ccw_sweep
+    } else {
+        ccw_sweep
+    };
+    let turn = in_tangent.perp_dot(out_tangent);
+    let u_turn = turn.abs() <= T::fuzzy_epsilon() * in_tangent.length() * out_tangent.length()
+        && in_tangent.dot(out_tangent) < T::zero();
+    if u_turn && sweep < T::zero() {
+        sweep = sweep + T::tau();
+    }
+    if sweep <= T::zero() {
+        // inner corner, connected by a line
+        return;
+    }
+
+    let bulge = bulge_from_angle(sweep);
+    let bulge = if dist > T::zero() { -bulge } else { bulge };
+    let last_index = result.vertex_count() - 1;
+    result.set_vertex(last_index, PlineVertex::from_vector2(last.pos(), bulge));
+}
+
+/// Push all intersect points between `raw_offset` and `other` as `(raw_offset segment index,
+/// point)`.
+fn push_intersects<R, T>(
+    raw_offset: &Polyline<T>,
+    raw_offset_index: &StaticAABB2DIndex<T>,
+    other: &R,
+    pos_equal_eps: T,
+    points: &mut Vec<(usize, Vector2<T>)>,
+) where
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let intrs = find_intersects(
+        raw_offset,
+        other,
+        &FindIntersectsOptions {
+            pline1_aabb_index: Some(raw_offset_index),
+            pos_equal_eps,
+        },
+    );
+    points.extend(
+        intrs
+            .basic_intersects
+            .iter()
+            .map(|intr| (intr.start_index1, intr.point)),
+    );
+    for intr in &intrs.overlapping_intersects {
+        points.push((intr.start_index1, intr.point1));
+        points.push((intr.start_index1, intr.point2));
+    }
+}
+
+/// Tests points against the variable distance band around the source polyline.
+struct VariableDistValidator<'a, P, T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    source: &'a P,
+    distances: &'a [T],
+    source_index: &'a StaticAABB2DIndex<T>,
+    max_dist: T,
+    offset_dist_eps: T,
+}
+
+impl<P, T> VariableDistValidator<'_, P, T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    /// Returns true if `point` is not closer to any source segment than the distance interpolated
+    /// along that segment (within `offset_dist_eps`).
+    fn point_is_valid(&self, point: Vector2<T>, query_stack: &mut Vec<usize>) -> bool {
+        let query_dist = self.max_dist + self.offset_dist_eps;
+        point_clear_of_segments(self.source_index, point, query_dist, query_stack, |i| {
+            let j = self.source.next_wrapping_index(i);
+            let excess = min_excess_distance(
+                self.source.at(i),
+                self.source.at(j),
+                self.distances[i].abs(),
+                self.distances[j].abs(),
+                point,
+            );
+            excess >= -self.offset_dist_eps
+        })
+    }
+}
+
+/// Minimum over the segment of (distance from `point` to the segment point minus the interpolated
+/// offset distance at the segment point).
+fn min_excess_distance<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    dist1: T,
+    dist2: T,
+    point: Vector2<T>,
+) -> T
+where
+    T: Real,
+{
+    if v1.pos().fuzzy_eq(v2.pos()) {
+        return (point - v1.pos()).length() - num_traits::real::Real::max(dist1, dist2);
+    }
+
+    if dist1.fuzzy_eq(dist2) {
+        let closest = seg_closest_point(v1, v2, point, T::fuzzy_epsilon());
+        return (point - closest).length() - dist1;
+    }
+
+    let excess_at = |seg_point: Vector2<T>, s: T| {
+        dist_squared(point, seg_point).sqrt() - (dist1 + s * (dist2 - dist1))
+    };
+
+    if v1.bulge_is_zero() {
+        // convex function of the line parameter
+        let p1 = v1.pos();
+        let dir = v2.pos() - v1.pos();
+        return golden_section_min(T::zero(), T::one(), |s| excess_at(p1 + dir.scale(s), s));
+    }
+
+    // check pieces of the arc so each search is over a short (near convex) span
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, v1.pos());
+    let sweep = angle_from_bulge(v1.bulge);
+    let count = arc_piece_count(sweep, SEARCH_PIECES_PER_HALF_CIRCLE);
+    let count_t = T::from(count).unwrap();
+    (0..count)
+        .map(|k| {
+            golden_section_min(
+                T::from(k).unwrap() / count_t,
+                T::from(k + 1).unwrap() / count_t,
+                |s| excess_at(point_on_circle(radius, center, start_angle + s * sweep), s),
+            )
+        })
+        .fold(
+            num_traits::Bounded::max_value(),
+            num_traits::real::Real::min,
+        )
+}
+
+/// Minimum value of `f` over `[a, b]` (exact for convex functions) using a golden section search,
+/// includes the end point values.
+fn golden_section_min<T, F>(mut a: T, mut b: T, f: F) -> T
+where
+    T: Real,
+    F: Fn(T) -> T,
+{
+    let end_min = num_traits::real::Real::min(f(a), f(b));
+    let inv_phi = T::from(0.618_033_988_749_894_8).unwrap();
+    let mut c = b - (b - a) * inv_phi;
+    let mut d = a + (b - a) * inv_phi;
+    let mut fc = f(c);
+    let mut fd = f(d);
+    for _ in 0..GOLDEN_SECTION_ITERATIONS {
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - (b - a) * inv_phi;
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + (b - a) * inv_phi;
+            fd = f(d);
+        }
+    }
+    num_traits::real::Real::min(end_min, num_traits::real::Real::min(fc, fd))
+}
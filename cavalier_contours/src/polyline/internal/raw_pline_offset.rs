@@ -14,8 +14,8 @@ use crate::{
         traits::Real,
     },
     polyline::{
-        PlineCreation, PlineSource, PlineSourceMut, PlineVertex, line_seg_bounding_box,
-        seg_arc_radius_and_center,
+        OffsetJoinType, PlineCreation, PlineSource, PlineSourceMut, PlineVertex,
+        line_seg_bounding_box, seg_arc_radius_and_center,
     },
 };
 use static_aabb2d_index::AABB;
@@ -213,8 +213,14 @@ enum JoinClass {
     Trimmed,
     /// An outer join bridged by an arc centered at the shared source vertex.
     OuterRound,
+    /// An outer join extended to a sharp miter tip.
+    OuterMiter,
+    /// An outer join bridged by a straight line.
+    OuterBevel,
     /// Opposite collinear tangents bridged by an arc centered at the shared source vertex.
     ReversalRound,
+    /// Opposite collinear tangents bridged by a straight line through the shared source vertex.
+    ReversalBevel,
     /// An inner join has no usable intersection, so its straight connector is invalid.
     InvalidInnerGap,
     /// A zero-length or same-direction collinear tangent configuration leaves an invalid gap.
@@ -238,6 +244,9 @@ struct JoinBoundary<T> {
     next_param: T,
     /// Bulge of the connector from `current_end` to `next_start`.
     connector_bulge: T,
+    /// Miter tip emitted between `current_end` and `next_start` when neither adjacent segment is a
+    /// line that can be extended to reach it.
+    miter_tip: Option<Vector2<T>>,
 }
 
 impl<T> JoinBoundary<T>
@@ -265,6 +274,7 @@ where
     invalid_segment_indexes: Vec<usize>,
     offset: T,
     connector_is_clockwise: bool,
+    join_type: OffsetJoinType<T>,
     pos_equal_eps: T,
 }
 
@@ -274,7 +284,13 @@ where
     T: Real,
     O: PlineCreation<Num = T>,
 {
-    fn new(source: &'a P, raw_segments: Vec<RawOffsetSeg<T>>, offset: T, pos_equal_eps: T) -> Self {
+    fn new(
+        source: &'a P,
+        raw_segments: Vec<RawOffsetSeg<T>>,
+        offset: T,
+        join_type: OffsetJoinType<T>,
+        pos_equal_eps: T,
+    ) -> Self {
         Self {
             source,
             polyline: O::with_capacity(source.vertex_count() + 1, false),
@@ -282,6 +298,7 @@ where
             raw_segments,
             offset,
             connector_is_clockwise: offset >= T::zero(),
+            join_type,
             pos_equal_eps,
         }
     }
@@ -328,6 +345,7 @@ where
             next_start,
             next_param: T::zero(),
             connector_bulge,
+            miter_tip: None,
         }
     }
 
@@ -344,9 +362,81 @@ where
             next_start,
             next_param: T::zero(),
             connector_bulge: T::zero(),
+            miter_tip: None,
         }
     }
 
+    /// Builds the outer join connector for the configured join type.
+    fn outer_connection_boundary(
+        &self,
+        current: &RawOffsetSeg<T>,
+        next: &RawOffsetSeg<T>,
+        shared_source_vertex: Vector2<T>,
+    ) -> JoinBoundary<T> {
+        let limit = match self.join_type {
+            OffsetJoinType::Round => {
+                return self.arc_connection_boundary(
+                    JoinClass::OuterRound,
+                    current.end(),
+                    next.start(),
+                    shared_source_vertex,
+                );
+            }
+            OffsetJoinType::Bevel => {
+                return Self::line_connection_boundary(
+                    JoinClass::OuterBevel,
+                    current.end(),
+                    next.start(),
+                );
+            }
+            OffsetJoinType::Miter { limit } => limit,
+        };
+
+        // Both raw endpoints lie at the offset distance along the source normals, so the tip
+        // where the two offset tangent lines meet lies along their sum.
+        let current_normal = current.end() - shared_source_vertex;
+        let next_normal = next.start() - shared_source_vertex;
+        let dist_squared = current_normal.length_squared();
+        let denominator = dist_squared + current_normal.dot(next_normal);
+        let tip_vector = (current_normal + next_normal).scale(dist_squared / denominator);
+
+        // Bevel when the tip distance exceeds the limit (the ratio is 1 / cos(turn / 2)).
+        if denominator <= T::zero() || tip_vector.length_squared() > limit * limit * dist_squared {
+            return Self::line_connection_boundary(
+                JoinClass::OuterBevel,
+                current.end(),
+                next.start(),
+            );
+        }
+
+        let tip = shared_source_vertex + tip_vector;
+        let mut boundary = Self::line_connection_boundary(JoinClass::OuterMiter, tip, tip);
+
+        // Extend adjacent lines to the tip, otherwise keep the arc end points and add the tip.
+        if let RawOffsetSeg::Line(current_line) = current {
+            boundary.current_param = Self::extended_line_param(current_line, tip);
+        } else {
+            boundary.current_end = current.end();
+        }
+        if let RawOffsetSeg::Line(next_line) = next {
+            boundary.next_param = Self::extended_line_param(next_line, tip);
+        } else {
+            boundary.next_start = next.start();
+        }
+        if boundary.current_end != tip && boundary.next_start != tip {
+            boundary.miter_tip = Some(tip);
+        }
+
+        boundary
+    }
+
+    /// Returns the parameter of `point` on the infinite line through `line`.
+    #[inline]
+    fn extended_line_param(line: &RawOffsetLine<T>, point: Vector2<T>) -> T {
+        let direction = line.end - line.start;
+        (point - line.start).dot(direction) / direction.length_squared()
+    }
+
     /// Classifies joins that need no intersection solve. Returns `None` for inner joins.
     fn join_without_intersection(
         &self,
@@ -375,6 +465,14 @@ where
 
         if turn * turn <= angle_eps * angle_eps * incoming_len_sq * outgoing_len_sq {
             if alignment < T::zero() {
+                if self.join_type != OffsetJoinType::Round {
+                    // Reversal bevel: a miter tip would be infinitely far away.
+                    return Some(Self::line_connection_boundary(
+                        JoinClass::ReversalBevel,
+                        current.end(),
+                        next.start(),
+                    ));
+                }
                 // Reversal round: bridge opposite collinear tangents with an arc.
                 Some(self.arc_connection_boundary(
                     JoinClass::ReversalRound,
@@ -394,13 +492,8 @@ where
             // Inner join: defer classification until the offset primitives are intersected.
             None
         } else {
-            // Outer round: bridge the offset endpoints with an arc.
-            Some(self.arc_connection_boundary(
-                JoinClass::OuterRound,
-                current.end(),
-                next.start(),
-                shared_source_vertex,
-            ))
+            // Outer join: bridge the offset endpoints according to the join type.
+            Some(self.outer_connection_boundary(current, next, shared_source_vertex))
         }
     }
 
@@ -522,6 +615,7 @@ where
             next_start: point,
             next_param,
             connector_bulge: T::zero(),
+            miter_tip: None,
         }
     }
 
@@ -800,6 +894,9 @@ where
                 .pos()
                 .fuzzy_eq_eps(boundary.current_end, self.pos_equal_eps)
         );
+        if let Some(tip) = boundary.miter_tip {
+            self.emit_segment(tip, T::zero(), false);
+        }
         self.emit_segment(
             boundary.next_start,
             boundary.connector_bulge,
@@ -879,6 +976,9 @@ where
                 closing_boundary.current_end,
                 closing_boundary.current_param,
             );
+            if let Some(tip) = closing_boundary.miter_tip {
+                self.emit_segment(tip, T::zero(), false);
+            }
             (
                 closing_boundary.connector_bulge,
                 closing_boundary.connector_is_invalid(),
@@ -990,7 +1090,14 @@ where
 }
 
 /// Constructs raw offset geometry and marks locally invalid output segments.
-pub fn create_raw_offset<P, T, O>(polyline: &P, offset: T, pos_equal_eps: T) -> RawOffsetResult<O>
+///
+/// `join_type` controls the connectors emitted at outer joins.
+pub fn create_raw_offset<P, T, O>(
+    polyline: &P,
+    offset: T,
+    join_type: OffsetJoinType<T>,
+    pos_equal_eps: T,
+) -> RawOffsetResult<O>
 where
     P: PlineSource<Num = T> + ?Sized,
     T: Real,
@@ -1017,7 +1124,8 @@ where
         return RawOffsetResult::empty();
     }
 
-    let builder = RawOffsetBuilder::<P, T, O>::new(polyline, raw_segments, offset, pos_equal_eps);
+    let builder =
+        RawOffsetBuilder::<P, T, O>::new(polyline, raw_segments, offset, join_type, pos_equal_eps);
     if polyline.is_closed() {
         builder.build_closed()
     } else {
@@ -1046,8 +1154,18 @@ mod tests {
     }
 
     fn join_at(input: &Polyline, offset: f64, current_index: usize) -> JoinBoundary<f64> {
+        join_with_type_at(input, offset, OffsetJoinType::Round, current_index)
+    }
+
+    fn join_with_type_at(
+        input: &Polyline,
+        offset: f64,
+        join_type: OffsetJoinType,
+        current_index: usize,
+    ) -> JoinBoundary<f64> {
         let raw_segments = create_untrimmed_raw_offset_segs(input, offset);
-        let builder = RawOffsetBuilder::<_, _, Polyline>::new(input, raw_segments, offset, 1e-5);
+        let builder =
+            RawOffsetBuilder::<_, _, Polyline>::new(input, raw_segments, offset, join_type, 1e-5);
         builder.analyze_join(current_index, current_index + 1)
     }
 
@@ -1093,8 +1211,13 @@ mod tests {
         ] {
             let next = Vector2::new(sweep.cos(), sweep.sin());
             for offset in [-1.0, 1.0] {
-                let builder =
-                    RawOffsetBuilder::<_, _, Polyline>::new(&source, Vec::new(), offset, 1e-5);
+                let builder = RawOffsetBuilder::<_, _, Polyline>::new(
+                    &source,
+                    Vec::new(),
+                    offset,
+                    OffsetJoinType::Round,
+                    1e-5,
+                );
                 let boundary = builder.arc_connection_boundary(
                     JoinClass::OuterRound,
                     Vector2::new(1.0, 0.0),
@@ -1113,7 +1236,8 @@ mod tests {
     #[test]
     fn normal_raw_offsets_have_no_invalid_segments() {
         let input = open(&[(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)]);
-        let result: RawOffsetResult<Polyline> = create_raw_offset(&input, -1.0, 1e-5);
+        let result: RawOffsetResult<Polyline> =
+            create_raw_offset(&input, -1.0, OffsetJoinType::Round, 1e-5);
         assert!(!result.invalid_segments.contains(&true));
         assert_eq!(result.polyline.segment_count(), 3);
     }
@@ -1126,7 +1250,8 @@ mod tests {
             (-451.9166112272101, -41.09344423983177, 0.0),
             (-452.0787206022149, -40.854568155450046, 0.0),
         ]);
-        let result: RawOffsetResult<Polyline> = create_raw_offset(&input, 11.0, 1e-5);
+        let result: RawOffsetResult<Polyline> =
+            create_raw_offset(&input, 11.0, OffsetJoinType::Round, 1e-5);
         assert_eq!(result.invalid_segments, [false, true, false, false, false]);
 
         let source_segments = create_untrimmed_raw_offset_segs(&input, 11.0);
@@ -1144,7 +1269,8 @@ mod tests {
     #[test]
     fn collapsed_arc_spans_are_invalid() {
         let input = open(&[(0.0, 0.0, 1.0), (2.0, 0.0, 0.0), (3.0, 1.0, 0.0)]);
-        let result: RawOffsetResult<Polyline> = create_raw_offset(&input, 2.0, 1e-5);
+        let result: RawOffsetResult<Polyline> =
+            create_raw_offset(&input, 2.0, OffsetJoinType::Round, 1e-5);
         assert!(result.invalid_segments.contains(&true));
     }
     #[test]
@@ -1156,7 +1282,8 @@ mod tests {
 
         for input in fixtures {
             for offset in [-1.0, 1.0] {
-                let result: RawOffsetResult<Polyline> = create_raw_offset(&input, offset, 1e-5);
+                let result: RawOffsetResult<Polyline> =
+                    create_raw_offset(&input, offset, OffsetJoinType::Round, 1e-5);
                 assert!(result.polyline.is_closed());
                 assert_eq!(
                     result.invalid_segments.len(),
@@ -1179,10 +1306,85 @@ mod tests {
         assert_eq!(join_at(&reversal, 0.2, 0).class, JoinClass::ReversalRound);
     }
     #[test]
+    fn outer_joins_follow_join_type() {
+        let corner = open(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)]);
+        let miter = OffsetJoinType::Miter { limit: 2.0 };
+
+        let boundary = join_with_type_at(&corner, -0.2, miter, 0);
+        assert_eq!(boundary.class, JoinClass::OuterMiter);
+        assert!(boundary.current_end.fuzzy_eq(Vector2::new(1.2, -0.2)));
+        assert!(boundary.next_start.fuzzy_eq(Vector2::new(1.2, -0.2)));
+        assert!(boundary.current_param.fuzzy_eq(1.2));
+        assert!(boundary.next_param.fuzzy_eq(-0.2));
+        assert!(boundary.miter_tip.is_none());
+
+        let boundary = join_with_type_at(&corner, -0.2, OffsetJoinType::Bevel, 0);
+        assert_eq!(boundary.class, JoinClass::OuterBevel);
+        assert!(boundary.connector_bulge.fuzzy_eq(0.0));
+
+        // Inner joins are trimmed regardless of join type.
+        assert_eq!(
+            join_with_type_at(&corner, 0.2, miter, 0).class,
+            JoinClass::Trimmed
+        );
+
+        // Sharp corners exceeding the miter limit are beveled.
+        let sharp = open(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 0.1, 0.0)]);
+        assert_eq!(
+            join_with_type_at(&sharp, -0.2, miter, 0).class,
+            JoinClass::OuterBevel
+        );
+
+        let reversal = open(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 0.0, 0.0)]);
+        assert_eq!(
+            join_with_type_at(&reversal, 0.2, miter, 0).class,
+            JoinClass::ReversalBevel
+        );
+    }
+    #[test]
+    fn arc_arc_miter_join_emits_tip_vertex() {
+        let input = open(&[(0.0, 0.0, 0.2), (1.0, 0.0, 0.2), (1.0, 1.0, 0.0)]);
+        let miter = OffsetJoinType::Miter { limit: 4.0 };
+        let boundary = join_with_type_at(&input, -0.2, miter, 0);
+        assert_eq!(boundary.class, JoinClass::OuterMiter);
+        let tip = boundary.miter_tip.unwrap();
+
+        let result: RawOffsetResult<Polyline> = create_raw_offset(&input, -0.2, miter, 1e-5);
+        assert_eq!(result.polyline.vertex_count(), 5);
+        assert!(result.polyline.at(2).pos().fuzzy_eq(tip));
+        assert!(result.polyline.at(1).bulge.fuzzy_eq(0.0));
+        assert!(result.polyline.at(2).bulge.fuzzy_eq(0.0));
+        assert!(!result.invalid_segments.contains(&true));
+    }
+    #[test]
+    fn closed_miter_raw_offset_has_sharp_corners() {
+        let input = closed(&[
+            (0.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (0.0, 1.0, 0.0),
+        ]);
+        let result: RawOffsetResult<Polyline> =
+            create_raw_offset(&input, -0.5, OffsetJoinType::Miter { limit: 2.0 }, 1e-5);
+        assert_eq!(result.polyline.vertex_count(), 4);
+        assert!(result.polyline.area().fuzzy_eq(4.0));
+
+        let result: RawOffsetResult<Polyline> =
+            create_raw_offset(&input, -0.5, OffsetJoinType::Bevel, 1e-5);
+        assert_eq!(result.polyline.vertex_count(), 8);
+        assert!(result.polyline.area().fuzzy_eq(3.5));
+    }
+    #[test]
     fn near_collinear_corner_uses_normalized_angular_tolerance() {
         let source = open(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (2.0, 1.0e-10, 0.0)]);
         let raw_segments = create_untrimmed_raw_offset_segs(&source, 1.0e6);
-        let builder = RawOffsetBuilder::<_, _, Polyline>::new(&source, raw_segments, 1.0e6, 1e-5);
+        let builder = RawOffsetBuilder::<_, _, Polyline>::new(
+            &source,
+            raw_segments,
+            1.0e6,
+            OffsetJoinType::Round,
+            1e-5,
+        );
         assert_eq!(
             builder.analyze_join(0, 1).class,
             JoinClass::InvalidDegenerateGap
@@ -1217,7 +1419,13 @@ mod tests {
     fn inner_join_endpoint_tolerance_clamps_the_local_candidate() {
         let source = open(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)]);
         let raw_segments = create_untrimmed_raw_offset_segs(&source, 0.2);
-        let builder = RawOffsetBuilder::<_, _, Polyline>::new(&source, raw_segments, 0.2, 1e-5);
+        let builder = RawOffsetBuilder::<_, _, Polyline>::new(
+            &source,
+            raw_segments,
+            0.2,
+            OffsetJoinType::Round,
+            1e-5,
+        );
         let current = RawOffsetLine {
             start: Vector2::new(0.0, 0.0),
             end: Vector2::new(1.0, 0.0),
@@ -1236,7 +1444,13 @@ mod tests {
     fn unusable_nearest_line_arc_candidate_does_not_select_farther_crossing() {
         let source = open(&[(0.0, 0.0, 0.0), (1.0, 0.0, 1.0), (2.0, 0.0, 0.0)]);
         let raw_segments = create_untrimmed_raw_offset_segs(&source, -0.2);
-        let builder = RawOffsetBuilder::<_, _, Polyline>::new(&source, raw_segments, -0.2, 1e-5);
+        let builder = RawOffsetBuilder::<_, _, Polyline>::new(
+            &source,
+            raw_segments,
+            -0.2,
+            OffsetJoinType::Round,
+            1e-5,
+        );
         let line = RawOffsetLine {
             start: Vector2::new(0.5, 0.0),
             end: Vector2::new(2.0, 0.0),
@@ -1264,7 +1478,8 @@ mod tests {
     #[test]
     fn failed_inner_join_connector_is_recorded_as_invalid() {
         let input = open(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)]);
-        let result: RawOffsetResult<Polyline> = create_raw_offset(&input, 2.0, 1e-5);
+        let result: RawOffsetResult<Polyline> =
+            create_raw_offset(&input, 2.0, OffsetJoinType::Round, 1e-5);
         assert_eq!(result.polyline.segment_count(), 3);
         assert_eq!(result.invalid_segments, [false, true, false]);
     }
@@ -1278,7 +1493,8 @@ mod tests {
         ]);
         input.set_userdata_values([3, 5]);
 
-        let result: RawOffsetResult<Polyline> = create_raw_offset(&input, -0.0, 1e-5);
+        let result: RawOffsetResult<Polyline> =
+            create_raw_offset(&input, -0.0, OffsetJoinType::Round, 1e-5);
         assert_eq!(
             result.polyline.iter_vertexes().collect::<Vec<_>>(),
             input.iter_vertexes().collect::<Vec<_>>()
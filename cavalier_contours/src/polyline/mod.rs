@@ -3,6 +3,10 @@
 #[doc(hidden)]
 pub mod internal;
 mod pline;
+mod pline_convex_hull;
+pub(crate) mod pline_error;
+mod pline_join;
+mod pline_medial_axis;
 mod pline_seg;
 mod pline_seg_intersect;
 mod pline_types;
@@ -11,6 +15,10 @@ mod pline_view;
 mod traits;
 
 pub use pline::*;
+pub use pline_convex_hull::*;
+pub use pline_error::PlineError;
+pub use pline_join::*;
+pub use pline_medial_axis::*;
 pub use pline_seg::*;
 pub use pline_seg_intersect::*;
 pub use pline_types::*;
@@ -0,0 +1,73 @@
+//! Convex hull of point sets and collections of polylines (following arc segments where they are
+//! on the hull boundary).
+
+use crate::core::{math::Vector2, traits::Real};
+
+use super::{
+    PlineSource, Polyline,
+    internal::pline_convex_hull::{add_pline_elements, convex_hull},
+};
+
+/// Returns the convex hull of `points` as a closed counter clockwise polyline of line segments.
+///
+/// Points within `pos_equal_eps` of each other are treated as one and points along the hull edges
+/// are not included as vertexes. Returns an empty polyline if `points` is empty.
+///
+/// # Examples
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::math::Vector2;
+/// let points = [
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(4.0, 0.0),
+///     Vector2::new(2.0, 1.0),
+///     Vector2::new(4.0, 4.0),
+///     Vector2::new(0.0, 4.0),
+/// ];
+/// let hull = convex_hull_of_points(&points, 1e-5);
+/// assert!(hull.is_closed());
+/// assert_eq!(hull.vertex_count(), 4);
+/// assert_eq!(hull.area(), 16.0);
+/// ```
+#[must_use]
+pub fn convex_hull_of_points<T>(points: &[Vector2<T>], pos_equal_eps: T) -> Polyline<T>
+where
+    T: Real,
+{
+    convex_hull(points, &[], pos_equal_eps)
+}
+
+/// Returns the convex hull of all the `plines` as a closed counter clockwise polyline.
+///
+/// The hull includes arc segments where it follows the arc segments of the polylines, see
+/// [`PlineSource::convex_hull`](super::PlineSource::convex_hull). Returns an empty polyline if there
+/// are no vertexes.
+///
+/// # Examples
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::traits::*;
+/// # use cavalier_contours::pline_closed;
+/// // two circles of radius 1 with centers 4 apart
+/// let plines = [
+///     pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)],
+///     pline_closed![(3.0, 0.0, 1.0), (5.0, 0.0, 1.0)],
+/// ];
+/// let hull = convex_hull_of_plines(&plines, 1e-5);
+/// // stadium shape, 4 x 2 rectangle and two half circles
+/// assert!(hull.area().fuzzy_eq(8.0 + std::f64::consts::PI));
+/// ```
+#[must_use]
+pub fn convex_hull_of_plines<P, T>(plines: &[P], pos_equal_eps: T) -> Polyline<T>
+where
+    P: PlineSource<Num = T>,
+    T: Real,
+{
+    let mut points = Vec::new();
+    let mut arcs = Vec::new();
+    for pline in plines {
+        add_pline_elements(pline, &mut points, &mut arcs);
+    }
+
+    convex_hull(&points, &arcs, pos_equal_eps)
+}
@@ -0,0 +1,156 @@
+//! Error type for the fallible polyline and shape operations.
+
+use std::fmt;
+
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
+
+use crate::core::traits::Real;
+
+use super::PlineSource;
+
+/// Error returned by the fallible (`try_`) polyline and shape operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlineError {
+    /// Vertex has a NaN or infinite `x` or `y` coordinate.
+    NonFiniteCoordinate {
+        /// Index of the input polyline (0 for `self` and 1 for `other` in polyline operations, loop
+        /// index for shape operations).
+        input: usize,
+        /// Index of the vertex.
+        index: usize,
+    },
+    /// Vertex has a NaN or infinite bulge.
+    NonFiniteBulge {
+        /// Index of the input polyline (0 for `self` and 1 for `other` in polyline operations, loop
+        /// index for shape operations).
+        input: usize,
+        /// Index of the vertex.
+        index: usize,
+    },
+    /// Polyline has fewer vertexes than required by the operation.
+    TooFewVertexes {
+        /// Index of the input polyline (0 for `self` and 1 for `other` in polyline operations, loop
+        /// index for shape operations).
+        input: usize,
+        /// Vertex count of the polyline.
+        count: usize,
+        /// Vertex count required by the operation.
+        required: usize,
+    },
+    /// Polyline is open but the operation requires a closed polyline.
+    NotClosed {
+        /// Index of the input polyline (0 for `self` and 1 for `other` in polyline operations, loop
+        /// index for shape operations).
+        input: usize,
+    },
+    /// Numeric parameter of the operation is NaN or infinite.
+    NonFiniteParameter {
+        /// Name of the parameter.
+        name: &'static str,
+    },
+    /// Spatial index failed to build, e.g. a `Self::Num` value failed to cast to `f64`.
+    SpatialIndex {
+        /// Spatial index build error message.
+        message: String,
+    },
+}
+
+impl fmt::Display for PlineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlineError::NonFiniteCoordinate { input, index } => write!(
+                f,
+                "vertex {index} of input {input} has a NaN or infinite coordinate"
+            ),
+            PlineError::NonFiniteBulge { input, index } => write!(
+                f,
+                "vertex {index} of input {input} has a NaN or infinite bulge"
+            ),
+            PlineError::TooFewVertexes {
+                input,
+                count,
+                required,
+            } => write!(
+                f,
+                "input {input} has {count} vertexes but at least {required} are required"
+            ),
+            PlineError::NotClosed { input } => write!(f, "input {input} must be closed"),
+            PlineError::NonFiniteParameter { name } => {
+                write!(f, "parameter `{name}` is NaN or infinite")
+            }
+            PlineError::SpatialIndex { message } => {
+                write!(f, "failed to build spatial index: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlineError {}
+
+/// Returns an error for the first vertex of `polyline` with a NaN or infinite coordinate or bulge.
+pub(crate) fn check_finite_vertexes<P, T>(polyline: &P, input: usize) -> Result<(), PlineError>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    for (index, v) in polyline.iter_vertexes().enumerate() {
+        if !v.x.is_finite() || !v.y.is_finite() {
+            return Err(PlineError::NonFiniteCoordinate { input, index });
+        }
+        if !v.bulge.is_finite() {
+            return Err(PlineError::NonFiniteBulge { input, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns an error if `polyline` has a NaN or infinite vertex, less than 2 vertexes, or is open
+/// when `require_closed` is true.
+pub(crate) fn check_pline_input<P, T>(
+    polyline: &P,
+    input: usize,
+    require_closed: bool,
+) -> Result<(), PlineError>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let count = polyline.vertex_count();
+    if count < 2 {
+        return Err(PlineError::TooFewVertexes {
+            input,
+            count,
+            required: 2,
+        });
+    }
+    if require_closed && !polyline.is_closed() {
+        return Err(PlineError::NotClosed { input });
+    }
+
+    check_finite_vertexes(polyline, input)
+}
+
+/// Returns an error if `value` is NaN or infinite.
+pub(crate) fn check_finite_param<T>(value: T, name: &'static str) -> Result<(), PlineError>
+where
+    T: Real,
+{
+    if value.is_finite() {
+        Ok(())
+    } else {
+        Err(PlineError::NonFiniteParameter { name })
+    }
+}
+
+/// Build the spatial index from `builder`, returning [`PlineError::SpatialIndex`] if it fails.
+pub(crate) fn build_spatial_index<T>(
+    builder: StaticAABB2DIndexBuilder<T>,
+) -> Result<StaticAABB2DIndex<T>, PlineError>
+where
+    T: Real,
+{
+    builder.build().map_err(|e| PlineError::SpatialIndex {
+        message: e.to_string(),
+    })
+}
@@ -0,0 +1,211 @@
+//! Joining unordered open polyline fragments (e.g. LINE and ARC entities from a drawing) end to end
+//! into chains and closed loops.
+
+use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
+
+use crate::core::{math::Vector2, traits::Real};
+
+use super::{PlineCreation, PlineSource, PlineSourceMut, PlineVertex, Polyline};
+
+/// Fragment in a chain and whether it is traversed in reverse.
+#[derive(Debug, Clone, Copy)]
+struct ChainLink {
+    fragment: usize,
+    reversed: bool,
+}
+
+/// Join open polyline fragments end to end into maximal chains and closed loops.
+///
+/// Fragments are joined where an end point of one is within `pos_equal_eps` of an end point of
+/// another, fragments are reversed as needed. A chain that ends where it starts is returned as a
+/// closed polyline, otherwise it is returned open. When more than two fragment end points meet at
+/// a position the chain continues with one of them and the others start or end other chains.
+/// Closed polylines given are returned unchanged and polylines with less than 2 vertexes are
+/// ignored. The userdata values of the fragments joined are combined in the result.
+///
+/// # Panics
+///
+/// Panics if `T` type fails to cast to/from a `u16` (required for spatial index).
+///
+/// # Examples
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::pline_open;
+/// // unordered sides of a square, one of them reversed
+/// let fragments = [
+///     pline_open![(4.0, 4.0, 0.0), (0.0, 4.0, 0.0)],
+///     pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)],
+///     pline_open![(0.0, 0.0, 0.0), (0.0, 4.0, 0.0)],
+///     pline_open![(4.0, 0.0, 0.0), (4.0, 4.0, 0.0)],
+/// ];
+/// let result = join_plines(&fragments, 1e-5);
+/// assert_eq!(result.len(), 1);
+/// assert!(result[0].is_closed());
+/// assert_eq!(result[0].vertex_count(), 4);
+/// assert_eq!(f64::abs(result[0].area()), 16.0);
+/// ```
+#[must_use]
+pub fn join_plines<P, T>(plines: &[P], pos_equal_eps: T) -> Vec<Polyline<T>>
+where
+    P: PlineSource<Num = T>,
+    T: Real,
+{
+    let mut result = Vec::new();
+    let mut fragments = Vec::with_capacity(plines.len());
+    for pline in plines {
+        if pline.vertex_count() < 2 {
+            continue;
+        }
+
+        if pline.is_closed() {
+            result.push(Polyline::create_from(pline));
+        } else {
+            fragments.push(pline);
+        }
+    }
+
+    if fragments.is_empty() {
+        return result;
+    }
+
+    // end points of fragment i are at index 2 * i (start) and 2 * i + 1 (end)
+    let end_points = fragments
+        .iter()
+        .flat_map(|f| [f.at(0).pos(), f.last().unwrap().pos()])
+        .collect::<Vec<_>>();
+    let end_points_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(end_points.len());
+        for p in &end_points {
+            builder.add(
+                p.x - pos_equal_eps,
+                p.y - pos_equal_eps,
+                p.x + pos_equal_eps,
+                p.y + pos_equal_eps,
+            );
+        }
+        builder.build().unwrap()
+    };
+
+    let mut visited = vec![false; fragments.len()];
+    let mut query_stack = Vec::new();
+    // find an unvisited fragment with an end point at `point`
+    let mut find_next = |visited: &[bool], point: Vector2<T>| {
+        find_unvisited_end_point(
+            &end_points_index,
+            &end_points,
+            visited,
+            point,
+            pos_equal_eps,
+            &mut query_stack,
+        )
+    };
+
+    for first in 0..fragments.len() {
+        if visited[first] {
+            continue;
+        }
+        visited[first] = true;
+
+        let mut chain_start = end_points[2 * first];
+        let mut chain_end = end_points[2 * first + 1];
+        let mut forward = vec![ChainLink {
+            fragment: first,
+            reversed: false,
+        }];
+
+        // fragment closed by position forms a loop on its own
+        let mut is_loop = fragments[first].vertex_count() > 2
+            && chain_end.fuzzy_eq_eps(chain_start, pos_equal_eps);
+
+        while !is_loop {
+            let Some(end_point) = find_next(&visited, chain_end) else {
+                break;
+            };
+            let fragment = end_point / 2;
+            let reversed = end_point % 2 == 1;
+            visited[fragment] = true;
+            forward.push(ChainLink { fragment, reversed });
+            // chain continues from the other end point of the fragment
+            chain_end = end_points[end_point ^ 1];
+            is_loop = chain_end.fuzzy_eq_eps(chain_start, pos_equal_eps);
+        }
+
+        // extend backwards from the chain start if it did not form a loop
+        let mut backward = Vec::new();
+        while !is_loop {
+            let Some(end_point) = find_next(&visited, chain_start) else {
+                break;
+            };
+            let fragment = end_point / 2;
+            // fragment ending at the chain start is traversed forward
+            let reversed = end_point % 2 == 0;
+            visited[fragment] = true;
+            backward.push(ChainLink { fragment, reversed });
+            chain_start = end_points[end_point ^ 1];
+            is_loop = chain_end.fuzzy_eq_eps(chain_start, pos_equal_eps);
+        }
+
+        let mut pline = Polyline::new();
+        for link in backward.iter().rev().chain(&forward) {
+            let f = fragments[link.fragment];
+            if link.reversed {
+                let vc = f.vertex_count();
+                for i in (0..vc).rev() {
+                    let bulge = if i > 0 { -f.at(i - 1).bulge } else { T::zero() };
+                    pline.add_or_replace_vertex(
+                        PlineVertex::from_vector2(f.at(i).pos(), bulge),
+                        pos_equal_eps,
+                    );
+                }
+            } else {
+                pline.extend_remove_repeat(f, pos_equal_eps);
+            }
+            pline.add_userdata_values(f.get_userdata_values());
+        }
+
+        if is_loop && pline.vertex_count() > 2 {
+            pline.remove_last();
+            pline.set_is_closed(true);
+        }
+
+        result.push(pline);
+    }
+
+    result
+}
+
+/// Returns the end point index (`2 * fragment` for start, `2 * fragment + 1` for end) of an
+/// unvisited fragment with an end point at `point`, preferring the closest one.
+fn find_unvisited_end_point<T>(
+    end_points_index: &StaticAABB2DIndex<T>,
+    end_points: &[Vector2<T>],
+    visited: &[bool],
+    point: Vector2<T>,
+    pos_equal_eps: T,
+    query_stack: &mut Vec<usize>,
+) -> Option<usize>
+where
+    T: Real,
+{
+    let mut closest: Option<(usize, T)> = None;
+    let mut visitor = |i: usize| {
+        if visited[i / 2] || !end_points[i].fuzzy_eq_eps(point, pos_equal_eps) {
+            return;
+        }
+        let dist = (end_points[i] - point).length_squared();
+        if closest.is_none_or(|(_, d)| dist < d) {
+            closest = Some((i, dist));
+        }
+    };
+
+    end_points_index.visit_query_with_stack(
+        point.x,
+        point.y,
+        point.x,
+        point.y,
+        &mut visitor,
+        query_stack,
+    );
+
+    closest.map(|(i, _)| i)
+}
@@ -0,0 +1,96 @@
+//! Types for the medial axis of closed polylines and shapes.
+
+use crate::core::{math::Vector2, traits::Real};
+
+use super::{PlineCreation, PlineSourceMut, Polyline};
+
+/// Point on a medial axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MedialAxisPoint<T = f64> {
+    /// Position of the point.
+    pub pos: Vector2<T>,
+    /// Clearance radius, the distance from the point to the closest point on the boundary.
+    pub radius: T,
+}
+
+/// Edge of a medial axis graph between two nodes.
+#[derive(Debug, Clone)]
+pub struct MedialAxisEdge<T = f64> {
+    /// Index of the start node in [`MedialAxis::nodes`].
+    pub start: usize,
+    /// Index of the end node in [`MedialAxis::nodes`] (same as `start` for an edge that forms a
+    /// closed loop).
+    pub end: usize,
+    /// Points along the edge from the start node to the end node (inclusive), consecutive points
+    /// are connected by straight lines.
+    pub points: Vec<MedialAxisPoint<T>>,
+}
+
+impl<T> MedialAxisEdge<T>
+where
+    T: Real,
+{
+    /// Total length of the edge.
+    #[must_use]
+    pub fn length(&self) -> T {
+        self.points
+            .windows(2)
+            .fold(T::zero(), |acc, w| acc + (w[1].pos - w[0].pos).length())
+    }
+
+    /// Returns the edge as an open polyline of line segments.
+    #[must_use]
+    pub fn to_polyline(&self) -> Polyline<T> {
+        let mut result = Polyline::with_capacity(self.points.len(), false);
+        for p in &self.points {
+            result.add(p.pos.x, p.pos.y, T::zero());
+        }
+        result
+    }
+}
+
+/// Medial axis of a closed polyline or shape as a graph, created by
+/// [`PlineSource::medial_axis`](super::PlineSource::medial_axis) or
+/// [`Shape::medial_axis`](crate::shape_algorithms::Shape::medial_axis).
+///
+/// The medial axis is the set of points inside the boundary with more than one closest point on
+/// the boundary (the centers of the maximal inscribed circles). Nodes are the end points of
+/// branches and the junctions where branches meet.
+#[derive(Debug, Clone)]
+pub struct MedialAxis<T = f64> {
+    /// Branch end points and junctions.
+    pub nodes: Vec<MedialAxisPoint<T>>,
+    /// Branches between nodes.
+    pub edges: Vec<MedialAxisEdge<T>>,
+}
+
+impl<T> MedialAxis<T>
+where
+    T: Real,
+{
+    /// Create an empty medial axis (no nodes or edges).
+    #[inline]
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Number of edge ends at the node with index `node` (an edge that forms a closed loop counts
+    /// twice).
+    #[must_use]
+    pub fn node_degree(&self, node: usize) -> usize {
+        self.edges
+            .iter()
+            .map(|e| usize::from(e.start == node) + usize::from(e.end == node))
+            .sum()
+    }
+
+    /// Total length of all the edges.
+    #[must_use]
+    pub fn total_length(&self) -> T {
+        self.edges.iter().fold(T::zero(), |acc, e| acc + e.length())
+    }
+}
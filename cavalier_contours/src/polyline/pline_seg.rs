@@ -371,6 +371,18 @@ where
         return line_seg_bounding_box(v1.pos(), v2.pos());
     }
 
+    if v1.bulge.abs() > T::one() {
+        // sagitta rectangle only bounds arcs up to a half circle, combine both halves
+        let SplitResult {
+            updated_start,
+            split_vertex,
+        } = seg_split_at_midpoint(v1, v2);
+        return aabb_union(
+            seg_fast_approx_bounding_box(updated_start, split_vertex),
+            seg_fast_approx_bounding_box(split_vertex, v2),
+        );
+    }
+
     // For arcs we don't compute the actual extents which is slower, instead we create an approximate
     // bounding box from the rectangle formed by extending the chord by the sagitta, note this
     // approximate bounding box is always equal to or bigger than the true bounding box
@@ -406,6 +418,18 @@ where
         return AABB::new(v1.x, v1.y, v1.x, v1.y);
     }
 
+    if v1.bulge.abs() > T::one() {
+        // cardinal crossing tests below only hold for arcs up to a half circle
+        let SplitResult {
+            updated_start,
+            split_vertex,
+        } = seg_split_at_midpoint(v1, v2);
+        return aabb_union(
+            arc_seg_bounding_box(updated_start, split_vertex),
+            arc_seg_bounding_box(split_vertex, v2),
+        );
+    }
+
     let bulge = v1.bulge;
     let bulge_squared = bulge * bulge;
     let chord = v2.pos() - v1.pos();
@@ -523,8 +547,8 @@ where
 /// Assumes `point` lies on the segment from `v1` to `v2`.
 ///
 /// Endpoints snap with `pos_equal_eps`. For lines, it uses distance along the line. For arcs, it
-/// uses the radius times the forward angle, which is the smaller radial angle because supported
-/// arcs sweep at most a half circle.
+/// uses the radius times the forward angle (the smaller radial angle unless the arc sweeps more
+/// than a half circle).
 pub fn dist_from_segment_start<T>(
     v1: PlineVertex<T>,
     v2: PlineVertex<T>,
@@ -560,14 +584,105 @@ where
         let start_radius =
             chord.perp().scale(bulge_squared - T::one()) - chord.scale(T::two() * bulge);
         let point_radius = start_radius + (point - v1.pos()).scale(T::four() * bulge);
-        let sweep = T::atan2(
-            start_radius.perp_dot(point_radius).abs(),
-            start_radius.dot(point_radius),
-        );
+        let sweep = if bulge.abs() > T::one() {
+            // forward angle may be more than a half circle, the common scale by `4 * bulge`
+            // reverses the vectors of clockwise arcs but not their rotation direction
+            let cross = start_radius.perp_dot(point_radius);
+            let forward_cross = if v1.bulge_is_neg() { -cross } else { cross };
+            let forward_angle = T::atan2(forward_cross, start_radius.dot(point_radius));
+            if forward_angle < T::zero() {
+                forward_angle + T::tau()
+            } else {
+                forward_angle
+            }
+        } else {
+            T::atan2(
+                start_radius.perp_dot(point_radius).abs(),
+                start_radius.dot(point_radius),
+            )
+        };
         radius * sweep
     }
 }
 
+/// Splits the arc segment defined by `v1` to `v2` at its midpoint, both resulting arcs have half
+/// the sweep angle of the original. Assumes `v1` to `v2` is an arc.
+///
+/// Used to handle arcs that sweep more than a half circle (`|bulge| > 1`) by splitting them into
+/// two arcs that each sweep less than a half circle.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::core::math::*;
+/// # use cavalier_contours::core::traits::*;
+/// # use cavalier_contours::polyline::*;
+/// // three quarter circle going counter clockwise from (1, 0) to (0, -1)
+/// let bulge = (3.0 * std::f64::consts::PI / 8.0).tan();
+/// let v1 = PlineVertex::new(1.0, 0.0, bulge);
+/// let v2 = PlineVertex::new(0.0, -1.0, 0.0);
+/// let SplitResult { updated_start, split_vertex } = seg_split_at_midpoint(v1, v2);
+/// let half_bulge = (3.0 * std::f64::consts::PI / 16.0).tan();
+/// assert!(updated_start.fuzzy_eq(PlineVertex::new(1.0, 0.0, half_bulge)));
+/// let sqrt_half = std::f64::consts::FRAC_1_SQRT_2;
+/// assert!(split_vertex.fuzzy_eq(PlineVertex::new(-sqrt_half, sqrt_half, half_bulge)));
+/// ```
+pub fn seg_split_at_midpoint<T>(v1: PlineVertex<T>, v2: PlineVertex<T>) -> SplitResult<T>
+where
+    T: Real,
+{
+    debug_assert!(!v1.bulge_is_zero(), "expected arc");
+    // tan(sweep / 8) from tan(sweep / 4) using the half angle identity
+    let bulge = v1.bulge;
+    let half_bulge = bulge / (T::one() + (T::one() + bulge * bulge).sqrt());
+    let midpoint = seg_midpoint(v1, v2);
+    SplitResult {
+        updated_start: PlineVertex::new(v1.x, v1.y, half_bulge),
+        split_vertex: PlineVertex::new(midpoint.x, midpoint.y, half_bulge),
+    }
+}
+
+/// Returns true if the segment defined by `v1` to `v2` is an arc that sweeps more than a half
+/// circle (`|bulge| > 1`) with end points that are not equal (within `pos_equal_eps`), these are
+/// the arcs split by [`PlineSource::split_large_arcs`](super::PlineSource::split_large_arcs).
+pub(crate) fn seg_is_large_arc<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, pos_equal_eps: T) -> bool
+where
+    T: Real,
+{
+    v1.bulge.abs() > T::one() && !v1.pos().fuzzy_eq_eps(v2.pos(), pos_equal_eps)
+}
+
+/// Bulge of the arc starting at `p0`, passing through `pm`, and ending at `p1` (zero if the points
+/// are collinear).
+pub(crate) fn three_point_bulge<T>(p0: Vector2<T>, pm: Vector2<T>, p1: Vector2<T>) -> T
+where
+    T: Real,
+{
+    // the inscribed angle at pm subtends the arc not containing pm, bulge = tan(sweep / 4) where
+    // sweep = 2 * (pi - inscribed angle)
+    let a = p0 - pm;
+    let b = p1 - pm;
+    let cross = a.perp_dot(b);
+    let lengths = a.length() * b.length();
+    if cross.fuzzy_eq_zero_eps(T::fuzzy_epsilon() * lengths) {
+        return T::zero();
+    }
+
+    -(lengths + a.dot(b)) / cross
+}
+
+/// Returns the union of two axis aligned bounding boxes.
+fn aabb_union<T>(a: AABB<T>, b: AABB<T>) -> AABB<T>
+where
+    T: Real,
+{
+    let (min_x, _) = min_max(a.min_x, b.min_x);
+    let (min_y, _) = min_max(a.min_y, b.min_y);
+    let (_, max_x) = min_max(a.max_x, b.max_x);
+    let (_, max_y) = min_max(a.max_y, b.max_y);
+    AABB::new(min_x, min_y, max_x, max_y)
+}
+
 /// Find the midpoint for the polyline segment defined by `v1` to `v2`.
 ///
 /// # Examples
@@ -657,6 +772,8 @@ mod tests {
             (-quarter_circle_bulge, 2.0f64.sqrt() * 2.0, -2.0),
             (1e-7, 10_000_000.0000001, 9_999_999.9999999),
             (-1e-7, 10_000_000.0000001, -9_999_999.9999999),
+            (2.0, 2.5, -1.5),
+            (-2.0, 2.5, 1.5),
         ] {
             let v1 = PlineVertex::new(0.0, 0.0, bulge);
             let v2 = PlineVertex::new(4.0, 0.0, 0.0);
@@ -672,12 +789,14 @@ mod tests {
     #[test]
     fn seg_tangent_vector_preserves_arc_radius_magnitude() {
         for bulge in [
+            -2.0,
             -1.0,
             -std::f64::consts::FRAC_PI_8.tan(),
             -1e-7,
             1e-7,
             std::f64::consts::FRAC_PI_8.tan(),
             1.0,
+            2.0,
         ] {
             let sweep = 4.0 * bulge.atan();
             let v1 = PlineVertex::new(1.0, 0.0, bulge);
@@ -941,12 +1060,14 @@ mod tests {
     #[test]
     fn seg_split_at_point_returns_expected_subarcs() {
         for bulge in [
+            -2.0,
             -1.0,
             -std::f64::consts::FRAC_PI_8.tan(),
             -1e-7,
             1e-7,
             std::f64::consts::FRAC_PI_8.tan(),
             1.0,
+            2.0,
         ] {
             let sweep = 4.0 * bulge.atan();
             let v1 = PlineVertex::new(1.0, 0.0, bulge);
@@ -977,12 +1098,14 @@ mod tests {
     #[test]
     fn seg_length_returns_expected_arc_lengths() {
         for bulge in [
+            -2.0,
             -1.0,
             -std::f64::consts::FRAC_PI_8.tan(),
             -1e-7,
             1e-7,
             std::f64::consts::FRAC_PI_8.tan(),
             1.0,
+            2.0,
         ] {
             let sweep = 4.0 * bulge.atan();
             let v1 = PlineVertex::new(1.0, 0.0, bulge);
@@ -1014,12 +1137,14 @@ mod tests {
         let radius = 3.25;
         let start_angle = 0.37;
         for bulge in [
+            -2.0,
             -1.0,
             -std::f64::consts::FRAC_PI_8.tan(),
             -1e-7,
             1e-7,
             std::f64::consts::FRAC_PI_8.tan(),
             1.0,
+            2.0,
         ] {
             let sweep = 4.0 * bulge.atan();
             let point_at_angle = |angle: f64| {
@@ -1048,4 +1173,60 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn large_arc_bounding_boxes_include_full_sweep() {
+        let center = Vector2::new(3.0, -2.0);
+        let radius = 2.5;
+        for start_angle in [0.0, 0.25, 2.0, 4.0] {
+            for sweep in [-1.75, -1.5, -1.1, 1.1, 1.5, 1.75].map(|f| f * std::f64::consts::PI) {
+                let (v1, v2) = arc_vertexes(center, radius, start_angle, sweep);
+                assert!(v1.bulge.abs() > 1.0);
+                // extents from sampling points along the arc
+                let sample_count = 10_000;
+                let mut expected = AABB::new(v1.x, v1.y, v1.x, v1.y);
+                for k in 1..=sample_count {
+                    let angle = start_angle + sweep * f64::from(k) / f64::from(sample_count);
+                    let p = center + Vector2::new(radius * angle.cos(), radius * angle.sin());
+                    expected = aabb_union(expected, AABB::new(p.x, p.y, p.x, p.y));
+                }
+                let exact = seg_bounding_box(v1, v2);
+                assert_aabb_close(exact, expected, 1e-6);
+                let approx = seg_fast_approx_bounding_box(v1, v2);
+                assert!(approx.min_x <= exact.min_x && approx.min_y <= exact.min_y);
+                assert!(approx.max_x >= exact.max_x && approx.max_y >= exact.max_y);
+            }
+        }
+    }
+
+    #[test]
+    fn large_arc_closest_point_and_midpoint_split() {
+        // three quarter circle counter clockwise from (1, 0) to (0, -1)
+        let (v1, v2) = arc_vertexes(Vector2::zero(), 1.0, 0.0, 1.5 * std::f64::consts::PI);
+        let on_arc = Vector2::new(-2.0, 0.0);
+        assert!(seg_closest_point(v1, v2, on_arc, 1e-12).fuzzy_eq(Vector2::new(-1.0, 0.0)));
+        // query in the quarter not swept is closest to an end point
+        let off_arc = Vector2::new(2.0, -1.0);
+        assert!(seg_closest_point(v1, v2, off_arc, 1e-12).fuzzy_eq(v1.pos()));
+        assert!(seg_distance_is_greater_than(v1, v2, on_arc, 0.9, 1e-12));
+        assert!(!seg_distance_is_greater_than(v1, v2, on_arc, 1.1, 1e-12));
+
+        let SplitResult {
+            updated_start,
+            split_vertex,
+        } = seg_split_at_midpoint(v1, v2);
+        let half_bulge = (1.5 * std::f64::consts::PI / 8.0).tan();
+        assert!(updated_start.bulge.fuzzy_eq(half_bulge));
+        assert!(split_vertex.bulge.fuzzy_eq(half_bulge));
+        let sqrt_half = std::f64::consts::FRAC_1_SQRT_2;
+        assert!(
+            split_vertex
+                .pos()
+                .fuzzy_eq(Vector2::new(-sqrt_half, sqrt_half))
+        );
+        assert!(
+            (seg_length(updated_start, split_vertex) + seg_length(split_vertex, v2))
+                .fuzzy_eq(seg_length(v1, v2))
+        );
+    }
 }
@@ -41,6 +41,45 @@ where
     pub distance: T,
 }
 
+/// Point sampled along a polyline path, returned by [`PlineSource::sample_points`] and
+/// [`PlineSource::sample_at_spacing`].
+#[derive(Debug, Copy, Clone)]
+pub struct PathSample<T = f64>
+where
+    T: Real,
+{
+    /// The start vertex index of the segment the point lies on.
+    pub seg_index: usize,
+    /// Path length from the start of the polyline to the point.
+    pub path_length: T,
+    /// The sampled point.
+    pub point: Vector2<T>,
+    /// Unit tangent vector at the point (direction of travel along the polyline).
+    pub tangent: Vector2<T>,
+    /// Unit normal vector at the point (tangent rotated 90 degrees counter clockwise, pointing left
+    /// of the direction of travel).
+    pub normal: Vector2<T>,
+}
+
+/// Result of a distance query between two polylines, returned by [`PlineSource::min_distance`] and
+/// the Hausdorff distance methods.
+#[derive(Debug, Copy, Clone)]
+pub struct PlineDistanceResult<T = f64>
+where
+    T: Real,
+{
+    /// The distance between the points.
+    pub distance: T,
+    /// The point on `self`.
+    pub point: Vector2<T>,
+    /// The start vertex index of the segment on `self` that `point` lies on.
+    pub seg_index: usize,
+    /// The point on the other polyline.
+    pub other_point: Vector2<T>,
+    /// The start vertex index of the segment on the other polyline that `other_point` lies on.
+    pub other_seg_index: usize,
+}
+
 /// Controls how tangent contacts between offset loops are handled.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TouchingLoopBehavior {
@@ -60,6 +99,34 @@ pub enum CoincidentSegmentBehavior {
     Discard,
 }
 
+/// Controls how the offset segments are connected at outer (convex) corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OffsetJoinType<T = f64> {
+    /// Connect with an arc centered at the source vertex.
+    Round,
+    /// Extend the adjacent offset segments until they meet at a sharp corner. `limit` is the
+    /// maximum allowed ratio of the distance between the source vertex and the miter tip to the
+    /// offset distance (equivalent to the SVG `stroke-miterlimit`), corners exceeding it are
+    /// beveled instead. A limit of `2.0` allows corners down to 60 degrees to remain sharp.
+    Miter {
+        /// Maximum miter tip distance as a multiple of the offset distance.
+        limit: T,
+    },
+    /// Connect with a straight line between the adjacent offset segment end points.
+    Bevel,
+}
+
+/// Controls the shape of the offset at the end points of open polylines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetCapType {
+    /// Half circle centered at the end point.
+    Round,
+    /// Square projecting past the end point by the offset distance.
+    Square,
+    /// Flat cut through the end point, perpendicular to the end segment tangent.
+    Butt,
+}
+
 /// Struct to hold options parameters when performing polyline offset.
 #[derive(Debug, Clone)]
 pub struct PlineOffsetOptions<'a, T = f64>
@@ -87,6 +154,11 @@ where
     pub touching_loop_behavior: TouchingLoopBehavior,
     /// Controls whether spans that coincide with other raw offset spans are retained.
     pub coincident_segment_behavior: CoincidentSegmentBehavior,
+    /// Controls how offset segments are connected at outer corners.
+    pub join_type: OffsetJoinType<T>,
+    /// Controls how offsets are clipped at the end points of open polylines (and the end shape of
+    /// [`PlineSource::stroke_opt`] outlines). Ignored for closed polylines.
+    pub cap_type: OffsetCapType,
 }
 
 impl<T> PlineOffsetOptions<'_, T>
@@ -103,6 +175,8 @@ where
             offset_dist_eps: T::from(1e-4).unwrap(),
             touching_loop_behavior: TouchingLoopBehavior::Preserve,
             coincident_segment_behavior: CoincidentSegmentBehavior::Preserve,
+            join_type: OffsetJoinType::Round,
+            cap_type: OffsetCapType::Round,
         }
     }
 }
@@ -117,6 +191,52 @@ where
     }
 }
 
+/// Struct to hold options parameters when performing variable distance polyline offset.
+#[derive(Debug, Clone)]
+pub struct PlineVariableOffsetOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index of all the polyline segment bounding boxes (or boxes no smaller, e.g. using
+    /// [`PlineSource::create_approx_aabb_index`] is valid). If `None` is given then it will be
+    /// computed internally.
+    pub aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used when testing distance of slices to original polyline for
+    /// validity.
+    pub offset_dist_eps: T,
+    /// Maximum distance between the offset of an arc segment with changing distance (a spiral like
+    /// curve) and the arc segments approximating it. Smaller values use more arc segments.
+    pub spiral_error_tol: T,
+}
+
+impl<T> PlineVariableOffsetOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            offset_dist_eps: T::from(1e-4).unwrap(),
+            spiral_error_tol: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineVariableOffsetOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // The containment functions use the same underlying mechinsims as the boolean functions.
 /// Information about what happened during the boolean operation.
 #[derive(Debug, Clone, PartialEq)]
@@ -307,6 +427,11 @@ where
     /// included in the result. This is useful to avoid inconsistent results due to floating point
     /// thresholding, or if you just don't want ever want collapsed polylines in the result.
     pub collapsed_area_eps: Option<T>,
+    /// If Some then self intersecting input polylines are first resolved into non-self-intersecting
+    /// polylines using the fill rule given (see [`PlineSource::resolve_self_intersects`]). When
+    /// either input self intersects the result polylines have no subslices and the result info is
+    /// [`BooleanResultInfo::Intersected`]. If None then the input polylines must not self intersect.
+    pub fill_rule: Option<FillRule>,
 }
 
 impl<T> PlineBooleanOptions<'_, T>
@@ -320,6 +445,7 @@ where
             pline1_aabb_index: None,
             pos_equal_eps: T::from(1e-5).unwrap(),
             collapsed_area_eps: None,
+            fill_rule: None,
         }
     }
 }
@@ -334,6 +460,702 @@ where
     }
 }
 
+/// Controls which parts of a polyline are kept when clipping it against a closed boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ClipKeep {
+    /// Keep only the parts inside of the boundary.
+    Inside,
+    /// Keep only the parts outside of the boundary.
+    Outside,
+    /// Keep both the inside and outside parts.
+    Both,
+}
+
+/// Represents one of the polylines resulting from clipping a polyline against a closed boundary.
+#[derive(Debug, Clone)]
+pub struct ClipResultPline<P>
+where
+    P: PlineCreation,
+{
+    /// Clipped part of the source polyline (always open unless the source polyline is closed and
+    /// has no intersects with the boundary).
+    pub pline: P,
+    /// Index of the source polyline segment the clipped part starts on.
+    pub start_index: usize,
+    /// Index of the source polyline segment the clipped part ends on.
+    pub end_index: usize,
+}
+
+/// Result of clipping a polyline against a closed boundary. Clipped parts are ordered following
+/// the direction of the source polyline.
+#[derive(Debug, Clone)]
+pub struct ClipResult<P>
+where
+    P: PlineCreation,
+{
+    /// Parts of the polyline inside of the boundary (including parts that lie on the boundary).
+    pub inside: Vec<ClipResultPline<P>>,
+    /// Parts of the polyline outside of the boundary.
+    pub outside: Vec<ClipResultPline<P>>,
+}
+
+impl<P> ClipResult<P>
+where
+    P: PlineCreation,
+{
+    #[inline]
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            inside: Vec::new(),
+            outside: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PlineClipOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index for `self` or the polyline being clipped.
+    pub pline_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineClipOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pline_aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineClipOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rule used to determine which areas enclosed by a self intersecting closed polyline are filled,
+/// based on the winding number of the area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FillRule {
+    /// Area is filled if its winding number is not zero.
+    NonZero,
+    /// Area is filled if its winding number is odd.
+    EvenOdd,
+}
+
+#[derive(Debug)]
+pub struct PlineFillOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index for the polyline.
+    pub aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// If Some then this epsilon value is used to determine if a result polyline is collapsed, that
+    /// is has no area according to abs(area) < eps. Polylines that are collapsed will not be
+    /// included in the result.
+    pub collapsed_area_eps: Option<T>,
+}
+
+impl<T> PlineFillOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            collapsed_area_eps: None,
+        }
+    }
+}
+
+impl<T> Default for PlineFillOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineRepairOptions<T = f64>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal. Also used as the
+    /// maximum width of a spike (segment doubling back on the previous segment) to remove.
+    pub pos_equal_eps: T,
+    /// Open polylines with a distance between their start and end less than or equal to this
+    /// value are closed, other open polylines are discarded.
+    pub close_tolerance: T,
+    /// Fill rule used to resolve self intersects.
+    pub fill_rule: FillRule,
+    /// Result polylines with abs(area) < eps are discarded (zero width slivers).
+    pub collapsed_area_eps: T,
+}
+
+impl<T> PlineRepairOptions<T>
+where
+    T: Real,
+{
+    /// Create options with the default values: `pos_equal_eps` of 1e-5, `close_tolerance` of 1e-3,
+    /// [`FillRule::NonZero`], and `collapsed_area_eps` of 1e-5.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            close_tolerance: T::from(1e-3).unwrap(),
+            fill_rule: FillRule::NonZero,
+            collapsed_area_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineRepairOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineFitArcsOptions<T = f64>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal. Repeat positions
+    /// within a run of line segments are skipped when fitting.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineFitArcsOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineFitArcsOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Algorithm used to simplify a polyline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SimplifyMethod {
+    /// Douglas-Peucker: recursively keep the vertex furthest from the segment replacing a span of
+    /// the polyline until every span is within the tolerance.
+    DouglasPeucker,
+    /// Visvalingam-Whyatt: repeatedly remove the vertex whose removal changes the area the least,
+    /// as long as the span replaced stays within the tolerance.
+    Visvalingam,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineSimplifyOptions<T = f64>
+where
+    T: Real,
+{
+    /// Simplification algorithm to use.
+    pub method: SimplifyMethod,
+    /// If true then removed vertexes are added back where required so the result has no self
+    /// intersects (checked with
+    /// [`PlineSource::scan_for_self_intersect`](super::PlineSource::scan_for_self_intersect)). Only
+    /// applies if the polyline given has no self intersects.
+    pub preserve_topology: bool,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineSimplifyOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            method: SimplifyMethod::DouglasPeucker,
+            preserve_topology: false,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineSimplifyOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineConvexHullOptions<T = f64>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineConvexHullOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineConvexHullOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Treatment applied to a corner (vertex) of a polyline by
+/// [`PlineSource::treat_corners`](super::PlineSource::treat_corners).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CornerTreatment<T = f64> {
+    /// Leave the corner unchanged.
+    Sharp,
+    /// Round the corner with an arc of the radius given, tangent to both adjacent segments.
+    Fillet {
+        /// Radius of the fillet arc.
+        radius: T,
+    },
+    /// Cut the corner with a line segment, trimming both adjacent segments by the distance given
+    /// (measured along the segments from the corner).
+    Chamfer {
+        /// Distance along each adjacent segment from the corner to the chamfer line.
+        distance: T,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineCornerOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Treatment to apply to each vertex by index, vertexes without an entry (index past the end
+    /// of the slice) use the treatment given to the function. If `None` then the treatment given
+    /// is applied to all vertexes.
+    pub vertex_treatments: Option<&'a [CornerTreatment<T>]>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineCornerOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            vertex_treatments: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineCornerOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineMinkowskiOptions<T = f64>
+where
+    T: Real,
+{
+    /// Maximum distance between a path arc segment and the line segments approximating it, only
+    /// used if the shape has arc segments (path arcs are swept exactly by shapes made of line
+    /// segments).
+    pub arc_approx_error: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineMinkowskiOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            arc_approx_error: T::from(1e-3).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineMinkowskiOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineMedialAxisOptions<T = f64>
+where
+    T: Real,
+{
+    /// Minimum angle (in radians) subtended at a medial axis edge by the two boundary points it is
+    /// equidistant to. Edges with a smaller angle come from sampling the boundary or from nearly
+    /// flat corners and are removed, e.g. a corner with an interior angle greater than
+    /// `PI - min_angle` has no branch.
+    pub min_angle: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineMedialAxisOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            min_angle: T::pi() / T::four(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineMedialAxisOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineTransformOptions<T = f64>
+where
+    T: Real,
+{
+    /// If true then arc segments transformed into ellipse arcs (by a non-uniform scale or shear)
+    /// are approximated by arc segments within `ellipse_approx_error`. If false then arc segments
+    /// keep their bulge values and only their end points are transformed, which is only exact for
+    /// transforms that map circles to circles.
+    pub approx_ellipse_arcs: bool,
+    /// Maximum distance between an ellipse arc and the arc segments approximating it.
+    pub ellipse_approx_error: T,
+}
+
+impl<T> PlineTransformOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            approx_ellipse_arcs: true,
+            ellipse_approx_error: T::from(1e-3).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineTransformOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineSampleOptions<T = f64>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal (zero length
+    /// segments are skipped and a path length within this epsilon of the end is at the end).
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineSampleOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineSampleOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineResampleOptions<T = f64>
+where
+    T: Real,
+{
+    /// If true then the original vertexes are kept so the path is unchanged and the new vertexes
+    /// only split it. If false then the result only has the new vertexes, segments between new
+    /// vertexes on the same original segment follow it (arcs stay arcs), segments spanning
+    /// original vertexes are replaced by an arc through the path midpoint if the spanned path is
+    /// all arc segments (e.g. a circle stays a circle) and by a line segment otherwise.
+    pub keep_vertexes: bool,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal (a new vertex at
+    /// an original vertex is merged with it).
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineResampleOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            keep_vertexes: false,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineResampleOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineSliceOptions<T = f64>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal (a slice shorter
+    /// than this epsilon has zero length).
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineSliceOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineSliceOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlineDistanceOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index for `self`, only used when measuring from the other polyline to `self` (the
+    /// symmetric Hausdorff distance).
+    pub pline1_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Spatial index for the other polyline.
+    pub pline2_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Maximum amount the Hausdorff distance found may be less than the true Hausdorff distance,
+    /// smaller values require more subdivision of the segments.
+    pub hausdorff_tolerance: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineDistanceOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pline1_aabb_index: None,
+            pline2_aabb_index: None,
+            hausdorff_tolerance: T::from(1e-5).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineDistanceOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct PlineValidateOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index for the polyline (used to find self intersects).
+    pub aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Vertexes with an absolute bulge greater than this value are reported as out of range.
+    /// Default is 1e3 (arc sweeping about 359.8 degrees).
+    pub max_abs_bulge: T,
+    /// Closed polylines with abs(area) < eps are reported as having near zero area.
+    pub zero_area_eps: T,
+}
+
+impl<T> PlineValidateOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            max_abs_bulge: T::from(1e3).unwrap(),
+            zero_area_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineValidateOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Report of the problems found by [`PlineSource::validate`](super::PlineSource::validate).
+#[derive(Debug, Clone)]
+pub struct PlineValidationReport<T = f64> {
+    /// Indexes of the vertexes with a NaN or infinite coordinate or bulge. If not empty then the
+    /// self intersect and area checks are skipped.
+    pub non_finite_vertexes: Vec<usize>,
+    /// Indexes of the vertexes with an absolute bulge greater than the maximum allowed (the bulge of
+    /// the last vertex of an open polyline is not checked).
+    pub out_of_range_bulges: Vec<usize>,
+    /// Pairs of vertex indexes `(i, j)` with `i < j` that are at the same position but are not the
+    /// start and end of the same segment.
+    pub repeated_positions: Vec<(usize, usize)>,
+    /// Start vertex indexes of the segments that start and end at the same position.
+    pub zero_length_segments: Vec<usize>,
+    /// Self intersects of the polyline (local and global). Zero length segments are also reported
+    /// here as overlapping intersects.
+    pub self_intersects: PlineIntersectsCollection<T>,
+    /// True if the polyline is closed and has near zero area.
+    pub near_zero_area: bool,
+}
+
+impl<T> PlineValidationReport<T> {
+    /// Returns true if no problems were found.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.non_finite_vertexes.is_empty()
+            && self.out_of_range_bulges.is_empty()
+            && self.repeated_positions.is_empty()
+            && self.zero_length_segments.is_empty()
+            && self.self_intersects.basic_intersects.is_empty()
+            && self.self_intersects.overlapping_intersects.is_empty()
+            && !self.near_zero_area
+    }
+}
+
 /// Enum to control which self intersects to include.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SelfIntersectsInclude {
@@ -681,7 +1503,8 @@ impl<T> PlineIntersectsCollection<T> {
 #[derive(Debug, Copy, Clone)]
 pub struct BooleanPlineSlice<T = f64> {
     /// View data for the slice, can be used with source polyline to form a view of the vertexes for
-    /// the slice.
+    /// the slice. If the source polyline has arcs larger than a half circle the view data is for
+    /// the polyline returned by [`PlineSource::split_large_arcs`](crate::polyline::PlineSource::split_large_arcs).
     pub view_data: PlineViewData<T>,
     /// If true then the source polyline for this slice is pline1 from the boolean operation
     /// otherwise it is pline2.
@@ -6,8 +6,9 @@ use crate::core::{math::Vector2, traits::Real};
 ///
 /// `x` and `y` describe the 2D position of the vertex. `bulge` describes the arc sweep angle for
 /// the polyline segment that starts with this vertex. `bulge` is defined as
-/// `tan(arc_sweep_angle / 4)`. Note a polyline arc segment can never have a sweep angle greater
-/// than `PI` (half circle).
+/// `tan(arc_sweep_angle / 4)`. Arc segments sweeping more than `PI` (half circle) have a bulge
+/// magnitude greater than 1, a full circle (infinite bulge) is not representable by a single
+/// segment. See [`PlineSource::split_large_arcs`](crate::polyline::PlineSource::split_large_arcs).
 ///
 /// See [`angle_from_bulge`](crate::core::math::angle_from_bulge) and
 /// [`bulge_from_angle`](crate::core::math::bulge_from_angle) for functions to convert between bulge
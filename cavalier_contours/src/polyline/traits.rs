@@ -1,37 +1,57 @@
-use static_aabb2d_index::{
-    AABB, IndexableNum, StaticAABB2DIndex, StaticAABB2DIndexBuildError, StaticAABB2DIndexBuilder,
-};
+use static_aabb2d_index::{AABB, IndexableNum, StaticAABB2DIndex, StaticAABB2DIndexBuilder};
 
 use crate::{
     core::{
         Control,
         math::{
-            Vector2, angle, angle_from_bulge, bulge_from_angle, delta_angle, is_left,
+            Affine2, Vector2, angle, angle_from_bulge, bulge_from_angle, delta_angle, is_left,
             is_left_or_equal, point_on_circle,
         },
         traits::{ControlFlow, FuzzyEq, FuzzyOrd, Real},
     },
     polyline::{
-        PlineContainsOptions, PlineContainsResult, PlineIntersect, SelfIntersectsInclude,
-        TwoPlinesIntersectVisitor, seg_arc_radius_and_center,
+        PlineContainsOptions, PlineContainsResult, PlineIntersect, TwoPlinesIntersectVisitor,
+        seg_arc_radius_and_center,
     },
 };
 
 use super::{
-    BooleanOp, BooleanResult, ClosestPointResult, FindIntersectsOptions, PlineBooleanOptions,
-    PlineIntersectVisitor, PlineIntersectsCollection, PlineOffsetOptions, PlineOrientation,
-    PlineSelfIntersectOptions, PlineVertex, arc_seg_bounding_box,
+    BooleanOp, BooleanResult, ClipKeep, ClipResult, ClosestPointResult, CornerTreatment, FillRule,
+    FindIntersectsOptions, MedialAxis, PathSample, PlineBooleanOptions, PlineClipOptions,
+    PlineConvexHullOptions, PlineCornerOptions, PlineDistanceOptions, PlineDistanceResult,
+    PlineError, PlineFillOptions, PlineFitArcsOptions, PlineIntersectVisitor,
+    PlineIntersectsCollection, PlineMedialAxisOptions, PlineMinkowskiOptions, PlineOffsetOptions,
+    PlineOrientation, PlineRepairOptions, PlineResampleOptions, PlineSampleOptions,
+    PlineSelfIntersectOptions, PlineSimplifyOptions, PlineSliceOptions, PlineTransformOptions,
+    PlineValidateOptions, PlineValidationReport, PlineVariableOffsetOptions, PlineVertex,
+    PlineView, SplitResult, arc_seg_bounding_box,
     internal::{
         pline_boolean::polyline_boolean,
+        pline_clip::clip_polyline,
         pline_contains::polyline_contains,
+        pline_convex_hull::{add_pline_elements, convex_hull},
+        pline_corners::treat_corners,
+        pline_distance,
+        pline_fill::resolve_self_intersects,
+        pline_fit_arcs::fit_arcs,
         pline_intersects::{
-            find_intersects, visit_global_self_intersects, visit_intersects,
-            visit_local_self_intersects,
+            SplitArcsSelfIntersectVisitor, find_intersects, split_large_arcs_source_indexes,
+            visit_intersects, visit_self_intersects_unsplit,
         },
-        pline_offset::parallel_offset,
+        pline_medial_axis::medial_axis,
+        pline_minkowski::minkowski_sum,
+        pline_offset::{parallel_offset, stroke},
+        pline_path_slice,
+        pline_repair::repair,
+        pline_sample,
+        pline_simplify::simplify,
+        pline_transform,
+        pline_validate::validate,
+        pline_variable_offset::variable_parallel_offset,
     },
-    seg_bounding_box, seg_closest_point, seg_fast_approx_bounding_box, seg_length,
-    seg_split_at_point,
+    pline_error::{build_spatial_index, check_finite_param, check_pline_input},
+    seg_bounding_box, seg_closest_point, seg_fast_approx_bounding_box, seg_is_large_arc,
+    seg_length, seg_split_at_midpoint, seg_split_at_point,
 };
 use num_traits::One;
 use num_traits::ToPrimitive;
@@ -936,11 +956,24 @@ pub trait PlineSource {
     ///
     /// # Panics
     ///
-    /// Panics if `Self::Num` type fails to cast to/from a `u16`.
+    /// Panics if `Self::Num` type fails to cast to/from a `u16`, see
+    /// [`PlineSource::try_create_approx_aabb_index`] to return an error instead.
     fn create_approx_aabb_index(&self) -> StaticAABB2DIndex<Self::Num> {
+        unwrap_spatial_index(self.try_create_approx_aabb_index())
+    }
+
+    /// Creates a fast approximate spatial index of all the polyline segments, returning an error
+    /// instead of panicking if the index fails to build.
+    ///
+    /// See [`PlineSource::create_approx_aabb_index`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlineError::SpatialIndex`] if `Self::Num` type fails to cast to/from a `u16`.
+    fn try_create_approx_aabb_index(&self) -> Result<StaticAABB2DIndex<Self::Num>, PlineError> {
         let vc = self.vertex_count();
         if vc < 2 {
-            return unwrap_spatial_index(StaticAABB2DIndexBuilder::new(0));
+            return build_spatial_index(StaticAABB2DIndexBuilder::new(0));
         }
 
         let seg_count = if self.is_closed() { vc } else { vc - 1 };
@@ -957,7 +990,7 @@ pub trait PlineSource {
             );
         }
 
-        unwrap_spatial_index(builder)
+        build_spatial_index(builder)
     }
 
     /// Creates a spatial index of all the polyline segments.
@@ -968,11 +1001,24 @@ pub trait PlineSource {
     ///
     /// # Panics
     ///
-    /// Panics if `Self::Num` type fails to cast to/from a `u16`.
+    /// Panics if `Self::Num` type fails to cast to/from a `u16`, see
+    /// [`PlineSource::try_create_aabb_index`] to return an error instead.
     fn create_aabb_index(&self) -> StaticAABB2DIndex<Self::Num> {
+        unwrap_spatial_index(self.try_create_aabb_index())
+    }
+
+    /// Creates a spatial index of all the polyline segments, returning an error instead of
+    /// panicking if the index fails to build.
+    ///
+    /// See [`PlineSource::create_aabb_index`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlineError::SpatialIndex`] if `Self::Num` type fails to cast to/from a `u16`.
+    fn try_create_aabb_index(&self) -> Result<StaticAABB2DIndex<Self::Num>, PlineError> {
         let vc = self.vertex_count();
         if vc < 2 {
-            return unwrap_spatial_index(StaticAABB2DIndexBuilder::new(0));
+            return build_spatial_index(StaticAABB2DIndexBuilder::new(0));
         }
 
         let seg_count = if self.is_closed() { vc } else { vc - 1 };
@@ -989,7 +1035,7 @@ pub trait PlineSource {
             );
         }
 
-        unwrap_spatial_index(builder)
+        build_spatial_index(builder)
     }
 
     /// Find the closest segment point on a polyline to a `point` given.
@@ -1242,6 +1288,14 @@ pub trait PlineSource {
         for (v1, v2) in self.iter_segments() {
             if v1.bulge_is_zero() {
                 winding += process_line_winding(v1, v2, point);
+            } else if num_traits::real::Real::abs(v1.bulge) > Self::Num::one() {
+                // arc sector tests only hold up to a half circle, process both halves
+                let SplitResult {
+                    updated_start,
+                    split_vertex,
+                } = seg_split_at_midpoint(v1, v2);
+                winding += process_arc_winding(updated_start, split_vertex, point);
+                winding += process_arc_winding(split_vertex, v2, point);
             } else {
                 winding += process_arc_winding(v1, v2, point);
             }
@@ -1293,8 +1347,7 @@ pub trait PlineSource {
             }
 
             let start_angle = angle(arc_center, v1.pos());
-            let end_angle = angle(arc_center, v2.pos());
-            let angle_diff = delta_angle(start_angle, end_angle).abs();
+            let angle_diff = angle_from_bulge(v1.bulge).abs();
 
             let seg_sub_angle =
                 Self::Num::two() * (Self::Num::one() - abs_error / arc_radius).acos().abs();
@@ -1326,6 +1379,395 @@ pub trait PlineSource {
         Some(result)
     }
 
+    /// Returns a new polyline with an affine `transform` applied using default options.
+    ///
+    /// See [`PlineSource::transform_opt`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Affine2;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // circle with radius 5 stretched into an ellipse with radii 10 and 5
+    /// let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    /// let ellipse = circle.transform(&Affine2::scaling(2.0, 1.0));
+    /// assert!(ellipse.vertex_count() > 2);
+    /// assert!(ellipse.area().fuzzy_eq_eps(50.0 * std::f64::consts::PI, 0.1));
+    /// ```
+    fn transform(&self, transform: &Affine2<Self::Num>) -> Self::OutputPolyline {
+        self.transform_opt(transform, &PlineTransformOptions::default())
+    }
+
+    /// Returns a new polyline with an affine `transform` applied.
+    ///
+    /// Vertex positions are transformed and bulge values are negated if the transform mirrors
+    /// (negative [`Affine2::determinant`]). Transforms that map circles to circles (uniform scale,
+    /// rotation, mirroring, and translation) are exact. Other transforms (non-uniform scale or
+    /// shear) turn arc segments into ellipse arcs, if
+    /// [`PlineTransformOptions::approx_ellipse_arcs`] is true the ellipse arcs are approximated by
+    /// arc segments within [`PlineTransformOptions::ellipse_approx_error`], otherwise arc segments
+    /// keep their bulge values (same as [`PlineSourceMut::transform_mut`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Affine2;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline = pline_open![(0.0, 0.0, 1.0), (2.0, 0.0, 0.0)];
+    /// let shear = Affine2::shearing(1.0, 0.0);
+    /// let options = PlineTransformOptions {
+    ///     ellipse_approx_error: 1e-4,
+    ///     ..Default::default()
+    /// };
+    /// let result = polyline.transform_opt(&shear, &options);
+    /// assert!(result.vertex_count() > 2);
+    /// // end points are transformed exactly
+    /// assert_eq!(result[0].pos(), shear.transform_point(polyline[0].pos()));
+    /// assert_eq!(result.last().unwrap().pos(), shear.transform_point(polyline[1].pos()));
+    ///
+    /// // keep arcs as they are
+    /// let options = PlineTransformOptions {
+    ///     approx_ellipse_arcs: false,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(polyline.transform_opt(&shear, &options).vertex_count(), 2);
+    /// ```
+    fn transform_opt(
+        &self,
+        transform: &Affine2<Self::Num>,
+        options: &PlineTransformOptions<Self::Num>,
+    ) -> Self::OutputPolyline {
+        pline_transform::transform(self, transform, options)
+    }
+
+    /// Returns a new polyline with runs of line segments replaced by fewer line and arc segments
+    /// (inverse of [`PlineSource::arcs_to_approx_lines`]), using default options (see
+    /// [`PlineFitArcsOptions`]).
+    ///
+    /// See [`PlineSource::fit_arcs_opt`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let circle = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    /// let lines = circle.arcs_to_approx_lines(1e-3).unwrap();
+    /// assert!(lines.vertex_count() > 100);
+    /// let fitted = lines.fit_arcs(1e-2);
+    /// assert_eq!(fitted.vertex_count(), 2);
+    /// assert!(fitted.area().fuzzy_eq_eps(circle.area(), 0.1));
+    /// ```
+    fn fit_arcs(&self, tolerance: Self::Num) -> Self::OutputPolyline {
+        self.fit_arcs_opt(tolerance, &PlineFitArcsOptions::default())
+    }
+
+    /// Returns a new polyline with runs of line segments replaced by fewer line and arc segments
+    /// (inverse of [`PlineSource::arcs_to_approx_lines`]).
+    ///
+    /// `tolerance` is the maximum distance from the new segments to the end points and midpoints of
+    /// the line segments they replace. Runs of line segments are fit greedily, each new segment
+    /// extends over as many line segments as possible, arcs are not required to be tangent to the
+    /// segments they connect to and sweep at most a half circle. Existing arc segments, the first
+    /// vertex, and the last vertex of an open polyline are kept unchanged. Repeat positions within
+    /// a run of line segments (within [`PlineFitArcsOptions::pos_equal_eps`]) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// // quarter circle of points with a near repeat position
+    /// let mut lines = Polyline::new();
+    /// for i in 0..=8 {
+    ///     let a = std::f64::consts::FRAC_PI_2 * f64::from(i) / 8.0;
+    ///     lines.add(5.0 * a.cos(), 5.0 * a.sin(), 0.0);
+    /// }
+    /// let v = lines[4];
+    /// lines.insert(5, v.x + 1e-4, v.y, 0.0);
+    /// let options = PlineFitArcsOptions {
+    ///     pos_equal_eps: 1e-3,
+    /// };
+    /// let fitted = lines.fit_arcs_opt(0.05, &options);
+    /// assert_eq!(fitted.vertex_count(), 2);
+    /// ```
+    fn fit_arcs_opt(
+        &self,
+        tolerance: Self::Num,
+        options: &PlineFitArcsOptions<Self::Num>,
+    ) -> Self::OutputPolyline {
+        fit_arcs(self, tolerance, options)
+    }
+
+    /// Returns a new polyline with vertexes removed such that the original polyline lies within
+    /// `tolerance` of the result, using default options (see [`PlineSimplifyOptions`]).
+    ///
+    /// See [`PlineSource::simplify_opt`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_open;
+    /// // noisy line followed by an arc
+    /// let pline = pline_open![
+    ///     (0.0, 0.0, 0.0),
+    ///     (1.0, 0.01, 0.0),
+    ///     (2.0, -0.01, 0.0),
+    ///     (3.0, 0.0, 1.0),
+    ///     (5.0, 0.0, 0.0),
+    /// ];
+    /// let simplified = pline.simplify(0.05);
+    /// assert_eq!(simplified.vertex_count(), 3);
+    /// // arc segment is kept
+    /// assert!(simplified.at(1).bulge.fuzzy_eq(1.0));
+    /// ```
+    fn simplify(&self, tolerance: Self::Num) -> Self::OutputPolyline {
+        self.simplify_opt(tolerance, &PlineSimplifyOptions::default())
+    }
+
+    /// Returns a new polyline with vertexes removed such that the original polyline lies within
+    /// `tolerance` of the result.
+    ///
+    /// Vertexes kept are from the original polyline, the first vertex (and last vertex if open) is
+    /// always kept. A run of segments between two kept vertexes is replaced by a single line segment
+    /// only if all of the run (including the bulge of any arc segments) is within `tolerance` of the
+    /// line segment, so arc segments that cannot be replaced are kept unchanged. Closed polylines
+    /// keep at least 3 vertexes. Use [`PlineSimplifyOptions::method`] to choose between
+    /// Douglas-Peucker and Visvalingam-Whyatt vertex removal and
+    /// [`PlineSimplifyOptions::preserve_topology`] to avoid creating self intersects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let pline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (2.0, 0.02, 0.0),
+    ///     (4.0, 0.0, 0.0),
+    ///     (4.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// let options = PlineSimplifyOptions {
+    ///     method: SimplifyMethod::Visvalingam,
+    ///     ..Default::default()
+    /// };
+    /// let simplified = pline.simplify_opt(0.1, &options);
+    /// assert_eq!(simplified.vertex_count(), 4);
+    /// assert!(simplified.area().fuzzy_eq(16.0));
+    /// ```
+    fn simplify_opt(
+        &self,
+        tolerance: Self::Num,
+        options: &PlineSimplifyOptions<Self::Num>,
+    ) -> Self::OutputPolyline {
+        simplify(self, tolerance, options)
+    }
+
+    /// Returns a new polyline with every corner filleted or chamfered using default options (see
+    /// [`PlineCornerOptions`]).
+    ///
+    /// See [`PlineSource::treat_corners_opt`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (4.0, 0.0, 0.0),
+    ///     (4.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// let rounded = square.treat_corners(CornerTreatment::Fillet { radius: 1.0 });
+    /// assert_eq!(rounded.vertex_count(), 8);
+    /// // each corner loses a 1 x 1 square and gains a quarter circle
+    /// let expected_area = 16.0 - 4.0 + std::f64::consts::PI;
+    /// assert!(rounded.area().fuzzy_eq(expected_area));
+    /// ```
+    fn treat_corners(&self, treatment: CornerTreatment<Self::Num>) -> Self::OutputPolyline {
+        self.treat_corners_opt(treatment, &PlineCornerOptions::default())
+    }
+
+    /// Returns a new polyline with corners filleted (rounded with a tangent arc) or chamfered (cut
+    /// with a line segment).
+    ///
+    /// `treatment` is applied to every vertex unless overridden per vertex by
+    /// [`PlineCornerOptions::vertex_treatments`]. Corners between line and arc segments are
+    /// supported, fillet arcs are tangent to both adjacent segments and chamfer distances are
+    /// measured along the adjacent segments (arc length for arc segments). The first and last vertex
+    /// of an open polyline are not corners. Corners where the adjacent segments are tangent are
+    /// left unchanged, as are corners where the treatment does not fit on the adjacent segments or
+    /// overlaps the treatment of a neighboring corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::pline_open;
+    /// let pline = pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+    /// // chamfer the first corner and fillet the second
+    /// let treatments = [
+    ///     CornerTreatment::Sharp,
+    ///     CornerTreatment::Chamfer { distance: 1.0 },
+    ///     CornerTreatment::Fillet { radius: 2.0 },
+    /// ];
+    /// let options = PlineCornerOptions {
+    ///     vertex_treatments: Some(&treatments),
+    ///     ..Default::default()
+    /// };
+    /// let result = pline.treat_corners_opt(CornerTreatment::Sharp, &options);
+    /// assert_eq!(result.vertex_count(), 6);
+    /// assert!(result[1].pos().fuzzy_eq(Vector2::new(3.0, 0.0)));
+    /// assert!(result[2].pos().fuzzy_eq(Vector2::new(4.0, 1.0)));
+    /// // quarter circle fillet from (4, 2) to (2, 4)
+    /// assert!(result[3].pos().fuzzy_eq(Vector2::new(4.0, 2.0)));
+    /// assert!(result[3].bulge.fuzzy_eq((std::f64::consts::PI / 8.0).tan()));
+    /// ```
+    fn treat_corners_opt(
+        &self,
+        treatment: CornerTreatment<Self::Num>,
+        options: &PlineCornerOptions<Self::Num>,
+    ) -> Self::OutputPolyline {
+        treat_corners(self, treatment, options)
+    }
+
+    /// Returns the convex hull of the polyline as a closed counter clockwise polyline, using default
+    /// options (see [`PlineConvexHullOptions`]).
+    ///
+    /// See [`PlineSource::convex_hull_opt`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // 4 x 4 square with a half circle notch cut into the top and a half circle bump on the right
+    /// let pline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (4.0, 0.0, 1.0),
+    ///     (4.0, 4.0, 0.0),
+    ///     (3.0, 4.0, -1.0),
+    ///     (1.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// let hull = pline.convex_hull();
+    /// assert_eq!(hull.vertex_count(), 4);
+    /// // notch is filled in, bump is kept
+    /// assert!(hull.area().fuzzy_eq(16.0 + 2.0 * std::f64::consts::PI));
+    /// ```
+    fn convex_hull(&self) -> Self::OutputPolyline {
+        self.convex_hull_opt(&PlineConvexHullOptions::default())
+    }
+
+    /// Returns the convex hull of the polyline as a closed counter clockwise polyline.
+    ///
+    /// The hull follows arc segments of the polyline where they are on the hull boundary (with
+    /// line segments tangent to the arcs bridging between them) rather than only the hull of the
+    /// vertexes. Returns an empty polyline if the polyline has no vertexes. See
+    /// [`convex_hull_of_points`](super::convex_hull_of_points) and
+    /// [`convex_hull_of_plines`](super::convex_hull_of_plines) for point sets and collections of
+    /// polylines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // 4 x 4 square with a half circle notch cut into the top and a half circle bump on the right
+    /// let pline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (4.0, 0.0, 1.0),
+    ///     (4.0, 4.0, 0.0),
+    ///     (3.0, 4.0, -1.0),
+    ///     (1.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// let options = PlineConvexHullOptions {
+    ///     pos_equal_eps: 1e-8,
+    /// };
+    /// let hull = pline.convex_hull_opt(&options);
+    /// assert_eq!(hull.vertex_count(), 4);
+    /// // notch is filled in, bump is kept
+    /// assert!(hull.area().fuzzy_eq(16.0 + 2.0 * std::f64::consts::PI));
+    /// ```
+    fn convex_hull_opt(&self, options: &PlineConvexHullOptions<Self::Num>) -> Self::OutputPolyline {
+        let mut points = Vec::new();
+        let mut arcs = Vec::new();
+        add_pline_elements(self, &mut points, &mut arcs);
+        convex_hull(&points, &arcs, options.pos_equal_eps)
+    }
+
+    /// Returns a new polyline with every arc segment that sweeps more than a half circle
+    /// (`|bulge| > 1`) split at its midpoint into two arc segments.
+    ///
+    /// The path of the polyline is unchanged. Arcs whose end points are equal (within
+    /// `pos_equal_eps`) are left as is. Returns `None` to avoid allocation and copy in the case
+    /// that there are no arcs to split. Offset, boolean, fill, clip, and containment operations
+    /// apply this automatically to their inputs using their `pos_equal_eps` option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // circle made of a three quarter arc and a quarter arc
+    /// let bulge = (3.0 * std::f64::consts::PI / 8.0).tan();
+    /// let circle = pline_closed![(1.0, 0.0, bulge), (0.0, -1.0, (std::f64::consts::PI / 8.0).tan())];
+    /// let split = circle.split_large_arcs(1e-5).expect("large arc was split");
+    /// assert_eq!(split.vertex_count(), 3);
+    /// assert!(split.iter_vertexes().all(|v| v.bulge.abs() <= 1.0));
+    /// assert!(split.area().fuzzy_eq(circle.area()));
+    /// assert!(split.split_large_arcs(1e-5).is_none());
+    /// ```
+    fn split_large_arcs(&self, pos_equal_eps: Self::Num) -> Option<Self::OutputPolyline> {
+        let large_arc_count = self
+            .iter_segments()
+            .filter(|&(v1, v2)| seg_is_large_arc(v1, v2, pos_equal_eps))
+            .count();
+        if large_arc_count == 0 {
+            return None;
+        }
+
+        let mut result = Self::OutputPolyline::with_capacity(
+            self.vertex_count() + large_arc_count,
+            self.is_closed(),
+        );
+        for (v1, v2) in self.iter_segments() {
+            if seg_is_large_arc(v1, v2, pos_equal_eps) {
+                let SplitResult {
+                    updated_start,
+                    split_vertex,
+                } = seg_split_at_midpoint(v1, v2);
+                result.add_vertex(updated_start);
+                result.add_vertex(split_vertex);
+            } else {
+                result.add_vertex(v1);
+            }
+        }
+
+        if !self.is_closed() {
+            result.add_vertex(self.last().unwrap());
+        }
+
+        result.set_userdata_values(self.get_userdata_values());
+        Some(result)
+    }
+
     /// Visit self intersects of the polyline using default options.
     ///
     /// # Panics
@@ -1370,6 +1812,9 @@ pub trait PlineSource {
 
     /// Visit self intersects of the polyline using options provided.
     ///
+    /// Arcs that sweep more than a half circle are split (see [`PlineSource::split_large_arcs`])
+    /// before finding intersects, visited intersect segment indexes still refer to this polyline.
+    ///
     /// # Panics
     ///
     /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
@@ -1386,32 +1831,66 @@ pub trait PlineSource {
             return C::continuing();
         }
 
-        if options.include == SelfIntersectsInclude::Local {
-            // local intersects only
-            return visit_local_self_intersects(self, visitor, options.pos_equal_eps);
-        }
-
-        let constructed_index;
-        let index = if let Some(x) = options.aabb_index {
-            x
-        } else {
-            constructed_index = self.create_approx_aabb_index();
-            &constructed_index
-        };
-
-        if options.include == SelfIntersectsInclude::Global {
-            // global intersects only
-            return visit_global_self_intersects(self, index, visitor, options.pos_equal_eps);
+        // segment intersect functions assume arcs of at most a half circle
+        if let Some(split) = self.split_large_arcs(options.pos_equal_eps) {
+            let source_indexes = split_large_arcs_source_indexes(self, options.pos_equal_eps);
+            let split_options = PlineSelfIntersectOptions {
+                aabb_index: None,
+                ..*options
+            };
+            return visit_self_intersects_unsplit(
+                &split,
+                &mut SplitArcsSelfIntersectVisitor {
+                    visitor,
+                    source_indexes: &source_indexes,
+                },
+                &split_options,
+            );
         }
 
-        // else all intersects
-        try_cf!(visit_local_self_intersects(
-            self,
-            visitor,
-            options.pos_equal_eps
-        ));
+        visit_self_intersects_unsplit(self, visitor, options)
+    }
 
-        visit_global_self_intersects(self, index, visitor, options.pos_equal_eps)
+    /// Validate the polyline using the options provided, returning a report of the problems that
+    /// may cause other operations (e.g. offset and boolean) to fail or give unexpected results.
+    ///
+    /// Reports vertexes with NaN or infinite values, bulges out of range, repeated positions, zero
+    /// length segments, self intersects, and closed polylines with near zero area. The self
+    /// intersect and area checks are skipped if any vertex has a NaN or infinite value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let options = PlineValidateOptions::default();
+    /// // square with a repeated vertex at the start
+    /// let square = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (0.0, 0.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (2.0, 2.0, 0.0),
+    ///     (0.0, 2.0, 0.0),
+    /// ];
+    /// let report = square.validate(&options);
+    /// assert!(!report.is_valid());
+    /// assert_eq!(report.zero_length_segments, vec![0]);
+    ///
+    /// // bow tie, areas of the two loops cancel out
+    /// let bow_tie = pline_closed![(0.0, 0.0, 0.0), (2.0, 2.0, 0.0), (2.0, 0.0, 0.0), (0.0, 2.0, 0.0)];
+    /// let report = bow_tie.validate(&options);
+    /// assert_eq!(report.self_intersects.basic_intersects.len(), 1);
+    /// assert!(report.near_zero_area);
+    /// ```
+    #[inline]
+    fn validate(
+        &self,
+        options: &PlineValidateOptions<Self::Num>,
+    ) -> PlineValidationReport<Self::Num> {
+        validate(self, options)
     }
 
     /// Visit all intersects between two polylines using default options.
@@ -1608,48 +2087,74 @@ pub trait PlineSource {
         parallel_offset(self, offset, options)
     }
 
-    /// Perform a boolean `operation` between this polyline and another using default options.
+    /// Compute the parallel offset polylines of the polyline using default options, returning an
+    /// error for invalid input instead of panicking.
     ///
-    /// See [`PlineSource::boolean_opt`] for more information.
+    /// See [`PlineSource::try_parallel_offset_opt`] for more information.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    /// See [`PlineSource::try_parallel_offset_opt`].
+    fn try_parallel_offset(
+        &self,
+        offset: Self::Num,
+    ) -> Result<Vec<Self::OutputPolyline>, PlineError> {
+        self.try_parallel_offset_opt(offset, &PlineOffsetOptions::default())
+    }
+
+    /// Compute the parallel offset polylines of the polyline with options given, returning an
+    /// error for invalid input instead of panicking.
+    ///
+    /// Same as [`PlineSource::parallel_offset_opt`] except the input is validated first and the
+    /// spatial index of the polyline (if not given in `options`) is built returning an error if it
+    /// fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlineError::TooFewVertexes`] if the polyline has less than 2 vertexes,
+    /// [`PlineError::NonFiniteCoordinate`] or [`PlineError::NonFiniteBulge`] if a vertex has a NaN
+    /// or infinite value, [`PlineError::NonFiniteParameter`] if `offset` is NaN or infinite, and
+    /// [`PlineError::SpatialIndex`] if the spatial index of the polyline fails to build.
     ///
     /// # Examples
     /// ```
-    /// # use cavalier_contours::core::traits::*;
     /// # use cavalier_contours::polyline::*;
     /// # use cavalier_contours::pline_closed;
-    /// let rectangle = pline_closed![
-    ///     (-1.0, -2.0, 0.0),
-    ///     (3.0, -2.0, 0.0),
-    ///     (3.0, 2.0, 0.0),
-    ///     (-1.0, 2.0, 0.0),
-    /// ];
-    /// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
-    /// let results = rectangle.boolean(&circle, BooleanOp::Not);
-    /// // since the circle is inside the rectangle we get back 1 positive polyline and 1 negative
-    /// // polyline where the positive polyline is the rectangle and the negative polyline is the
-    /// // circle
-    /// assert_eq!(results.pos_plines.len(), 1);
-    /// assert_eq!(results.neg_plines.len(), 1);
-    /// assert!(matches!(results.result_info, BooleanResultInfo::Pline2InsidePline1));
-    /// assert!(results.pos_plines[0].pline.area().fuzzy_eq(rectangle.area()));
-    /// assert!(results.neg_plines[0].pline.area().fuzzy_eq(circle.area()));
+    /// let pline = pline_closed![(0.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+    /// let options = PlineOffsetOptions::default();
+    /// assert_eq!(pline.try_parallel_offset_opt(0.2, &options).unwrap().len(), 1);
+    ///
+    /// let bad_pline = pline_closed![(0.0, 0.0, 1.0), (f64::NAN, 0.0, 1.0)];
+    /// assert_eq!(
+    ///     bad_pline.try_parallel_offset_opt(0.2, &options).unwrap_err(),
+    ///     PlineError::NonFiniteCoordinate { input: 0, index: 1 }
+    /// );
     /// ```
-    fn boolean<P>(&self, other: &P, operation: BooleanOp) -> BooleanResult<Self::OutputPolyline>
-    where
-        P: PlineSource<Num = Self::Num> + ?Sized,
-    {
-        self.boolean_opt(other, operation, &PlineBooleanOptions::default())
+    fn try_parallel_offset_opt(
+        &self,
+        offset: Self::Num,
+        options: &PlineOffsetOptions<Self::Num>,
+    ) -> Result<Vec<Self::OutputPolyline>, PlineError> {
+        check_pline_input(self, 0, false)?;
+        check_finite_param(offset, "offset")?;
+        let constructed_index;
+        let aabb_index = if let Some(x) = options.aabb_index {
+            x
+        } else {
+            constructed_index = self.try_create_approx_aabb_index()?;
+            &constructed_index
+        };
+        let options = PlineOffsetOptions {
+            aabb_index: Some(aabb_index),
+            ..*options
+        };
+        Ok(parallel_offset(self, offset, &options))
     }
 
-    /// Perform a boolean `operation` between this polyline and another with options provided.
+    /// Compute the closed outline of the polyline stroked with half width `distance` on each side
+    /// using default options.
     ///
-    /// Returns the boolean result polylines and their associated slices that were stitched together
-    /// end to end to form them. For the result `pline1` refers to `self`, and `pline2` refers to
-    /// `other`.
+    /// See [`PlineSource::stroke_opt`] for more information.
     ///
     /// # Panics
     ///
@@ -1657,11 +2162,346 @@ pub trait PlineSource {
     ///
     /// # Examples
     /// ```
+    /// # use cavalier_contours::polyline::*;
     /// # use cavalier_contours::core::traits::*;
+    /// let mut pline = Polyline::new();
+    /// pline.add(0.0, 0.0, 0.0);
+    /// pline.add(10.0, 0.0, 0.0);
+    /// // round caps by default
+    /// let outlines = pline.stroke(1.0);
+    /// assert_eq!(outlines.len(), 1);
+    /// assert!(outlines[0].is_closed());
+    /// assert!(outlines[0].area().fuzzy_eq(20.0 + std::f64::consts::PI));
+    /// ```
+    fn stroke(&self, distance: Self::Num) -> Vec<Self::OutputPolyline> {
+        self.stroke_opt(distance, &PlineOffsetOptions::default())
+    }
+
+    /// Compute the closed outline of the polyline stroked with half width `distance` on each side
+    /// with options given.
+    ///
+    /// For an open polyline the result is the outline of both side offsets connected by caps at
+    /// the polyline ends, [`PlineOffsetOptions::cap_type`] selects the cap shape. For a closed
+    /// polyline the result is its outward and inward parallel offsets. Outer boundaries are
+    /// counter clockwise and holes are clockwise. Corners follow
+    /// [`PlineOffsetOptions::join_type`]. A `distance` that is not positive returns no polylines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
     /// # use cavalier_contours::polyline::*;
-    /// # use cavalier_contours::pline_closed;
-    /// let rectangle = pline_closed![
-    ///     (-1.0, -2.0, 0.0),
+    /// # use cavalier_contours::core::traits::*;
+    /// let mut pline = Polyline::new();
+    /// pline.add(0.0, 0.0, 0.0);
+    /// pline.add(10.0, 0.0, 0.0);
+    /// let options = PlineOffsetOptions {
+    ///     cap_type: OffsetCapType::Square,
+    ///     ..Default::default()
+    /// };
+    /// let outlines = pline.stroke_opt(1.0, &options);
+    /// assert_eq!(outlines.len(), 1);
+    /// // 12 x 2 rectangle
+    /// assert!(outlines[0].area().fuzzy_eq(24.0));
+    /// ```
+    fn stroke_opt(
+        &self,
+        distance: Self::Num,
+        options: &PlineOffsetOptions<Self::Num>,
+    ) -> Vec<Self::OutputPolyline> {
+        stroke(self, distance, options)
+    }
+
+    /// Compute the Minkowski sum of the polyline (as a path) with a convex closed polyline `shape`
+    /// using default options.
+    ///
+    /// See [`PlineSource::minkowski_sum_opt`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// let path = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    /// // 2 x 1 rectangle centered on the origin
+    /// let tool = pline_closed![
+    ///     (-1.0, -0.5, 0.0),
+    ///     (1.0, -0.5, 0.0),
+    ///     (1.0, 0.5, 0.0),
+    ///     (-1.0, 0.5, 0.0),
+    /// ];
+    /// let outlines = path.minkowski_sum(&tool);
+    /// assert_eq!(outlines.len(), 1);
+    /// // 12 x 1 rectangle
+    /// assert!(outlines[0].area().fuzzy_eq(12.0));
+    /// ```
+    fn minkowski_sum<S>(&self, shape: &S) -> Vec<Self::OutputPolyline>
+    where
+        S: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.minkowski_sum_opt(shape, &PlineMinkowskiOptions::default())
+    }
+
+    /// Compute the Minkowski sum of the polyline (as a path) with a convex closed polyline `shape`
+    /// with options given.
+    ///
+    /// The result is the closed outline of the region covered by `shape` moved along the path,
+    /// with the origin of `shape` following the path. Sweeping a circle centered on the origin is
+    /// the same as [`PlineSource::stroke`] with round caps and joins. Outer boundaries are counter
+    /// clockwise and holes (e.g. inside a closed path) are clockwise.
+    ///
+    /// The convex hull of `shape` is used if it is not convex (see [`PlineSource::convex_hull`]).
+    /// Path arc segments are swept exactly if `shape` is made of line segments, otherwise they are
+    /// approximated by line segments within [`PlineMinkowskiOptions::arc_approx_error`]. A path with
+    /// a single vertex returns `shape` moved to that vertex. An empty path or a `shape` with no
+    /// area returns no polylines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // closed square path swept by a 2 x 2 square
+    /// let path = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let tool = pline_closed![
+    ///     (-1.0, -1.0, 0.0),
+    ///     (1.0, -1.0, 0.0),
+    ///     (1.0, 1.0, 0.0),
+    ///     (-1.0, 1.0, 0.0),
+    /// ];
+    /// let outlines = path.minkowski_sum_opt(&tool, &PlineMinkowskiOptions::default());
+    /// assert_eq!(outlines.len(), 2);
+    /// // outer 12 x 12 square and inner 8 x 8 square hole
+    /// assert!(outlines[0].area().fuzzy_eq(144.0));
+    /// assert!(outlines[1].area().fuzzy_eq(-64.0));
+    /// ```
+    fn minkowski_sum_opt<S>(
+        &self,
+        shape: &S,
+        options: &PlineMinkowskiOptions<Self::Num>,
+    ) -> Vec<Self::OutputPolyline>
+    where
+        S: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        minkowski_sum(self, shape, options)
+    }
+
+    /// Compute the approximate medial axis of the closed polyline using default options.
+    ///
+    /// See [`PlineSource::medial_axis_opt`] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (20.0, 0.0, 0.0),
+    ///     (20.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let axis = rectangle.medial_axis(0.1);
+    /// // center line with two branches to the corners at each end
+    /// assert_eq!(axis.edges.len(), 5);
+    /// let junctions = (0..axis.nodes.len())
+    ///     .filter(|&i| axis.node_degree(i) == 3)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(junctions.len(), 2);
+    /// for i in junctions {
+    ///     assert!(axis.nodes[i].radius.fuzzy_eq_eps(5.0, 0.01));
+    /// }
+    /// ```
+    fn medial_axis(&self, sample_distance: Self::Num) -> MedialAxis<Self::Num> {
+        self.medial_axis_opt(sample_distance, &PlineMedialAxisOptions::default())
+    }
+
+    /// Compute the approximate medial axis of the closed polyline with options given.
+    ///
+    /// The medial axis is the set of points inside the polyline with more than one closest point
+    /// on the polyline, it is returned as a graph of branches (polylines of line segments) with
+    /// the clearance radius (distance to the polyline) at every point, e.g. for engraving with a
+    /// V shaped tool or finding the center line of a stroke outline.
+    ///
+    /// The polyline is sampled with at most `sample_distance` between samples and the medial axis
+    /// is approximated from the Voronoi diagram of the samples, branches are within about
+    /// `sample_distance` of the exact medial axis (parabolic branches between a vertex and a
+    /// segment and arc branches are approximated by line segments). Branches into corners with an
+    /// interior angle greater than `PI - min_angle` are not included (see
+    /// [`PlineMedialAxisOptions::min_angle`]). The medial axis of a circle is a single node at the
+    /// center. An open polyline or `sample_distance` that is not positive returns an empty medial
+    /// axis. See [`Shape::medial_axis`](crate::shape_algorithms::Shape::medial_axis) for shapes
+    /// with holes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    /// let axis = circle.medial_axis_opt(0.1, &PlineMedialAxisOptions::default());
+    /// assert!(axis.edges.is_empty());
+    /// assert_eq!(axis.nodes.len(), 1);
+    /// assert!(axis.nodes[0].pos.length() < 1e-5);
+    /// assert!(axis.nodes[0].radius.fuzzy_eq(5.0));
+    /// ```
+    fn medial_axis_opt(
+        &self,
+        sample_distance: Self::Num,
+        options: &PlineMedialAxisOptions<Self::Num>,
+    ) -> MedialAxis<Self::Num> {
+        medial_axis(&[self], sample_distance, options)
+    }
+
+    /// Compute the variable distance parallel offset polylines of the polyline using default
+    /// options.
+    ///
+    /// See [`PlineSource::variable_parallel_offset_opt`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_open;
+    /// let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    /// // taper from 1 to 2 along the line
+    /// let offset_plines = pline.variable_parallel_offset(&[1.0, 2.0]);
+    /// assert_eq!(offset_plines.len(), 1);
+    /// let offset_pline = &offset_plines[0];
+    /// // line tangent to the circles at the ends
+    /// assert_eq!(offset_pline.vertex_count(), 2);
+    /// assert!(offset_pline[0].pos().length().fuzzy_eq(1.0));
+    /// assert!((offset_pline[1].pos() - pline[1].pos()).length().fuzzy_eq(2.0));
+    /// ```
+    fn variable_parallel_offset(&self, distances: &[Self::Num]) -> Vec<Self::OutputPolyline> {
+        self.variable_parallel_offset_opt(distances, &PlineVariableOffsetOptions::default())
+    }
+
+    /// Compute the variable distance (tapered) parallel offset polylines of the polyline with
+    /// options given.
+    ///
+    /// `distances` holds one offset distance per vertex, the distance is interpolated linearly
+    /// along each segment by path length. The offset is the boundary of the area swept by circles
+    /// centered on the polyline with the interpolated distance as radius (so tapered segments are
+    /// tangent to the circles at their end vertexes). Positive distances offset to the left of the
+    /// polyline segment tangent vectors and negative distances offset to the right, all distances
+    /// must have the same sign (zero is allowed). If `distances` does not have one value per vertex
+    /// or mixes signs then no polylines are returned, if all distances are zero then a copy of the
+    /// polyline is returned (matching [`PlineSource::parallel_offset`] with zero offset).
+    ///
+    /// Line segments offset to line segments and arc segments with equal distance at both ends
+    /// offset to arc segments, arc segments with changing distance are approximated by multiple
+    /// arc segments within [`PlineVariableOffsetOptions::spiral_error_tol`]. Joins and end caps are
+    /// always round.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let aabb_index = square.create_approx_aabb_index();
+    /// let options = PlineVariableOffsetOptions {
+    ///     aabb_index: Some(&aabb_index),
+    ///     ..Default::default()
+    /// };
+    /// // inward offset (left side of counter clockwise polyline) with distance 1 at the bottom
+    /// // vertexes and 2 at the top vertexes
+    /// let offset_plines = square.variable_parallel_offset_opt(&[1.0, 1.0, 2.0, 2.0], &options);
+    /// assert_eq!(offset_plines.len(), 1);
+    /// assert!(offset_plines[0].is_closed());
+    /// assert!(offset_plines[0].area() < 64.0 && offset_plines[0].area() > 36.0);
+    /// ```
+    fn variable_parallel_offset_opt(
+        &self,
+        distances: &[Self::Num],
+        options: &PlineVariableOffsetOptions<Self::Num>,
+    ) -> Vec<Self::OutputPolyline> {
+        variable_parallel_offset(self, distances, options)
+    }
+
+    /// Perform a boolean `operation` between this polyline and another using default options.
+    ///
+    /// See [`PlineSource::boolean_opt`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle = pline_closed![
+    ///     (-1.0, -2.0, 0.0),
+    ///     (3.0, -2.0, 0.0),
+    ///     (3.0, 2.0, 0.0),
+    ///     (-1.0, 2.0, 0.0),
+    /// ];
+    /// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let results = rectangle.boolean(&circle, BooleanOp::Not);
+    /// // since the circle is inside the rectangle we get back 1 positive polyline and 1 negative
+    /// // polyline where the positive polyline is the rectangle and the negative polyline is the
+    /// // circle
+    /// assert_eq!(results.pos_plines.len(), 1);
+    /// assert_eq!(results.neg_plines.len(), 1);
+    /// assert!(matches!(results.result_info, BooleanResultInfo::Pline2InsidePline1));
+    /// assert!(results.pos_plines[0].pline.area().fuzzy_eq(rectangle.area()));
+    /// assert!(results.neg_plines[0].pline.area().fuzzy_eq(circle.area()));
+    /// ```
+    fn boolean<P>(&self, other: &P, operation: BooleanOp) -> BooleanResult<Self::OutputPolyline>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.boolean_opt(other, operation, &PlineBooleanOptions::default())
+    }
+
+    /// Perform a boolean `operation` between this polyline and another with options provided.
+    ///
+    /// Returns the boolean result polylines and their associated slices that were stitched together
+    /// end to end to form them. For the result `pline1` refers to `self`, and `pline2` refers to
+    /// `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle = pline_closed![
+    ///     (-1.0, -2.0, 0.0),
     ///     (3.0, -2.0, 0.0),
     ///     (3.0, 2.0, 0.0),
     ///     (-1.0, 2.0, 0.0),
@@ -1695,6 +2535,233 @@ pub trait PlineSource {
         polyline_boolean(self, other, operation, options)
     }
 
+    /// Perform a boolean `operation` between this polyline and another using default options,
+    /// returning an error for invalid input instead of panicking.
+    ///
+    /// See [`PlineSource::try_boolean_opt`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// See [`PlineSource::try_boolean_opt`].
+    fn try_boolean<P>(
+        &self,
+        other: &P,
+        operation: BooleanOp,
+    ) -> Result<BooleanResult<Self::OutputPolyline>, PlineError>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.try_boolean_opt(other, operation, &PlineBooleanOptions::default())
+    }
+
+    /// Perform a boolean `operation` between this polyline and another with options provided,
+    /// returning an error for invalid input instead of panicking.
+    ///
+    /// Same as [`PlineSource::boolean_opt`] except the input is validated first and the spatial
+    /// index of `self` (if not given in `options`) is built returning an error if it fails. In
+    /// errors `input` 0 refers to `self` and 1 refers to `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlineError::TooFewVertexes`] if a polyline has less than 2 vertexes,
+    /// [`PlineError::NotClosed`] if a polyline is open, [`PlineError::NonFiniteCoordinate`] or
+    /// [`PlineError::NonFiniteBulge`] if a vertex has a NaN or infinite value, and
+    /// [`PlineError::SpatialIndex`] if the spatial index of `self` fails to build.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let options = PlineBooleanOptions::default();
+    /// let result = circle.try_boolean_opt(&circle, BooleanOp::Or, &options).unwrap();
+    /// assert_eq!(result.pos_plines.len(), 1);
+    ///
+    /// let line = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)];
+    /// assert_eq!(
+    ///     circle.try_boolean_opt(&line, BooleanOp::Or, &options).unwrap_err(),
+    ///     PlineError::NotClosed { input: 1 }
+    /// );
+    /// ```
+    fn try_boolean_opt<P>(
+        &self,
+        other: &P,
+        operation: BooleanOp,
+        options: &PlineBooleanOptions<Self::Num>,
+    ) -> Result<BooleanResult<Self::OutputPolyline>, PlineError>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        check_pline_input(self, 0, true)?;
+        check_pline_input(other, 1, true)?;
+        let constructed_index;
+        let pline1_aabb_index = if let Some(x) = options.pline1_aabb_index {
+            x
+        } else {
+            constructed_index = self.try_create_approx_aabb_index()?;
+            &constructed_index
+        };
+        let options = PlineBooleanOptions {
+            pline1_aabb_index: Some(pline1_aabb_index),
+            ..*options
+        };
+        Ok(polyline_boolean(self, other, operation, &options))
+    }
+
+    /// Resolve this closed polyline into non-self-intersecting closed polylines using
+    /// `fill_rule` and default options.
+    ///
+    /// See [`PlineSource::resolve_self_intersects_opt`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    #[inline]
+    fn resolve_self_intersects(&self, fill_rule: FillRule) -> Vec<Self::OutputPolyline> {
+        self.resolve_self_intersects_opt(fill_rule, &PlineFillOptions::default())
+    }
+
+    /// Resolve this closed polyline into non-self-intersecting closed polylines using
+    /// `fill_rule` and the options provided.
+    ///
+    /// The areas enclosed by the polyline are filled according to `fill_rule` (using the winding
+    /// number of each area). Result polylines bounding filled area are counter clockwise and result
+    /// polylines bounding holes are clockwise. Areas that only touch at a point are returned as
+    /// separate polylines. Returns an empty vector if the polyline is open or has no filled area.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // figure eight, left loop is counter clockwise and right loop is clockwise
+    /// let figure_eight = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (2.0, 2.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (0.0, 2.0, 0.0),
+    /// ];
+    /// let result = figure_eight.resolve_self_intersects_opt(FillRule::NonZero, &Default::default());
+    /// assert_eq!(result.len(), 2);
+    /// assert!(result.iter().all(|p| p.orientation() == PlineOrientation::CounterClockwise));
+    /// assert!(result.iter().all(|p| p.area().fuzzy_eq(1.0)));
+    /// ```
+    fn resolve_self_intersects_opt(
+        &self,
+        fill_rule: FillRule,
+        options: &PlineFillOptions<Self::Num>,
+    ) -> Vec<Self::OutputPolyline> {
+        resolve_self_intersects(self, fill_rule, options)
+    }
+
+    /// Repair this polyline into valid non-self-intersecting closed polylines using default
+    /// options.
+    ///
+    /// See [`PlineSource::repair_opt`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    #[inline]
+    fn repair(&self) -> Vec<Self::OutputPolyline> {
+        self.repair_opt(&PlineRepairOptions::default())
+    }
+
+    /// Repair this polyline (e.g. messy imported geometry) into valid non-self-intersecting closed
+    /// polylines using the options provided.
+    ///
+    /// Vertexes with NaN or infinite values are discarded, open polylines that nearly close (within
+    /// `close_tolerance`) are closed, repeat positions, spikes, and redundant vertexes are removed,
+    /// and self intersects are resolved using the fill rule (splitting figure eights into separate
+    /// loops). Resulting loops with near zero area (slivers) are discarded. Polylines that bound
+    /// filled area are counter clockwise and polylines that bound holes are clockwise. Returns an
+    /// empty vector if the polyline is open and does not nearly close or nothing with area remains.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// // clockwise figure eight that does not quite close, with a spike out to (3, -1) and back
+    /// // on the third and fourth segments
+    /// let messy = pline_open![
+    ///     (0.0, 0.0, 0.0),
+    ///     (0.0, 2.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (3.0, -1.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (2.0, 2.0, 0.0),
+    ///     (0.0, 0.0001, 0.0),
+    /// ];
+    /// let result = messy.repair_opt(&PlineRepairOptions::default());
+    /// assert_eq!(result.len(), 2);
+    /// assert!(result.iter().all(|p| p.orientation() == PlineOrientation::CounterClockwise));
+    /// assert!(result.iter().all(|p| p.area().fuzzy_eq_eps(1.0, 1e-3)));
+    /// ```
+    fn repair_opt(&self, options: &PlineRepairOptions<Self::Num>) -> Vec<Self::OutputPolyline> {
+        repair(self, options)
+    }
+
+    /// Clip this polyline against a closed `boundary` polyline using default options.
+    ///
+    /// See [`PlineSource::clip_opt`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    #[inline]
+    fn clip<P>(&self, boundary: &P, keep: ClipKeep) -> ClipResult<Self::OutputPolyline>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.clip_opt(boundary, keep, &PlineClipOptions::default())
+    }
+
+    /// Clip this polyline against a closed `boundary` polyline using the options provided.
+    ///
+    /// The polyline is split at every intersect with the boundary and the parts selected by `keep`
+    /// are returned (in order following the polyline direction) along with the source segment
+    /// indexes they start and end on. Parts lying on the boundary are considered inside. This
+    /// polyline may be open or closed, if it is closed and does not intersect the boundary then it
+    /// is returned whole (still closed). Returns an empty result if the boundary is not closed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// let boundary = pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+    /// // hatch line crossing the boundary
+    /// let line = pline_open![(-1.0, 2.0, 0.0), (5.0, 2.0, 0.0)];
+    /// let result = line.clip_opt(&boundary, ClipKeep::Both, &Default::default());
+    /// assert_eq!(result.inside.len(), 1);
+    /// assert!(result.inside[0].pline.path_length().fuzzy_eq(4.0));
+    /// assert_eq!(result.outside.len(), 2);
+    /// ```
+    fn clip_opt<P>(
+        &self,
+        boundary: &P,
+        keep: ClipKeep,
+        options: &PlineClipOptions<Self::Num>,
+    ) -> ClipResult<Self::OutputPolyline>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        clip_polyline(self, boundary, keep, options)
+    }
+
     /// Determine if this polyline fully contains another using default options.
     ///
     /// Caution: Polylines with self-intersections may generate unexpected results.
@@ -1855,6 +2922,451 @@ pub trait PlineSource {
 
         Err(acc_length)
     }
+
+    /// Returns a view of the part of the polyline between the path lengths `start` and `end` using
+    /// default options (see [`PlineSliceOptions`]).
+    ///
+    /// See [`PlineSource::slice_by_path_length_opt`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_closed;
+    /// let square: Polyline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// // wraps past the first vertex
+    /// let view = square.slice_by_path_length(35.0, 15.0).unwrap();
+    /// assert!(view.path_length().fuzzy_eq(20.0));
+    /// assert!(view.at(0).pos().fuzzy_eq(Vector2::new(0.0, 5.0)));
+    /// assert!(view.last().unwrap().pos().fuzzy_eq(Vector2::new(10.0, 5.0)));
+    /// let owned = Polyline::create_from(&view);
+    /// assert_eq!(owned.vertex_count(), 4);
+    /// assert!(!owned.is_closed());
+    /// ```
+    fn slice_by_path_length(
+        &self,
+        start: Self::Num,
+        end: Self::Num,
+    ) -> Option<PlineView<'_, Self>> {
+        self.slice_by_path_length_opt(start, end, &PlineSliceOptions::default())
+    }
+
+    /// Returns a view of the part of the polyline between the path lengths `start` and `end`, or
+    /// `None` if the part has zero length (within [`PlineSliceOptions::pos_equal_eps`]), `start` or
+    /// `end` is not finite, or the polyline has less than 2 vertexes.
+    ///
+    /// For an open polyline `start` and `end` are clamped to the path and `None` is returned if
+    /// `end` is not after `start`. For a closed polyline the path lengths wrap around the loop (e.g.
+    /// a negative length is measured back from the end) and the part always goes forward from
+    /// `start` to `end`, passing the first vertex if `end` comes before `start` on the loop. If
+    /// `end - start` is at least the path length the view is the entire loop starting at `start`.
+    ///
+    /// The view borrows the polyline without copying, use [`PlineCreation::create_from`] to create
+    /// an owned polyline from it. See [`PlineSource::find_path_length_at_point`] to find the path
+    /// length of a point on the polyline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_closed;
+    /// let square: Polyline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let options = PlineSliceOptions {
+    ///     pos_equal_eps: 1e-3,
+    /// };
+    /// // end is within the epsilon of the start so the part has zero length
+    /// assert!(square.slice_by_path_length_opt(5.0, 5.0001, &options).is_none());
+    /// let view = square.slice_by_path_length_opt(5.0, 15.0, &options).unwrap();
+    /// assert!(view.path_length().fuzzy_eq(10.0));
+    /// assert!(view.at(0).pos().fuzzy_eq(Vector2::new(5.0, 0.0)));
+    /// assert!(view.last().unwrap().pos().fuzzy_eq(Vector2::new(10.0, 5.0)));
+    /// ```
+    fn slice_by_path_length_opt(
+        &self,
+        start: Self::Num,
+        end: Self::Num,
+        options: &PlineSliceOptions<Self::Num>,
+    ) -> Option<PlineView<'_, Self>> {
+        pline_path_slice::slice_by_path_length(self, start, end, options.pos_equal_eps)
+            .map(|data| data.view(self))
+    }
+
+    /// Find the path length from the start of the polyline to the closest point on the polyline to
+    /// `point` (inverse of [`PlineSource::find_point_at_path_length`]). Returns `None` if the
+    /// polyline is empty.
+    ///
+    /// `pos_equal_eps` is used for the closest point (see [`PlineSource::closest_point`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_open;
+    /// // line followed by a half circle with radius 5
+    /// let polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 10.0, 0.0)];
+    /// let length = polyline.find_path_length_at_point(Vector2::new(20.0, 5.0), 1e-5).unwrap();
+    /// assert!(length.fuzzy_eq(10.0 + 5.0 * std::f64::consts::FRAC_PI_2));
+    /// let length = polyline.find_path_length_at_point(Vector2::new(4.0, -3.0), 1e-5).unwrap();
+    /// assert!(length.fuzzy_eq(4.0));
+    /// ```
+    fn find_path_length_at_point(
+        &self,
+        point: Vector2<Self::Num>,
+        pos_equal_eps: Self::Num,
+    ) -> Option<Self::Num> {
+        pline_path_slice::find_path_length_at_point(self, point, pos_equal_eps)
+    }
+
+    /// Find the minimum distance between this polyline and `other` using default options.
+    ///
+    /// Returns the distance with the closest pair of points and the segments they lie on, or
+    /// `None` if either polyline is empty or has a NaN or infinite vertex. The distance is zero if
+    /// the polylines intersect (or overlap) and the points are then an intersect point. Arc
+    /// segments are handled exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// // circle with radius 5 centered at (0, 0)
+    /// let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+    /// let line = pline_open![(-10.0, 8.0, 0.0), (10.0, 8.0, 0.0)];
+    /// let result = circle.min_distance(&line).unwrap();
+    /// assert!(result.distance.fuzzy_eq(3.0));
+    /// assert!(result.point.fuzzy_eq(Vector2::new(0.0, 5.0)));
+    /// assert!(result.other_point.fuzzy_eq(Vector2::new(0.0, 8.0)));
+    /// ```
+    #[inline]
+    fn min_distance<P>(&self, other: &P) -> Option<PlineDistanceResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.min_distance_opt(other, &PlineDistanceOptions::default())
+    }
+
+    /// Find the minimum distance between this polyline and `other` using the options provided.
+    ///
+    /// Segments of `other` are found using its spatial index (`options.pline2_aabb_index`, created
+    /// if not given) with queries expanded by the current minimum distance. See
+    /// [`PlineSource::min_distance`] for more information.
+    #[inline]
+    fn min_distance_opt<P>(
+        &self,
+        other: &P,
+        options: &PlineDistanceOptions<Self::Num>,
+    ) -> Option<PlineDistanceResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        pline_distance::min_distance(self, other, options)
+    }
+
+    /// Find the one-sided Hausdorff distance from this polyline to `other` using default options.
+    ///
+    /// This is the maximum over all points on this polyline of the distance to the closest point
+    /// on `other`, e.g. how far a machined path strays from the nominal path. Returns the distance
+    /// with the point on this polyline where it occurs and its closest point on `other`, or `None`
+    /// if either polyline is empty or has a NaN or infinite vertex.
+    ///
+    /// The distance is evaluated exactly for arc segments (no arc approximation), the result is
+    /// within `options.hausdorff_tolerance` below the true distance (see
+    /// [`PlineSource::hausdorff_distance_opt`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_open;
+    /// // half circle with radius 5 bulging up from the line
+    /// let arc = pline_open![(-5.0, 0.0, -1.0), (5.0, 0.0, 0.0)];
+    /// let line = pline_open![(-5.0, 0.0, 0.0), (5.0, 0.0, 0.0)];
+    /// let result = arc.hausdorff_distance(&line).unwrap();
+    /// assert!(result.distance.fuzzy_eq_eps(5.0, 1e-4));
+    /// assert!(result.point.fuzzy_eq_eps(Vector2::new(0.0, 5.0), 1e-2));
+    /// assert!(result.other_point.fuzzy_eq_eps(Vector2::new(0.0, 0.0), 1e-2));
+    /// ```
+    #[inline]
+    fn hausdorff_distance<P>(&self, other: &P) -> Option<PlineDistanceResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.hausdorff_distance_opt(other, &PlineDistanceOptions::default())
+    }
+
+    /// Find the one-sided Hausdorff distance from this polyline to `other` using the options
+    /// provided.
+    ///
+    /// Segments of this polyline are subdivided (best first) until the upper bound of the distance
+    /// over every piece is within `options.hausdorff_tolerance` of the largest distance found.
+    /// Closest points on `other` are found using its spatial index (`options.pline2_aabb_index`,
+    /// created if not given). See [`PlineSource::hausdorff_distance`] for more information.
+    #[inline]
+    fn hausdorff_distance_opt<P>(
+        &self,
+        other: &P,
+        options: &PlineDistanceOptions<Self::Num>,
+    ) -> Option<PlineDistanceResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        pline_distance::hausdorff_distance(self, other, options)
+    }
+
+    /// Find the symmetric Hausdorff distance between this polyline and `other` using default
+    /// options.
+    ///
+    /// This is the maximum of the one-sided Hausdorff distances in both directions (see
+    /// [`PlineSource::hausdorff_distance`]). In the result `point` is always on this polyline and
+    /// `other_point` on `other` regardless of which direction the maximum occurs in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_open;
+    /// let short = pline_open![(0.0, 0.0, 0.0), (5.0, 0.0, 0.0)];
+    /// let long = pline_open![(0.0, 1.0, 0.0), (10.0, 1.0, 0.0)];
+    /// assert!(short.hausdorff_distance(&long).unwrap().distance.fuzzy_eq(1.0));
+    /// let result = short.symmetric_hausdorff_distance(&long).unwrap();
+    /// assert!(result.distance.fuzzy_eq(26.0_f64.sqrt()));
+    /// assert!(result.other_point.x.fuzzy_eq(10.0));
+    /// ```
+    #[inline]
+    fn symmetric_hausdorff_distance<P>(&self, other: &P) -> Option<PlineDistanceResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.symmetric_hausdorff_distance_opt(other, &PlineDistanceOptions::default())
+    }
+
+    /// Find the symmetric Hausdorff distance between this polyline and `other` using the options
+    /// provided.
+    ///
+    /// `options.pline1_aabb_index` is used for the distance from `other` to this polyline and
+    /// `options.pline2_aabb_index` for the distance from this polyline to `other`. See
+    /// [`PlineSource::symmetric_hausdorff_distance`] for more information.
+    #[inline]
+    fn symmetric_hausdorff_distance_opt<P>(
+        &self,
+        other: &P,
+        options: &PlineDistanceOptions<Self::Num>,
+    ) -> Option<PlineDistanceResult<Self::Num>>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        pline_distance::symmetric_hausdorff_distance(self, other, options)
+    }
+
+    /// Sample `count` equally spaced points along the polyline path using default options (see
+    /// [`PlineSampleOptions`]).
+    ///
+    /// See [`PlineSource::sample_points_opt`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    /// let samples = polyline.sample_points(3);
+    /// assert_eq!(samples.len(), 3);
+    /// assert!(samples[1].point.fuzzy_eq(Vector2::new(5.0, 0.0)));
+    /// assert!(samples[1].tangent.fuzzy_eq(Vector2::new(1.0, 0.0)));
+    /// assert!(samples[1].normal.fuzzy_eq(Vector2::new(0.0, 1.0)));
+    /// assert!(samples[2].path_length.fuzzy_eq(10.0));
+    /// ```
+    fn sample_points(&self, count: usize) -> Vec<PathSample<Self::Num>> {
+        self.sample_points_opt(count, &PlineSampleOptions::default())
+    }
+
+    /// Sample `count` equally spaced points along the polyline path, each with the segment index,
+    /// path length, and unit tangent and normal vectors (from
+    /// [`seg_tangent_vector`](super::seg_tangent_vector)) at the point.
+    ///
+    /// For an open polyline the samples include both end points (spacing is the path length
+    /// divided by `count - 1`). For a closed polyline the samples start at the first vertex and
+    /// do not repeat it at the end (spacing is the path length divided by `count`). Returns no
+    /// samples if `count` is zero or the path length is zero.
+    ///
+    /// A point at a vertex joining two segments uses the segment ending at the vertex, except at
+    /// the start of the polyline. Zero length segments (within
+    /// [`PlineSampleOptions::pos_equal_eps`]) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_open;
+    /// // second segment is shorter than the epsilon so it is skipped
+    /// let polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 1e-4, 0.0)];
+    /// let options = PlineSampleOptions {
+    ///     pos_equal_eps: 1e-3,
+    /// };
+    /// let samples = polyline.sample_points_opt(3, &options);
+    /// assert_eq!(samples.len(), 3);
+    /// assert!(samples[1].point.fuzzy_eq_eps(Vector2::new(5.0, 0.0), 1e-3));
+    /// assert_eq!(samples[2].seg_index, 0);
+    /// assert!(samples[2].tangent.fuzzy_eq(Vector2::new(1.0, 0.0)));
+    /// ```
+    fn sample_points_opt(
+        &self,
+        count: usize,
+        options: &PlineSampleOptions<Self::Num>,
+    ) -> Vec<PathSample<Self::Num>> {
+        pline_sample::sample_points(self, count, options)
+    }
+
+    /// Sample points along the polyline path at a fixed `spacing` starting at the first vertex
+    /// using default options (see [`PlineSampleOptions`]).
+    ///
+    /// See [`PlineSource::sample_at_spacing_opt`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_closed;
+    /// // circle with radius 1
+    /// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let samples = circle.sample_at_spacing(std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(samples.len(), 4);
+    /// assert!(samples[1].point.fuzzy_eq(Vector2::new(1.0, -1.0)));
+    /// // counter clockwise so normals point to the center
+    /// assert!(samples[1].normal.fuzzy_eq(Vector2::new(0.0, 1.0)));
+    /// assert_eq!(samples[2].seg_index, 0);
+    /// assert_eq!(samples[3].seg_index, 1);
+    /// ```
+    fn sample_at_spacing(&self, spacing: Self::Num) -> Vec<PathSample<Self::Num>> {
+        self.sample_at_spacing_opt(spacing, &PlineSampleOptions::default())
+    }
+
+    /// Sample points along the polyline path at a fixed `spacing` starting at the first vertex,
+    /// each with the segment index, path length, and unit tangent and normal vectors (from
+    /// [`seg_tangent_vector`](super::seg_tangent_vector)) at the point.
+    ///
+    /// The last sample of an open polyline is at the end only if the path length is a multiple of
+    /// `spacing` (within [`PlineSampleOptions::pos_equal_eps`]). For a closed polyline a sample
+    /// coinciding with the start at the end of the path is not repeated. Zero length segments are
+    /// skipped. Returns no samples if `spacing` is not positive or the path length is zero.
+    ///
+    /// See [`PlineSource::sample_points_opt`] for sampling an exact number of points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline = pline_open![(0.0, 0.0, 0.0), (9.9995, 0.0, 0.0)];
+    /// assert_eq!(polyline.sample_at_spacing(2.0).len(), 5);
+    /// // path length is a multiple of the spacing within the epsilon so the end is sampled
+    /// let options = PlineSampleOptions {
+    ///     pos_equal_eps: 1e-3,
+    /// };
+    /// let samples = polyline.sample_at_spacing_opt(2.0, &options);
+    /// assert_eq!(samples.len(), 6);
+    /// assert!(samples[5].point.fuzzy_eq(Vector2::new(9.9995, 0.0)));
+    /// ```
+    fn sample_at_spacing_opt(
+        &self,
+        spacing: Self::Num,
+        options: &PlineSampleOptions<Self::Num>,
+    ) -> Vec<PathSample<Self::Num>> {
+        pline_sample::sample_at_spacing(self, spacing, options)
+    }
+
+    /// Resample the polyline into segments of equal path length no longer than
+    /// `max_segment_length` using default options.
+    ///
+    /// See [`PlineSource::resample_opt`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    /// let resampled = polyline.resample(3.0);
+    /// assert_eq!(resampled.vertex_count(), 5);
+    /// assert!(resampled[1].x.fuzzy_eq(2.5));
+    /// ```
+    fn resample(&self, max_segment_length: Self::Num) -> Self::OutputPolyline {
+        self.resample_opt(max_segment_length, &PlineResampleOptions::default())
+    }
+
+    /// Resample the polyline into segments of equal path length no longer than
+    /// `max_segment_length`.
+    ///
+    /// The path is divided into the fewest equal path length parts that are no longer than
+    /// `max_segment_length` (at least 2 for a closed polyline) and a vertex is placed at the end of
+    /// each part. Arc segments stay arcs when split. If
+    /// [`PlineResampleOptions::keep_vertexes`] is false only the new vertexes are kept and parts
+    /// spanning original vertexes become line segments (or arcs through the part path midpoint if
+    /// the part is all arc segments), otherwise original vertexes are kept and the path is
+    /// unchanged. The polyline is returned unchanged if `max_segment_length` is not
+    /// positive or the path length is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_closed;
+    /// // 10 x 10 square resampled into parts of length 15 crossing the corners
+    /// let square = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let resampled = square.resample(15.0);
+    /// assert_eq!(resampled.vertex_count(), 3);
+    ///
+    /// let options = PlineResampleOptions {
+    ///     keep_vertexes: true,
+    ///     ..Default::default()
+    /// };
+    /// let split = square.resample_opt(15.0, &options);
+    /// assert_eq!(split.vertex_count(), 6);
+    /// assert!(split.area().fuzzy_eq(100.0));
+    /// ```
+    fn resample_opt(
+        &self,
+        max_segment_length: Self::Num,
+        options: &PlineResampleOptions<Self::Num>,
+    ) -> Self::OutputPolyline {
+        pline_sample::resample(self, max_segment_length, options)
+    }
 }
 
 /// Trait representing a mutable source of polyline data. This trait has all the methods and
@@ -2100,6 +3612,84 @@ pub trait PlineSourceMut: PlineSource {
         }
     }
 
+    /// Rotate the polyline (mutably) about an `origin` point by some `angle` in radians (counter
+    /// clockwise).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// let mut polyline = Polyline::new();
+    /// polyline.add(2.0, 1.0, 0.5);
+    /// polyline.add(3.0, 1.0, 0.0);
+    /// polyline.rotate_mut(Vector2::new(1.0, 1.0), std::f64::consts::FRAC_PI_2);
+    /// let mut expected = Polyline::new();
+    /// expected.add(1.0, 2.0, 0.5);
+    /// expected.add(1.0, 3.0, 0.0);
+    /// assert!(polyline.fuzzy_eq(&expected));
+    /// ```
+    fn rotate_mut(&mut self, origin: Vector2<Self::Num>, angle: Self::Num) {
+        self.transform_mut(&Affine2::rotation_about(origin, angle));
+    }
+
+    /// Mirror the polyline (mutably) across the line passing through `point` with `direction`.
+    ///
+    /// Mirroring reverses the direction of arcs so all the bulge values are negated, a closed
+    /// polyline changes from clockwise to counter clockwise or vice versa. If `direction` is a zero
+    /// vector the polyline is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// let mut polyline = Polyline::new();
+    /// polyline.add(2.0, 1.0, 0.5);
+    /// polyline.add(3.0, 2.0, 0.0);
+    /// // mirror across the y axis
+    /// polyline.mirror_mut(Vector2::zero(), Vector2::new(0.0, 1.0));
+    /// let mut expected = Polyline::new();
+    /// expected.add(-2.0, 1.0, -0.5);
+    /// expected.add(-3.0, 2.0, 0.0);
+    /// assert!(polyline.fuzzy_eq(&expected));
+    /// ```
+    fn mirror_mut(&mut self, point: Vector2<Self::Num>, direction: Vector2<Self::Num>) {
+        self.transform_mut(&Affine2::mirror(point, direction));
+    }
+
+    /// Apply an affine `transform` to the polyline (mutably).
+    ///
+    /// Vertex positions are transformed and bulge values are negated if the transform mirrors
+    /// (negative [`Affine2::determinant`]). This is exact for transforms that map circles to
+    /// circles (uniform scale, rotation, mirroring, and translation), for other transforms arc
+    /// segments keep their bulge values (see [`PlineSource::transform_opt`] to approximate the
+    /// resulting ellipse arcs).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Affine2;
+    /// let mut polyline = Polyline::new();
+    /// polyline.add(2.0, 1.0, 0.5);
+    /// polyline.add(3.0, 2.0, 0.0);
+    /// polyline.transform_mut(&Affine2::scaling(-2.0, 2.0).then(&Affine2::translation(1.0, 0.0)));
+    /// let mut expected = Polyline::new();
+    /// expected.add(-3.0, 2.0, -0.5);
+    /// expected.add(-5.0, 4.0, 0.0);
+    /// assert!(polyline.fuzzy_eq(&expected));
+    /// ```
+    fn transform_mut(&mut self, transform: &Affine2<Self::Num>) {
+        let mirrors = transform.determinant() < Self::Num::zero();
+        for i in 0..self.vertex_count() {
+            let v = self.at(i);
+            let pos = transform.transform_point(v.pos());
+            let bulge = if mirrors { -v.bulge } else { v.bulge };
+            self.set(i, pos.x, pos.y, bulge);
+        }
+    }
+
     /// Invert/reverse the direction of the polyline in place (mutably).
     ///
     /// This method works by simply reversing the order of the vertexes, shifting by 1 position all
@@ -2441,21 +4031,13 @@ impl Iterator for PlineSegIndexIterator {
     }
 }
 
-/// Helper function to unwrap a spatial index from a builder or panic for the unexpected case of
-/// failure.
-fn unwrap_spatial_index<T>(builder: StaticAABB2DIndexBuilder<T>) -> StaticAABB2DIndex<T>
+/// Helper function to unwrap a built spatial index or panic for the unexpected case of failure.
+fn unwrap_spatial_index<T>(result: Result<StaticAABB2DIndex<T>, PlineError>) -> StaticAABB2DIndex<T>
 where
     T: IndexableNum,
 {
-    match builder.build() {
+    match result {
         Ok(x) => x,
-        Err(e) => match e {
-            StaticAABB2DIndexBuildError::ItemCountError { .. } => {
-                unreachable!("internal library error: count mismatch when building spatial index")
-            }
-            StaticAABB2DIndexBuildError::NumericCastError => {
-                panic!("failed to cast Self::Num type: {e}")
-            }
-        },
+        Err(e) => panic!("{e}"),
     }
 }
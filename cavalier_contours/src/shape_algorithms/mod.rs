@@ -1,3 +1,10 @@
+mod shape_boolean;
+mod shape_hatch;
+mod shape_medial_axis;
+mod shape_pocket;
+
+pub use shape_pocket::{InwardOffsets, PocketLoop, PocketTree};
+
 use std::collections::{BTreeMap, BTreeSet};
 
 use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
@@ -8,9 +15,11 @@ use crate::{
         traits::Real,
     },
     polyline::{
-        FindIntersectsOptions, PlineBasicIntersect, PlineOffsetOptions, PlineOrientation,
-        PlineSource, PlineSourceMut, PlineViewData, Polyline,
-        internal::pline_offset::point_valid_for_offset, seg_midpoint,
+        FindIntersectsOptions, OffsetCapType, OffsetJoinType, PlineBasicIntersect, PlineError,
+        PlineOffsetOptions, PlineOrientation, PlineSource, PlineSourceMut, PlineViewData, Polyline,
+        internal::pline_offset::{OffsetShape, point_valid_for_offset},
+        pline_error::{build_spatial_index, check_finite_param, check_pline_input},
+        seg_midpoint,
     },
 };
 
@@ -32,6 +41,10 @@ pub struct OffsetLoop<T: Real> {
     pub indexed_pline: IndexedPolyline<T>,
 }
 
+/// Counter clockwise offset loops, clockwise offset loops, and the spatial index of all their
+/// bounds (see [`Shape::create_offset_loops_with_index`]).
+type OffsetLoopsWithIndex<T> = (Vec<OffsetLoop<T>>, Vec<OffsetLoop<T>>, StaticAABB2DIndex<T>);
+
 impl<T> Default for OffsetLoop<T>
 where
     T: Real,
@@ -72,6 +85,15 @@ where
         }
     }
 
+    /// Creates an indexed polyline, returning an error if its spatial index fails to build.
+    pub(crate) fn try_new(polyline: Polyline<T>) -> Result<Self, PlineError> {
+        let spatial_index = polyline.try_create_approx_aabb_index()?;
+        Ok(Self {
+            polyline,
+            spatial_index,
+        })
+    }
+
     /// Computes parallel offsets for use in a shape offset operation.
     pub fn parallel_offset_for_shape(
         &self,
@@ -83,6 +105,7 @@ where
             handle_self_intersects: false,
             pos_equal_eps: options.pos_equal_eps,
             offset_dist_eps: options.offset_dist_eps,
+            join_type: options.join_type,
             ..Default::default()
         };
 
@@ -116,6 +139,8 @@ pub struct ShapeOffsetOptions<T> {
     /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
     /// polyline slices together.
     pub slice_join_eps: T,
+    /// Controls how offset segments are connected at outer corners.
+    pub join_type: OffsetJoinType<T>,
 }
 
 impl<T> ShapeOffsetOptions<T>
@@ -129,6 +154,7 @@ where
             pos_equal_eps: T::from(1e-5).unwrap(),
             offset_dist_eps: T::from(1e-4).unwrap(),
             slice_join_eps: T::from(1e-4).unwrap(),
+            join_type: OffsetJoinType::Round,
         }
     }
 }
@@ -143,22 +169,118 @@ where
     }
 }
 
+/// Struct to hold options parameters when performing shape boolean operations.
+#[derive(Debug, Clone)]
+pub struct ShapeBooleanOptions<T> {
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// If Some then this epsilon value is used to determine if a result polyline is collapsed, that
+    /// is has no area according to abs(area) < eps. Polylines that are collapsed will not be
+    /// included in the result.
+    pub collapsed_area_eps: Option<T>,
+}
+
+impl<T> ShapeBooleanOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            collapsed_area_eps: None,
+        }
+    }
+}
+
+impl<T> Default for ShapeBooleanOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct to hold options parameters when hatching a shape.
+#[derive(Debug, Clone)]
+pub struct ShapeHatchOptions<T> {
+    /// Angle (in radians, counter clockwise from the x axis) of the hatch lines.
+    pub angle: T,
+    /// Distance of the hatch lines from the origin (perpendicular to the lines), hatch lines are at
+    /// `offset + k * spacing` for every integer `k` so adjacent shapes hatched with the same
+    /// options have aligned lines.
+    pub offset: T,
+    /// If true then a second set of hatch lines perpendicular to the first is added.
+    pub crosshatch: bool,
+    /// If true then the end of a hatch line is connected to the start of a hatch line on the next
+    /// line over by following the shape boundary between them (where no other hatch line touches
+    /// the boundary), forming zigzag polylines.
+    pub zigzag: bool,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> ShapeHatchOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            angle: T::zero(),
+            offset: T::zero(),
+            crosshatch: false,
+            zigzag: false,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for ShapeHatchOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Shape<T>
 where
     T: Real,
 {
     pub fn from_plines<I>(plines: I) -> Self
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        match Self::build_from_plines(plines) {
+            Ok(shape) => shape,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Create a shape from polylines (see [`Shape::from_plines`]), returning an error if a spatial
+    /// index fails to build.
+    fn build_from_plines<I>(plines: I) -> Result<Self, PlineError>
     where
         I: IntoIterator<Item = Polyline<T>>,
     {
         let mut ccw_plines = Vec::new();
         let mut cw_plines = Vec::new();
-        // skip empty polylines
+        // skip empty polylines, split arcs larger than a half circle (using the default position
+        // epsilon of the shape options)
+        let pos_equal_eps = T::from(1e-5).unwrap();
         for pl in plines.into_iter().filter(|p| p.vertex_count() > 1) {
+            let pl = pl.split_large_arcs(pos_equal_eps).unwrap_or(pl);
             if pl.orientation() == PlineOrientation::CounterClockwise {
-                ccw_plines.push(IndexedPolyline::new(pl));
+                ccw_plines.push(IndexedPolyline::try_new(pl)?);
             } else {
-                cw_plines.push(IndexedPolyline::new(pl));
+                cw_plines.push(IndexedPolyline::try_new(pl)?);
             }
         }
 
@@ -179,14 +301,39 @@ where
             add_all_bounds(&ccw_plines);
             add_all_bounds(&cw_plines);
 
-            b.build().unwrap()
+            build_spatial_index(b)?
         };
 
-        Self {
+        Ok(Self {
             ccw_plines,
             cw_plines,
             plines_index,
+        })
+    }
+
+    /// Create a shape from polylines, returning an error for invalid input instead of panicking.
+    ///
+    /// Same as [`Shape::from_plines`] except the polylines are validated first and spatial index
+    /// build failures are returned as errors. In errors `input` is the index of the polyline in
+    /// `plines`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlineError::NotClosed`] if a polyline with 2 or more vertexes is open,
+    /// [`PlineError::NonFiniteCoordinate`] or [`PlineError::NonFiniteBulge`] if a vertex has a NaN
+    /// or infinite value, and [`PlineError::SpatialIndex`] if a spatial index fails to build.
+    pub fn try_from_plines<I>(plines: I) -> Result<Self, PlineError>
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        let plines = plines.into_iter().collect::<Vec<_>>();
+        for (i, pl) in plines.iter().enumerate() {
+            // empty polylines are skipped by from_plines
+            if pl.vertex_count() > 1 {
+                check_pline_input(pl, i, true)?;
+            }
         }
+        Self::build_from_plines(plines)
     }
 
     /// Return an empty shape (0 polylines).
@@ -202,11 +349,20 @@ where
 
     #[must_use]
     pub fn parallel_offset(&self, offset: T, options: &ShapeOffsetOptions<T>) -> Self {
+        match self.offset_shape(offset, options) {
+            Ok(shape) => shape,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Parallel offset the shape (see [`Shape::parallel_offset`]), returning an error if a spatial
+    /// index fails to build.
+    fn offset_shape(&self, offset: T, options: &ShapeOffsetOptions<T>) -> Result<Self, PlineError> {
         let (ccw_offset_loops, cw_offset_loops, offset_loops_index) =
-            self.create_offset_loops_with_index(offset, options);
+            self.try_create_offset_loops_with_index(offset, options)?;
 
         if ccw_offset_loops.is_empty() && cw_offset_loops.is_empty() {
-            return Self::empty();
+            return Ok(Self::empty());
         }
 
         let slice_point_sets = self.find_intersects_between_offset_loops(
@@ -224,7 +380,7 @@ where
             options,
         );
 
-        self.stitch_slices_together(
+        Self::try_stitch_slices_together(
             &slices_data,
             &ccw_offset_loops,
             &cw_offset_loops,
@@ -233,6 +389,52 @@ where
         )
     }
 
+    /// Parallel offset the shape, returning an error for invalid input instead of panicking.
+    ///
+    /// Same as [`Shape::parallel_offset`] except the shape loops are validated first. In errors
+    /// `input` is the index of the loop in `ccw_plines` followed by `cw_plines`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlineError::NonFiniteParameter`] if `offset` is NaN or infinite,
+    /// [`PlineError::TooFewVertexes`] or [`PlineError::NotClosed`] if a loop is not a closed
+    /// polyline, [`PlineError::NonFiniteCoordinate`] or [`PlineError::NonFiniteBulge`] if a vertex
+    /// has a NaN or infinite value, and [`PlineError::SpatialIndex`] if a spatial index fails to
+    /// build.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::shape_algorithms::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let shape = Shape::try_from_plines([circle]).unwrap();
+    /// let options = ShapeOffsetOptions::default();
+    /// assert_eq!(shape.try_parallel_offset(0.5, &options).unwrap().ccw_plines.len(), 1);
+    /// assert_eq!(
+    ///     shape.try_parallel_offset(f64::NAN, &options).unwrap_err(),
+    ///     PlineError::NonFiniteParameter { name: "offset" }
+    /// );
+    /// ```
+    pub fn try_parallel_offset(
+        &self,
+        offset: T,
+        options: &ShapeOffsetOptions<T>,
+    ) -> Result<Self, PlineError> {
+        check_finite_param(offset, "offset")?;
+        self.check_loops(0)?;
+        self.offset_shape(offset, options)
+    }
+
+    /// Check all the loops of the shape are valid closed polylines, loops are numbered starting at
+    /// `first_input` for errors.
+    pub(crate) fn check_loops(&self, first_input: usize) -> Result<(), PlineError> {
+        for (i, pl) in self.ccw_plines.iter().chain(&self.cw_plines).enumerate() {
+            check_pline_input(&pl.polyline, first_input + i, true)?;
+        }
+        Ok(())
+    }
+
     /// **Step 1** of the multipolyline offset algorithm: Creates offset loops with spatial index.
     ///
     /// This method generates offset polylines for each input polyline in the shape and creates
@@ -257,6 +459,19 @@ where
         offset: T,
         options: &ShapeOffsetOptions<T>,
     ) -> (Vec<OffsetLoop<T>>, Vec<OffsetLoop<T>>, StaticAABB2DIndex<T>) {
+        match self.try_create_offset_loops_with_index(offset, options) {
+            Ok(result) => result,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Same as [`Shape::create_offset_loops_with_index`] except a spatial index build failure is
+    /// returned as an error.
+    fn try_create_offset_loops_with_index(
+        &self,
+        offset: T,
+        options: &ShapeOffsetOptions<T>,
+    ) -> Result<OffsetLoopsWithIndex<T>, PlineError> {
         let mut ccw_offset_loops = Vec::new();
         let mut cw_offset_loops = Vec::new();
         let mut parent_idx = 0;
@@ -272,7 +487,7 @@ where
 
                 let offset_loop = OffsetLoop {
                     parent_loop_idx: parent_idx,
-                    indexed_pline: IndexedPolyline::new(offset_pline),
+                    indexed_pline: IndexedPolyline::try_new(offset_pline)?,
                 };
 
                 if area < T::zero() {
@@ -295,7 +510,7 @@ where
 
                 let offset_loop = OffsetLoop {
                     parent_loop_idx: parent_idx,
-                    indexed_pline: IndexedPolyline::new(offset_pline),
+                    indexed_pline: IndexedPolyline::try_new(offset_pline)?,
                 };
 
                 if area < T::zero() {
@@ -326,11 +541,10 @@ where
             add_all_bounds(&ccw_offset_loops);
             add_all_bounds(&cw_offset_loops);
 
-            b.build()
-                .expect("failed to build spatial index of offset loop bounds")
+            build_spatial_index(b)?
         };
 
-        (ccw_offset_loops, cw_offset_loops, offset_loops_index)
+        Ok((ccw_offset_loops, cw_offset_loops, offset_loops_index))
     }
 
     /// **Step 2** of the multipolyline offset algorithm: Finds intersections between offset loops.
@@ -503,6 +717,12 @@ where
         let offset_loop_count = ccw_offset_loops.len() + cw_offset_loops.len();
         let pos_equal_eps = options.pos_equal_eps;
         let offset_dist_eps = options.offset_dist_eps;
+        // shape loops are closed so caps do not apply
+        let shape = OffsetShape {
+            offset,
+            join_type: options.join_type,
+            cap_type: OffsetCapType::Round,
+        };
 
         let mut slice_points_lookup = BTreeMap::<usize, Vec<usize>>::new();
         for (set_idx, set) in slice_point_sets.iter().enumerate() {
@@ -582,7 +802,7 @@ where
 
                 if !point_valid_for_offset(
                     &parent_loop.polyline,
-                    offset,
+                    shape,
                     &parent_loop.spatial_index,
                     midpoint1,
                     query_stack,
@@ -595,7 +815,7 @@ where
                 if let Some(midpoint2) = midpoint2
                     && !point_valid_for_offset(
                         &parent_loop.polyline,
-                        offset,
+                        shape,
                         &parent_loop.spatial_index,
                         midpoint2,
                         query_stack,
@@ -755,8 +975,29 @@ where
         pos_equal_eps: T,
         slice_join_eps: T,
     ) -> Self {
+        match Self::try_stitch_slices_together(
+            slices_data,
+            ccw_offset_loops,
+            cw_offset_loops,
+            pos_equal_eps,
+            slice_join_eps,
+        ) {
+            Ok(shape) => shape,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Same as [`Shape::stitch_slices_together`] except a spatial index build failure is returned
+    /// as an error.
+    fn try_stitch_slices_together(
+        slices_data: &[DissectedSlice<T>],
+        ccw_offset_loops: &[OffsetLoop<T>],
+        cw_offset_loops: &[OffsetLoop<T>],
+        pos_equal_eps: T,
+        slice_join_eps: T,
+    ) -> Result<Self, PlineError> {
         if slices_data.is_empty() {
-            return Self::empty();
+            return Ok(Self::empty());
         }
 
         let mut ccw_plines_result = Vec::new();
@@ -773,7 +1014,7 @@ where
                     start_point.y + slice_join_eps,
                 );
             }
-            builder.build().unwrap()
+            build_spatial_index(builder)?
         };
 
         let mut visited_slices_idxs = vec![false; slices_data.len()];
@@ -831,9 +1072,9 @@ where
                     }
                     let is_ccw = current_pline.orientation() == PlineOrientation::CounterClockwise;
                     if is_ccw {
-                        ccw_plines_result.push(IndexedPolyline::new(current_pline));
+                        ccw_plines_result.push(IndexedPolyline::try_new(current_pline)?);
                     } else {
-                        cw_plines_result.push(IndexedPolyline::new(current_pline));
+                        cw_plines_result.push(IndexedPolyline::try_new(current_pline)?);
                     }
                     break;
                 }
@@ -872,14 +1113,14 @@ where
             add_all_bounds(&ccw_plines_result);
             add_all_bounds(&cw_plines_result);
 
-            b.build().unwrap()
+            build_spatial_index(b)?
         };
 
-        Shape {
+        Ok(Shape {
             ccw_plines: ccw_plines_result,
             cw_plines: cw_plines_result,
             plines_index,
-        }
+        })
     }
 
     fn get_loop<'a>(
@@ -0,0 +1,442 @@
+//! Boolean operations between shapes.
+//!
+//! Every loop of one shape is intersected with the loops of the other shape (found using the
+//! `plines_index` of the other shape). Each loop is then sliced at all of its intersects and the
+//! slices are kept or discarded based on whether they lie inside the other shape. The remaining
+//! slices are stitched together into closed polylines, keeping the convention that filled area is
+//! to the left of the polyline direction so outer loops are counter clockwise and holes are
+//! clockwise.
+
+use static_aabb2d_index::Control;
+
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{
+        BooleanOp, BooleanPlineSlice, PlineError, PlineSource, PlineViewData, Polyline,
+        internal::pline_boolean::{
+            ProcessForBooleanResult, process_for_boolean, slice_at_intersects, stitch_slices,
+        },
+        seg_midpoint,
+    },
+};
+
+use super::{IndexedPolyline, Shape, ShapeBooleanOptions};
+
+/// Slice of a shape loop kept for the boolean result.
+#[derive(Debug, Clone, Copy)]
+struct ShapeSlice<'a, T>
+where
+    T: Real,
+{
+    /// Loop the slice view data is defined on.
+    source: &'a Polyline<T>,
+    /// Whether the slice came from the first shape of the boolean operation.
+    from_first: bool,
+    v_data: PlineViewData<T>,
+}
+
+/// Which slices of a loop are kept relative to the other shape.
+#[derive(Debug, Clone, Copy)]
+struct KeepRule {
+    /// Keep slices outside of the other shape (in their original direction).
+    outside: bool,
+    /// Keep slices inside of the other shape, `Some(true)` if they are kept with inverted
+    /// direction.
+    inside: Option<bool>,
+}
+
+impl KeepRule {
+    /// Rules for the slices of the first and second shape for `operation` (not valid for
+    /// [`BooleanOp::Xor`]).
+    fn for_operation(operation: BooleanOp) -> (Self, Self) {
+        match operation {
+            BooleanOp::Or => (
+                Self {
+                    outside: true,
+                    inside: None,
+                },
+                Self {
+                    outside: true,
+                    inside: None,
+                },
+            ),
+            BooleanOp::And => (
+                Self {
+                    outside: false,
+                    inside: Some(false),
+                },
+                Self {
+                    outside: false,
+                    inside: Some(false),
+                },
+            ),
+            BooleanOp::Not => (
+                Self {
+                    outside: true,
+                    inside: None,
+                },
+                Self {
+                    outside: false,
+                    inside: Some(true),
+                },
+            ),
+            BooleanOp::Xor => unreachable!("xor is combined from two exclusions"),
+        }
+    }
+}
+
+impl<T> Shape<T>
+where
+    T: Real,
+{
+    /// Perform a boolean `operation` between this shape and `other` using default options.
+    ///
+    /// See [`Shape::boolean_opt`] for more information.
+    #[must_use]
+    pub fn boolean(&self, other: &Self, operation: BooleanOp) -> Self {
+        self.boolean_opt(other, operation, &ShapeBooleanOptions::default())
+    }
+
+    /// Perform a boolean `operation` between this shape and `other` with options given.
+    ///
+    /// Both shapes must be made of closed loops that do not intersect other loops in the same
+    /// shape, with counter clockwise loops for filled area and clockwise loops for holes. The
+    /// result follows the same convention. [`BooleanOp::Not`] subtracts `other` from this shape.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::shape_algorithms::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square = |min: f64, max: f64| {
+    ///     pline_closed![(min, min, 0.0), (max, min, 0.0), (max, max, 0.0), (min, max, 0.0)]
+    /// };
+    /// // 10 x 10 square with a 4 x 4 hole in the middle
+    /// let mut hole = square(3.0, 7.0);
+    /// hole.invert_direction_mut();
+    /// let part = Shape::from_plines([square(0.0, 10.0), hole]);
+    /// // fill the hole with a 6 x 6 square
+    /// let result = part.boolean(&Shape::from_plines([square(2.0, 8.0)]), BooleanOp::Or);
+    /// assert_eq!(result.ccw_plines.len(), 1);
+    /// assert!(result.cw_plines.is_empty());
+    /// ```
+    #[must_use]
+    pub fn boolean_opt(
+        &self,
+        other: &Self,
+        operation: BooleanOp,
+        options: &ShapeBooleanOptions<T>,
+    ) -> Self {
+        if operation == BooleanOp::Xor {
+            // combine both exclusions, they only touch at intersect points
+            let first_minus_second = self.boolean_opt(other, BooleanOp::Not, options);
+            let second_minus_first = other.boolean_opt(self, BooleanOp::Not, options);
+            return Shape::from_plines(
+                [first_minus_second, second_minus_first]
+                    .into_iter()
+                    .flat_map(|s| s.ccw_plines.into_iter().chain(s.cw_plines))
+                    .map(|p| p.polyline),
+            );
+        }
+
+        let pos_equal_eps = options.pos_equal_eps;
+        let first_count = self.ccw_plines.len() + self.cw_plines.len();
+        let second_count = other.ccw_plines.len() + other.cw_plines.len();
+
+        let empty_info = |pline: &Polyline<T>| ProcessForBooleanResult {
+            overlapping_slices: Vec::new(),
+            intersects: Vec::new(),
+            pline1_orientation: pline.orientation(),
+            pline2_orientation: pline.orientation(),
+        };
+        // all intersects found for each loop, merged across every loop of the other shape
+        let mut first_infos = (0..first_count)
+            .map(|i| empty_info(&self.loop_at(i).polyline))
+            .collect::<Vec<_>>();
+        let mut second_infos = (0..second_count)
+            .map(|j| empty_info(&other.loop_at(j).polyline))
+            .collect::<Vec<_>>();
+        let mut first_completely_overlapping = vec![false; first_count];
+        let mut second_completely_overlapping = vec![false; second_count];
+
+        let (first_rule, second_rule) = KeepRule::for_operation(operation);
+        let mut slices = Vec::new();
+        let mut query_stack = Vec::new();
+
+        for i in 0..first_count {
+            let loop1 = self.loop_at(i);
+            let Some(bounds) = loop1.spatial_index.bounds() else {
+                continue;
+            };
+            let query_results = other.plines_index.query_with_stack(
+                bounds.min_x,
+                bounds.min_y,
+                bounds.max_x,
+                bounds.max_y,
+                &mut query_stack,
+            );
+
+            for j in query_results {
+                let loop2 = other.loop_at(j);
+                let info = process_for_boolean(
+                    &loop1.polyline,
+                    &loop2.polyline,
+                    &loop1.spatial_index,
+                    pos_equal_eps,
+                );
+
+                if !info.any_intersects() {
+                    continue;
+                }
+
+                // coincident boundary is kept if it still bounds the result area: same direction
+                // means both areas are on the same side (kept for union and intersection),
+                // opposing direction means the areas are on opposite sides (kept for exclusion)
+                for overlap in &info.overlapping_slices {
+                    let copies: &[bool] = match (operation, overlap.opposing_directions) {
+                        (BooleanOp::Or | BooleanOp::And, false) => &[false],
+                        (BooleanOp::Not, true) => &[true],
+                        _ => &[],
+                    };
+                    for &inverted in copies {
+                        slices.push(ShapeSlice {
+                            source: &loop2.polyline,
+                            // inverted copy follows the direction of the first shape loop
+                            from_first: inverted,
+                            v_data: BooleanPlineSlice::from_overlapping(
+                                &loop2.polyline,
+                                overlap,
+                                inverted,
+                            )
+                            .view_data,
+                        });
+                    }
+                }
+
+                if info.completely_overlapping() {
+                    first_completely_overlapping[i] = true;
+                    second_completely_overlapping[j] = true;
+                    continue;
+                }
+
+                first_infos[i]
+                    .intersects
+                    .extend_from_slice(&info.intersects);
+                first_infos[i]
+                    .overlapping_slices
+                    .extend_from_slice(&info.overlapping_slices);
+                second_infos[j]
+                    .intersects
+                    .extend_from_slice(&info.intersects);
+                second_infos[j]
+                    .overlapping_slices
+                    .extend_from_slice(&info.overlapping_slices);
+            }
+        }
+
+        for (i, info) in first_infos.iter().enumerate() {
+            if !first_completely_overlapping[i] {
+                add_loop_slices(
+                    self.loop_at(i),
+                    info,
+                    false,
+                    first_rule,
+                    other,
+                    pos_equal_eps,
+                    &mut slices,
+                );
+            }
+        }
+        for (j, info) in second_infos.iter().enumerate() {
+            if !second_completely_overlapping[j] {
+                add_loop_slices(
+                    other.loop_at(j),
+                    info,
+                    true,
+                    second_rule,
+                    self,
+                    pos_equal_eps,
+                    &mut slices,
+                );
+            }
+        }
+
+        let result = stitch_shape_slices(&slices, pos_equal_eps, options.collapsed_area_eps);
+        Shape::from_plines(result)
+    }
+
+    /// Perform a boolean `operation` between this shape and `other` using default options,
+    /// returning an error for invalid input instead of panicking.
+    ///
+    /// See [`Shape::try_boolean_opt`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// See [`Shape::try_boolean_opt`].
+    pub fn try_boolean(&self, other: &Self, operation: BooleanOp) -> Result<Self, PlineError> {
+        self.try_boolean_opt(other, operation, &ShapeBooleanOptions::default())
+    }
+
+    /// Perform a boolean `operation` between this shape and `other` with options given, returning
+    /// an error for invalid input instead of panicking.
+    ///
+    /// Same as [`Shape::boolean_opt`] except the shape loops are validated first. In errors `input`
+    /// is the index of the loop in `ccw_plines` followed by `cw_plines` of this shape, continuing
+    /// with the loops of `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlineError::TooFewVertexes`] or [`PlineError::NotClosed`] if a loop is not a
+    /// closed polyline, and [`PlineError::NonFiniteCoordinate`] or [`PlineError::NonFiniteBulge`]
+    /// if a vertex has a NaN or infinite value.
+    pub fn try_boolean_opt(
+        &self,
+        other: &Self,
+        operation: BooleanOp,
+        options: &ShapeBooleanOptions<T>,
+    ) -> Result<Self, PlineError> {
+        self.check_loops(0)?;
+        other.check_loops(self.ccw_plines.len() + self.cw_plines.len())?;
+        Ok(self.boolean_opt(other, operation, options))
+    }
+
+    /// Returns the loop at `index` where indexes follow the `plines_index` ordering (all counter
+    /// clockwise loops followed by all clockwise loops).
+    pub(super) fn loop_at(&self, index: usize) -> &IndexedPolyline<T> {
+        if index < self.ccw_plines.len() {
+            &self.ccw_plines[index]
+        } else {
+            &self.cw_plines[index - self.ccw_plines.len()]
+        }
+    }
+
+    /// Sum of the winding numbers of all loops in the shape around `point`, non-zero if the point
+    /// is inside the shape area.
+    pub(super) fn winding_number(&self, point: Vector2<T>) -> i32 {
+        let mut winding = 0;
+        let mut visitor = |i: usize| {
+            winding += self.loop_at(i).polyline.winding_number(point);
+            Control::<()>::Continue
+        };
+        self.plines_index
+            .visit_query(point.x, point.y, point.x, point.y, &mut visitor);
+        winding
+    }
+}
+
+/// Slices a loop at its intersects with the other shape and adds the slices kept by `rule` to
+/// `slices`. A loop without intersects is kept or discarded as a whole.
+fn add_loop_slices<'a, T>(
+    indexed_pline: &'a IndexedPolyline<T>,
+    info: &ProcessForBooleanResult<T>,
+    use_second_index: bool,
+    rule: KeepRule,
+    other_shape: &Shape<T>,
+    pos_equal_eps: T,
+    slices: &mut Vec<ShapeSlice<'a, T>>,
+) where
+    T: Real,
+{
+    let pline = &indexed_pline.polyline;
+    let mut point_inside = |pt: Vector2<T>| other_shape.winding_number(pt) != 0;
+    let from_first = !use_second_index;
+
+    if !info.any_intersects() {
+        // no intersects, whole loop is either inside or outside the other shape
+        let inside = point_inside(seg_midpoint(pline.at(0), pline.at(1)));
+        let inverted = if inside {
+            rule.inside
+        } else {
+            rule.outside.then_some(false)
+        };
+        if let Some(inverted) = inverted {
+            let mut v_data = PlineViewData::from_entire_pline(pline);
+            v_data.inverted_direction = inverted;
+            slices.push(ShapeSlice {
+                source: pline,
+                from_first,
+                v_data,
+            });
+        }
+        return;
+    }
+
+    let mut loop_slices = Vec::new();
+    if rule.outside {
+        slice_at_intersects(
+            pline,
+            info,
+            use_second_index,
+            &mut |pt: Vector2<T>| !point_inside(pt),
+            &mut loop_slices,
+            pos_equal_eps,
+        );
+    }
+    let outside_count = loop_slices.len();
+    if rule.inside.is_some() {
+        slice_at_intersects(
+            pline,
+            info,
+            use_second_index,
+            &mut point_inside,
+            &mut loop_slices,
+            pos_equal_eps,
+        );
+    }
+    let invert_inside = rule.inside.unwrap_or(false);
+    for (k, slice) in loop_slices.into_iter().enumerate() {
+        let mut v_data = slice.view_data;
+        v_data.inverted_direction = k >= outside_count && invert_inside;
+        slices.push(ShapeSlice {
+            source: pline,
+            from_first,
+            v_data,
+        });
+    }
+}
+
+/// Stitches slices into closed polylines, slice directions must agree (every start point connects
+/// with an end point). When multiple slices connect at a point a slice from the other shape is
+/// preferred so crossing boundaries switch between the shapes. Result polylines carry the user data
+/// values of every loop that contributed a slice.
+fn stitch_shape_slices<T>(
+    slices: &[ShapeSlice<'_, T>],
+    pos_equal_eps: T,
+    collapsed_area_eps: Option<T>,
+) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    let select = |_: &Polyline<T>, current_idx: usize, candidates: &[usize]| {
+        let from_first = slices[current_idx].from_first;
+        candidates
+            .iter()
+            .copied()
+            .find(|&j| slices[j].from_first != from_first)
+            .or_else(|| candidates.first().copied())
+    };
+
+    stitch_slices(
+        slices.len(),
+        |i| slices[i].v_data.view(slices[i].source),
+        select,
+        pos_equal_eps,
+        collapsed_area_eps,
+        false,
+    )
+    .into_iter()
+    .map(|(mut pline, slice_indexes): (Polyline<T>, _)| {
+        let mut userdata = Vec::new();
+        for value in slice_indexes
+            .iter()
+            .flat_map(|&i| slices[i].source.get_userdata_values())
+        {
+            if !userdata.contains(&value) {
+                userdata.push(value);
+            }
+        }
+        pline.set_userdata_values(userdata);
+        pline
+    })
+    .collect()
+}
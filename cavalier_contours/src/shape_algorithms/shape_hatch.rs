@@ -0,0 +1,345 @@
+//! Hatching (scanline fill) of a shape.
+//!
+//! Each hatch line spans the shape extents and is intersected with the boundary segments found
+//! through the shape and loop spatial indexes using the segment intersect routine, so arc segments
+//! are clipped exactly. The intersects are sorted along the line and each span between consecutive
+//! intersects is inside or outside the shape as a whole, it is tested at its midpoint by winding
+//! number (robust to the line passing through vertexes or touching the boundary).
+
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{
+        PlineCreation, PlineSegIntr, PlineSource, PlineSourceMut, PlineVertex, PlineViewData,
+        Polyline, dist_from_segment_start, pline_seg_intr,
+    },
+};
+
+use super::{Shape, ShapeHatchOptions};
+
+/// Point where a hatch line meets the shape boundary.
+#[derive(Debug, Clone, Copy)]
+struct BoundaryPoint<T> {
+    /// Distance along the hatch line.
+    dist: T,
+    pos: Vector2<T>,
+    /// Index of the loop (following the `plines_index` ordering).
+    loop_index: usize,
+    /// Index of the loop segment.
+    seg_index: usize,
+}
+
+/// Part of a hatch line inside the shape.
+#[derive(Debug, Clone, Copy)]
+struct HatchSpan<T> {
+    /// Index of the hatch line.
+    line: usize,
+    start: BoundaryPoint<T>,
+    end: BoundaryPoint<T>,
+}
+
+impl<T> HatchSpan<T> {
+    /// Span end point by id (0 for start, 1 for end).
+    fn point(&self, id: usize) -> &BoundaryPoint<T> {
+        if id == 0 { &self.start } else { &self.end }
+    }
+}
+
+impl<T> Shape<T>
+where
+    T: Real,
+{
+    /// Generate parallel hatch lines `spacing` apart clipped to the filled area of this shape
+    /// (inside the counter clockwise polylines and outside the clockwise polylines).
+    ///
+    /// Returns open polylines, one per hatch line part inside the shape unless
+    /// [`ShapeHatchOptions::zigzag`] is set to connect them along the boundary. Hatch lines follow
+    /// [`ShapeHatchOptions::angle`] and are ordered along their perpendicular then along their
+    /// direction, followed by the perpendicular set of lines if [`ShapeHatchOptions::crosshatch`]
+    /// is set. Arc segments of the shape are clipped exactly. No polylines are returned if
+    /// `spacing` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::shape_algorithms::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let shape = Shape::from_plines([square]);
+    /// let options = ShapeHatchOptions {
+    ///     offset: 0.5,
+    ///     ..Default::default()
+    /// };
+    /// // horizontal lines at y = 0.5, 1.5, ..., 9.5
+    /// let lines = shape.hatch(1.0, &options);
+    /// assert_eq!(lines.len(), 10);
+    /// assert!(lines.iter().all(|l| l.path_length().fuzzy_eq(10.0)));
+    ///
+    /// // connected into a single zigzag polyline following the sides between lines
+    /// let options = ShapeHatchOptions {
+    ///     offset: 0.5,
+    ///     zigzag: true,
+    ///     ..Default::default()
+    /// };
+    /// let zigzag = shape.hatch(1.0, &options);
+    /// assert_eq!(zigzag.len(), 1);
+    /// assert!(zigzag[0].path_length().fuzzy_eq(10.0 * 10.0 + 9.0));
+    /// ```
+    #[must_use]
+    pub fn hatch(&self, spacing: T, options: &ShapeHatchOptions<T>) -> Vec<Polyline<T>> {
+        let mut result = Vec::new();
+        if spacing.partial_cmp(&T::zero()) != Some(std::cmp::Ordering::Greater) {
+            return result;
+        }
+
+        let mut angles = vec![options.angle];
+        if options.crosshatch {
+            angles.push(options.angle + T::pi() / T::two());
+        }
+
+        for angle in angles {
+            let spans = self.hatch_spans(spacing, angle, options);
+            if options.zigzag {
+                result.extend(self.connect_spans(&spans, options.pos_equal_eps));
+            } else {
+                result.extend(spans.iter().map(|span| {
+                    let mut pline = Polyline::with_capacity(2, false);
+                    pline.add(span.start.pos.x, span.start.pos.y, T::zero());
+                    pline.add(span.end.pos.x, span.end.pos.y, T::zero());
+                    pline
+                }));
+            }
+        }
+
+        result
+    }
+
+    /// Find the parts of the hatch lines at `angle` inside the shape, ordered by line then along
+    /// the line.
+    fn hatch_spans(
+        &self,
+        spacing: T,
+        angle: T,
+        options: &ShapeHatchOptions<T>,
+    ) -> Vec<HatchSpan<T>> {
+        let eps = options.pos_equal_eps;
+        let Some(bounds) = self.plines_index.bounds() else {
+            return Vec::new();
+        };
+
+        let (sin, cos) = angle.sin_cos();
+        let dir = Vector2::new(cos, sin);
+        let normal = dir.perp();
+
+        // extents of the shape along and perpendicular to the hatch lines
+        let corners = [
+            Vector2::new(bounds.min_x, bounds.min_y),
+            Vector2::new(bounds.max_x, bounds.min_y),
+            Vector2::new(bounds.max_x, bounds.max_y),
+            Vector2::new(bounds.min_x, bounds.max_y),
+        ];
+        let range = |axis: Vector2<T>| {
+            let first = axis.dot(corners[0]);
+            corners.iter().fold((first, first), |(min, max), c| {
+                let d = axis.dot(*c);
+                (
+                    num_traits::real::Real::min(min, d),
+                    num_traits::real::Real::max(max, d),
+                )
+            })
+        };
+        let (u_min, u_max) = range(dir);
+        let (v_min, v_max) = range(normal);
+        let pad = spacing + eps;
+
+        let mut spans = Vec::new();
+        let mut query_stack = Vec::new();
+        let mut points = Vec::new();
+        let mut k = ((v_min - options.offset) / spacing).ceil();
+        let last_k = ((v_max - options.offset) / spacing).floor();
+        let mut line = 0;
+        while k <= last_k {
+            let v = options.offset + k * spacing;
+            let line_start = normal.scale(v) + dir.scale(u_min - pad);
+            let line_end = normal.scale(v) + dir.scale(u_max + pad);
+            let line_v1 = PlineVertex::from_vector2(line_start, T::zero());
+            let line_v2 = PlineVertex::from_vector2(line_end, T::zero());
+            let min_x = num_traits::real::Real::min(line_start.x, line_end.x);
+            let min_y = num_traits::real::Real::min(line_start.y, line_end.y);
+            let max_x = num_traits::real::Real::max(line_start.x, line_end.x);
+            let max_y = num_traits::real::Real::max(line_start.y, line_end.y);
+
+            points.clear();
+            let loops =
+                self.plines_index
+                    .query_with_stack(min_x, min_y, max_x, max_y, &mut query_stack);
+            for loop_index in loops {
+                let indexed_pline = self.loop_at(loop_index);
+                let pline = &indexed_pline.polyline;
+                let segs = indexed_pline.spatial_index.query_with_stack(
+                    min_x,
+                    min_y,
+                    max_x,
+                    max_y,
+                    &mut query_stack,
+                );
+                for seg_index in segs {
+                    let v1 = pline.at(seg_index);
+                    let v2 = pline.at(pline.next_wrapping_index(seg_index));
+                    let mut add = |pos: Vector2<T>| {
+                        points.push(BoundaryPoint {
+                            dist: dir.dot(pos - line_start),
+                            pos,
+                            loop_index,
+                            seg_index,
+                        });
+                    };
+                    match pline_seg_intr(line_v1, line_v2, v1, v2, eps) {
+                        PlineSegIntr::TangentIntersect { point }
+                        | PlineSegIntr::OneIntersect { point } => add(point),
+                        PlineSegIntr::TwoIntersects { point1, point2 }
+                        | PlineSegIntr::OverlappingLines { point1, point2 } => {
+                            add(point1);
+                            add(point2);
+                        }
+                        PlineSegIntr::NoIntersect | PlineSegIntr::OverlappingArcs { .. } => {}
+                    }
+                }
+            }
+
+            points.sort_unstable_by(|a, b| a.dist.total_cmp(&b.dist));
+            points.dedup_by(|a, b| a.dist - b.dist < eps);
+
+            // merge consecutive inside intervals into spans
+            let mut span_start: Option<usize> = None;
+            for i in 0..points.len().saturating_sub(1) {
+                let mid = (points[i].pos + points[i + 1].pos).scale(T::one() / T::two());
+                let inside = self.winding_number(mid) != 0;
+                if inside && span_start.is_none() {
+                    span_start = Some(i);
+                }
+                let at_end = i + 2 == points.len();
+                if let Some(start) = span_start
+                    && (!inside || at_end)
+                {
+                    let end = if inside { i + 1 } else { i };
+                    if points[end].dist - points[start].dist > eps {
+                        spans.push(HatchSpan {
+                            line,
+                            start: points[start],
+                            end: points[end],
+                        });
+                    }
+                    span_start = None;
+                }
+            }
+
+            k = k + T::one();
+            line += 1;
+        }
+
+        spans
+    }
+
+    /// Connect the `spans` into zigzag polylines by following the boundary from the end of a span
+    /// to the next boundary point along the loop if it belongs to a span on the next line.
+    fn connect_spans(&self, spans: &[HatchSpan<T>], eps: T) -> Vec<Polyline<T>> {
+        // span end points (span index * 2 + end id) ordered along each loop, next and prev hold
+        // the neighboring end point along the loop
+        let point_count = 2 * spans.len();
+        let mut next = vec![usize::MAX; point_count];
+        let mut prev = vec![usize::MAX; point_count];
+        let mut by_loop = vec![Vec::new(); self.ccw_plines.len() + self.cw_plines.len()];
+        for id in 0..point_count {
+            let p = spans[id / 2].point(id % 2);
+            let pline = &self.loop_at(p.loop_index).polyline;
+            let v1 = pline.at(p.seg_index);
+            let v2 = pline.at(pline.next_wrapping_index(p.seg_index));
+            let dist = dist_from_segment_start(v1, v2, p.pos, eps);
+            by_loop[p.loop_index].push((p.seg_index, dist, id));
+        }
+        for ids in &mut by_loop {
+            ids.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+            for i in 0..ids.len() {
+                let j = (i + 1) % ids.len();
+                next[ids[i].2] = ids[j].2;
+                prev[ids[j].2] = ids[i].2;
+            }
+        }
+
+        let mut used = vec![false; spans.len()];
+        let mut result = Vec::new();
+        for first in 0..spans.len() {
+            if used[first] {
+                continue;
+            }
+            used[first] = true;
+            let mut pline = Polyline::new();
+            pline.add_vertex(PlineVertex::from_vector2(spans[first].start.pos, T::zero()));
+            pline.add_vertex(PlineVertex::from_vector2(spans[first].end.pos, T::zero()));
+            let mut exit = 2 * first + 1;
+
+            loop {
+                let line = spans[exit / 2].line;
+                let Some((entry, forward)) = [(next[exit], true), (prev[exit], false)]
+                    .into_iter()
+                    .find(|&(id, _)| !used[id / 2] && spans[id / 2].line == line + 1)
+                else {
+                    break;
+                };
+
+                let from = spans[exit / 2].point(exit % 2);
+                let to = spans[entry / 2].point(entry % 2);
+                let source = &self.loop_at(from.loop_index).polyline;
+                let connector = if forward {
+                    Self::boundary_path(source, from, to, eps)
+                } else {
+                    Self::boundary_path(source, to, from, eps).map(|mut p| {
+                        p.invert_direction_mut();
+                        p
+                    })
+                };
+                if let Some(connector) = connector {
+                    for v in connector.iter_vertexes() {
+                        pline.add_or_replace_vertex(v, eps);
+                    }
+                }
+
+                // line along the span entered
+                used[entry / 2] = true;
+                exit = entry ^ 1;
+                pline.add_or_replace_vertex(PlineVertex::from_vector2(to.pos, T::zero()), eps);
+                let exit_pos = spans[exit / 2].point(exit % 2).pos;
+                pline.add_or_replace_vertex(PlineVertex::from_vector2(exit_pos, T::zero()), eps);
+            }
+
+            result.push(pline);
+        }
+
+        result
+    }
+
+    /// Path along `source` going forward from `from` to `to`, `None` if they are at the same
+    /// position.
+    fn boundary_path(
+        source: &Polyline<T>,
+        from: &BoundaryPoint<T>,
+        to: &BoundaryPoint<T>,
+        eps: T,
+    ) -> Option<Polyline<T>> {
+        let view_data = PlineViewData::from_slice_points(
+            source,
+            from.pos,
+            from.seg_index,
+            to.pos,
+            to.seg_index,
+            eps,
+        )?;
+        Some(Polyline::create_from(&view_data.view(source)))
+    }
+}
@@ -0,0 +1,56 @@
+//! Medial axis of a shape with holes.
+
+use crate::{
+    core::traits::Real,
+    polyline::{
+        MedialAxis, PlineMedialAxisOptions, Polyline, internal::pline_medial_axis::medial_axis,
+    },
+};
+
+use super::Shape;
+
+impl<T> Shape<T>
+where
+    T: Real,
+{
+    /// Compute the approximate medial axis of the filled area of this shape (inside the counter
+    /// clockwise polylines and outside the clockwise polylines).
+    ///
+    /// The medial axis around holes forms closed loops in the graph. See
+    /// [`PlineSource::medial_axis_opt`](crate::polyline::PlineSource::medial_axis_opt) for more
+    /// information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::shape_algorithms::*;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // circle of radius 10 with a circle hole of radius 6
+    /// let outer = pline_closed![(-10.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    /// let hole = pline_closed![(6.0, 0.0, 1.0), (-6.0, 0.0, 1.0)];
+    /// let shape = Shape::from_plines([outer, hole]);
+    /// let axis = shape.medial_axis(0.1, &PlineMedialAxisOptions::default());
+    /// // single closed loop at radius 8
+    /// assert_eq!(axis.edges.len(), 1);
+    /// assert_eq!(axis.edges[0].start, axis.edges[0].end);
+    /// for p in &axis.edges[0].points {
+    ///     assert!(p.pos.length().fuzzy_eq_eps(8.0, 0.01));
+    ///     assert!(p.radius.fuzzy_eq_eps(2.0, 0.01));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn medial_axis(
+        &self,
+        sample_distance: T,
+        options: &PlineMedialAxisOptions<T>,
+    ) -> MedialAxis<T> {
+        let loops = self
+            .ccw_plines
+            .iter()
+            .chain(&self.cw_plines)
+            .map(|p| &p.polyline)
+            .collect::<Vec<&Polyline<T>>>();
+        medial_axis(&loops, sample_distance, options)
+    }
+}
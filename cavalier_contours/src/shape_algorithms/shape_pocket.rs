@@ -0,0 +1,245 @@
+//! Repeated inward offsets of a shape until nothing remains (pocketing).
+//!
+//! Each pass offsets the result of the previous pass inward by a fixed step. The loops of all the
+//! passes are collected into a tree where the parent of a loop is the adjacent loop of the previous
+//! pass: outer (counter clockwise) loops are parented by the outer loop they lie inside of and
+//! island (clockwise) loops are parented by the island loop they surround.
+
+use std::iter::FusedIterator;
+
+use crate::{
+    core::traits::Real,
+    polyline::{PlineSource, Polyline},
+};
+
+use super::{Shape, ShapeOffsetOptions};
+
+/// Iterator over successive inward offsets of a shape, created by [`Shape::inward_offsets`].
+#[derive(Debug, Clone)]
+pub struct InwardOffsets<'a, T>
+where
+    T: Real,
+{
+    shape: &'a Shape<T>,
+    step: T,
+    options: &'a ShapeOffsetOptions<T>,
+    /// Next offset to yield, computed ahead to know if the iteration ends after the current offset.
+    pending: Option<Shape<T>>,
+    done: bool,
+}
+
+impl<T> Iterator for InwardOffsets<'_, T>
+where
+    T: Real,
+{
+    type Item = Shape<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let current = self
+            .pending
+            .take()
+            .unwrap_or_else(|| self.shape.parallel_offset(self.step, self.options));
+        if current.ccw_plines.is_empty() {
+            // no filled area remains
+            self.done = true;
+            return None;
+        }
+
+        // offset the current shape before yielding it (rather than keeping a copy of it to offset
+        // on the next call)
+        let following = current.parallel_offset(self.step, self.options);
+        if following.ccw_plines.is_empty() {
+            self.done = true;
+        } else {
+            self.pending = Some(following);
+        }
+
+        Some(current)
+    }
+}
+
+impl<T> FusedIterator for InwardOffsets<'_, T> where T: Real {}
+
+/// Loop in a [`PocketTree`].
+#[derive(Debug, Clone)]
+pub struct PocketLoop<T>
+where
+    T: Real,
+{
+    /// Closed offset polyline, counter clockwise for outer loops and clockwise for island loops.
+    pub polyline: Polyline<T>,
+    /// Offset pass the loop was created in, 0 for the first inward offset.
+    pub pass: usize,
+    /// Index of the parent loop (from the previous pass) in [`PocketTree::loops`], `None` for
+    /// loops of the first pass (or if no adjacent loop was found in the previous pass).
+    pub parent: Option<usize>,
+    /// Indexes of the child loops (from the next pass) in [`PocketTree::loops`].
+    pub children: Vec<usize>,
+}
+
+/// Tree of the loops created by repeatedly offsetting a shape inward, created by
+/// [`Shape::pocket_offsets`].
+#[derive(Debug, Clone)]
+pub struct PocketTree<T>
+where
+    T: Real,
+{
+    /// All the loops ordered by pass, within a pass the outer loops come before the island loops.
+    pub loops: Vec<PocketLoop<T>>,
+    /// Indexes of the loops without a parent: the loops of the first pass and any loop of a later
+    /// pass for which no adjacent loop was found in the previous pass.
+    pub roots: Vec<usize>,
+}
+
+impl<T> PocketTree<T>
+where
+    T: Real,
+{
+    /// Number of offset passes in the tree.
+    #[inline]
+    #[must_use]
+    pub fn pass_count(&self) -> usize {
+        self.loops.last().map_or(0, |l| l.pass + 1)
+    }
+
+    /// Find the parent for `pline` among the loops in `candidates` (the loops of the previous
+    /// pass).
+    fn find_parent(
+        &self,
+        candidates: std::ops::Range<usize>,
+        pline: &Polyline<T>,
+        is_island: bool,
+    ) -> Option<usize> {
+        let candidates = || candidates.clone().map(|i| (i, &self.loops[i].polyline));
+
+        if is_island {
+            // island loops grow on each pass, parent is the largest island loop surrounded (most
+            // negative area)
+            let surrounded = candidates()
+                .filter(|(_, c)| c.area() < T::zero() && pline.winding_number(c.at(0).pos()) != 0)
+                .min_by(|(_, a), (_, b)| a.area().total_cmp(&b.area()))
+                .map(|(i, _)| i);
+            if surrounded.is_some() {
+                return surrounded;
+            }
+        }
+
+        // smallest outer loop containing the polyline (handles nested outer loops)
+        let pos = pline.at(0).pos();
+        candidates()
+            .filter(|(_, c)| c.area() > T::zero() && c.winding_number(pos) != 0)
+            .min_by(|(_, a), (_, b)| a.area().total_cmp(&b.area()))
+            .map(|(i, _)| i)
+    }
+}
+
+impl<T> Shape<T>
+where
+    T: Real,
+{
+    /// Returns an iterator over successive inward offsets of this shape by `step`, each offset is
+    /// of the previous offset result. Iteration stops when no filled area remains. The iterator is
+    /// empty if `step` is not positive.
+    ///
+    /// See [`Shape::pocket_offsets`] to collect the loops of all the passes into a tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::shape_algorithms::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let square = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let shape = Shape::from_plines([square]);
+    /// let options = ShapeOffsetOptions::default();
+    /// // offsets at 1, 2, 3, and 4 (offset at 5 collapses)
+    /// assert_eq!(shape.inward_offsets(1.0, &options).count(), 4);
+    /// ```
+    #[must_use]
+    pub fn inward_offsets<'a>(
+        &'a self,
+        step: T,
+        options: &'a ShapeOffsetOptions<T>,
+    ) -> InwardOffsets<'a, T> {
+        InwardOffsets {
+            shape: self,
+            step,
+            options,
+            pending: None,
+            done: step.partial_cmp(&T::zero()) != Some(std::cmp::Ordering::Greater),
+        }
+    }
+
+    /// Repeatedly offset this shape inward by `step` until no filled area remains, returning all
+    /// the loops as a tree.
+    ///
+    /// The parent of a loop is the adjacent loop of the previous pass (`step` distance away): outer
+    /// loops are parented by the outer loop they lie inside of and island loops by the island loop
+    /// they surround. An island loop that does not surround an island loop of the previous pass is
+    /// parented by the outer loop it lies inside of. When loops merge only one of them becomes the
+    /// parent. The tree is empty if `step` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::shape_algorithms::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // two 10 x 10 squares joined by a 4 wide neck
+    /// let dumbbell = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 3.0, 0.0),
+    ///     (14.0, 3.0, 0.0),
+    ///     (14.0, 0.0, 0.0),
+    ///     (24.0, 0.0, 0.0),
+    ///     (24.0, 10.0, 0.0),
+    ///     (14.0, 10.0, 0.0),
+    ///     (14.0, 7.0, 0.0),
+    ///     (10.0, 7.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let shape = Shape::from_plines([dumbbell]);
+    /// let tree = shape.pocket_offsets(1.5, &ShapeOffsetOptions::default());
+    /// // first pass is a single loop, the neck is closed on the second pass splitting it into two
+    /// assert_eq!(tree.roots.len(), 1);
+    /// assert_eq!(tree.loops[tree.roots[0]].children.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn pocket_offsets(&self, step: T, options: &ShapeOffsetOptions<T>) -> PocketTree<T> {
+        let mut tree = PocketTree {
+            loops: Vec::new(),
+            roots: Vec::new(),
+        };
+
+        let mut prev_pass = 0..0;
+        for (pass, shape) in self.inward_offsets(step, options).enumerate() {
+            let pass_start = tree.loops.len();
+            let outer_loops = shape.ccw_plines.into_iter().map(|p| (p.polyline, false));
+            let island_loops = shape.cw_plines.into_iter().map(|p| (p.polyline, true));
+            for (polyline, is_island) in outer_loops.chain(island_loops) {
+                let index = tree.loops.len();
+                let parent = tree.find_parent(prev_pass.clone(), &polyline, is_island);
+                match parent {
+                    Some(p) => tree.loops[p].children.push(index),
+                    None => tree.roots.push(index),
+                }
+                tree.loops.push(PocketLoop {
+                    polyline,
+                    pass,
+                    parent,
+                    children: Vec::new(),
+                });
+            }
+            prev_pass = pass_start..tree.loops.len();
+        }
+
+        tree
+    }
+}
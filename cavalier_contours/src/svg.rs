@@ -0,0 +1,696 @@
+//! Conversion between polylines/shapes and SVG path data (the `d` attribute of an SVG `path`
+//! element), enabled with the `svg` feature.
+//!
+//! Polylines are written using absolute move to (`M`), line to (`L`), elliptical arc (`A`), and
+//! close path (`Z`) commands. Arc segments are written as circular `A` commands so no
+//! approximation is done.
+//!
+//! Path data is parsed into polylines with each subpath becoming a polyline (closed if the subpath
+//! ends with a close path command). All path commands are supported, circular arcs are converted
+//! to arc segments exactly while elliptical arcs and bezier curves are approximated by line
+//! segments within a tolerance.
+
+use std::fmt::{self, Write};
+
+use crate::{
+    core::{
+        math::{Vector2, angle, bulge_from_angle},
+        traits::Real,
+    },
+    polyline::{PlineSource, PlineSourceMut, Polyline, seg_arc_radius_and_center},
+    shape_algorithms::Shape,
+};
+
+/// Struct to hold options parameters when parsing SVG path data.
+#[derive(Debug, Clone)]
+pub struct SvgPathParseOptions<T> {
+    /// Maximum distance between a curve (bezier curve or elliptical arc) and the line segments
+    /// approximating it.
+    pub curve_tolerance: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal. Path commands
+    /// that do not move the current point are skipped and a subpath that ends at its start point
+    /// before closing is closed without an additional segment.
+    pub pos_equal_eps: T,
+}
+
+impl<T> SvgPathParseOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            curve_tolerance: T::from(1e-2).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for SvgPathParseOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error returned when SVG path data fails to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvgPathError {
+    /// Path data does not start with a move to command (`M` or `m`).
+    MissingMoveTo,
+    /// Character that is not a path command or number found at byte `position`.
+    UnexpectedChar {
+        /// Byte position of the character in the path data.
+        position: usize,
+        /// Character found.
+        found: char,
+    },
+    /// Expected a number (or arc flag) at byte `position`.
+    ExpectedNumber {
+        /// Byte position in the path data.
+        position: usize,
+    },
+}
+
+impl fmt::Display for SvgPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgPathError::MissingMoveTo => write!(f, "path data must start with a move to command"),
+            SvgPathError::UnexpectedChar { position, found } => {
+                write!(f, "unexpected character '{found}' at position {position}")
+            }
+            SvgPathError::ExpectedNumber { position } => {
+                write!(f, "expected number at position {position}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SvgPathError {}
+
+/// Write the SVG path data for `polyline`.
+///
+/// Returns an empty string if `polyline` has no vertexes.
+///
+/// # Examples
+/// ```
+/// # use cavalier_contours::svg::*;
+/// # use cavalier_contours::pline_closed;
+/// // circle with radius 1 centered at (1, 0)
+/// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+/// assert_eq!(pline_to_path_data(&circle), "M 0 0 A 1 1 0 0 1 2 0 A 1 1 0 0 1 0 0 Z");
+/// ```
+#[must_use]
+pub fn pline_to_path_data<P, T>(polyline: &P) -> String
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut data = String::new();
+    write_pline(&mut data, polyline);
+    data
+}
+
+/// Write the SVG path data for all the loops of `shape`, counter clockwise loops followed by
+/// clockwise loops.
+///
+/// Filled area is represented with the `nonzero` fill rule (the default SVG fill rule).
+#[must_use]
+pub fn shape_to_path_data<T>(shape: &Shape<T>) -> String
+where
+    T: Real,
+{
+    let mut data = String::new();
+    for pline in shape.ccw_plines.iter().chain(&shape.cw_plines) {
+        if !data.is_empty() {
+            data.push(' ');
+        }
+        write_pline(&mut data, &pline.polyline);
+    }
+    data
+}
+
+fn write_pline<P, T>(data: &mut String, polyline: &P)
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let Some(first) = polyline.get(0) else {
+        return;
+    };
+
+    let num = |n: T| {
+        let n = n.to_f64().unwrap();
+        // avoid writing negative zero
+        if n == 0.0 { 0.0 } else { n }
+    };
+    let mut write_data = |args: fmt::Arguments| {
+        data.write_fmt(args).expect("writing to string cannot fail");
+    };
+
+    write_data(format_args!("M {} {}", num(first.x), num(first.y)));
+    for (i, j) in polyline.iter_segment_indexes() {
+        let v1 = polyline.at(i);
+        let v2 = polyline.at(j);
+        if v1.bulge_is_zero() {
+            if !(polyline.is_closed() && j == 0) {
+                write_data(format_args!(" L {} {}", num(v2.x), num(v2.y)));
+            }
+        } else {
+            let (radius, _) = seg_arc_radius_and_center(v1, v2);
+            let radius = num(radius);
+            let large_arc = u8::from(v1.bulge.abs() > T::one());
+            let sweep = u8::from(v1.bulge_is_pos());
+            write_data(format_args!(
+                " A {radius} {radius} 0 {large_arc} {sweep} {} {}",
+                num(v2.x),
+                num(v2.y)
+            ));
+        }
+    }
+
+    if polyline.is_closed() {
+        write_data(format_args!(" Z"));
+    }
+}
+
+/// Parse SVG path data into polylines using default options.
+///
+/// See [`plines_from_path_data_opt`] for more information.
+///
+/// # Errors
+///
+/// Returns [`SvgPathError`] if `data` is not valid SVG path data.
+pub fn plines_from_path_data<T>(data: &str) -> Result<Vec<Polyline<T>>, SvgPathError>
+where
+    T: Real,
+{
+    plines_from_path_data_opt(data, &SvgPathParseOptions::default())
+}
+
+/// Parse SVG path data into polylines with options given.
+///
+/// Each subpath with at least two distinct positions becomes a polyline, closed if the subpath
+/// ends with a close path command. Circular arcs (equal x and y radius) become arc segments,
+/// elliptical arcs and bezier curves are approximated by line segments within
+/// `options.curve_tolerance`. Empty path data returns an empty `Vec`.
+///
+/// # Errors
+///
+/// Returns [`SvgPathError`] if `data` is not valid SVG path data, no polylines are returned if
+/// any part of `data` fails to parse.
+///
+/// # Examples
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::svg::*;
+/// # use cavalier_contours::core::{math::Vector2, traits::*};
+/// // 2 x 1 rectangle with rounded right side, followed by an open line
+/// let plines = plines_from_path_data::<f64>("M0,0 h2 a0.5,0.5 0 0 1 0,1 H0 z m5,5 l1,0").unwrap();
+/// assert_eq!(plines.len(), 2);
+/// assert!(plines[0].is_closed());
+/// assert_eq!(plines[0].vertex_count(), 4);
+/// assert!(plines[0][1].bulge.fuzzy_eq(1.0));
+/// assert!(!plines[1].is_closed());
+/// assert!(plines[1][0].pos().fuzzy_eq(Vector2::new(5.0, 5.0)));
+/// ```
+pub fn plines_from_path_data_opt<T>(
+    data: &str,
+    options: &SvgPathParseOptions<T>,
+) -> Result<Vec<Polyline<T>>, SvgPathError>
+where
+    T: Real,
+{
+    let mut lexer = PathLexer {
+        bytes: data.as_bytes(),
+        pos: 0,
+    };
+    let mut builder = PathBuilder {
+        plines: Vec::new(),
+        current: Polyline::new(),
+        options,
+    };
+
+    // start point of the current subpath
+    let mut start = Vector2::zero();
+    // current point
+    let mut curr = Vector2::zero();
+    // last command and the control point it ended with (for reflecting with S and T commands)
+    let mut prev_cmd = None;
+    let mut prev_ctrl = Vector2::zero();
+
+    while lexer.skip_separators() {
+        let cmd = if let Some(cmd) = lexer.command() {
+            cmd
+        } else if lexer.at_number() {
+            // implicit repeat of the previous command, move to is repeated as line to
+            match prev_cmd {
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(cmd) if !cmd.eq_ignore_ascii_case(&b'Z') => cmd,
+                _ => return Err(lexer.unexpected_char()),
+            }
+        } else {
+            return Err(lexer.unexpected_char());
+        };
+
+        if prev_cmd.is_none() && !cmd.eq_ignore_ascii_case(&b'M') {
+            return Err(SvgPathError::MissingMoveTo);
+        }
+
+        let relative = cmd.is_ascii_lowercase();
+        let offset = if relative { curr } else { Vector2::zero() };
+        let read_point = |lexer: &mut PathLexer| -> Result<Vector2<T>, SvgPathError> {
+            let x = lexer.number()?;
+            let y = lexer.number()?;
+            Ok(Vector2::new(x, y) + offset)
+        };
+
+        // control point for reflection by the next command, reset to the current point for
+        // commands that are not curves
+        let mut ctrl = None;
+        match cmd.to_ascii_uppercase() {
+            b'M' => {
+                curr = read_point(&mut lexer)?;
+                start = curr;
+                builder.move_to(curr);
+            }
+            b'L' => {
+                curr = read_point(&mut lexer)?;
+                builder.line_to(curr);
+            }
+            b'H' => {
+                let x = lexer.number()?;
+                curr = Vector2::new(if relative { curr.x + x } else { x }, curr.y);
+                builder.line_to(curr);
+            }
+            b'V' => {
+                let y = lexer.number()?;
+                curr = Vector2::new(curr.x, if relative { curr.y + y } else { y });
+                builder.line_to(curr);
+            }
+            b'A' => {
+                let arc = SvgArc {
+                    rx: lexer.number()?,
+                    ry: lexer.number()?,
+                    rotation: lexer.number()?,
+                    large_arc: lexer.flag()?,
+                    sweep: lexer.flag()?,
+                };
+                let end = read_point(&mut lexer)?;
+                builder.arc_to(curr, end, arc);
+                curr = end;
+            }
+            b'C' | b'S' => {
+                let ctrl1 = if cmd.eq_ignore_ascii_case(&b'C') {
+                    read_point(&mut lexer)?
+                } else {
+                    reflected_ctrl(prev_cmd, prev_ctrl, curr, b'C', b'S')
+                };
+                let ctrl2 = read_point(&mut lexer)?;
+                let end = read_point(&mut lexer)?;
+                builder.cubic_to(curr, ctrl1, ctrl2, end);
+                curr = end;
+                ctrl = Some(ctrl2);
+            }
+            b'Q' | b'T' => {
+                let ctrl1 = if cmd.eq_ignore_ascii_case(&b'Q') {
+                    read_point(&mut lexer)?
+                } else {
+                    reflected_ctrl(prev_cmd, prev_ctrl, curr, b'Q', b'T')
+                };
+                let end = read_point(&mut lexer)?;
+                builder.quadratic_to(curr, ctrl1, end);
+                curr = end;
+                ctrl = Some(ctrl1);
+            }
+            b'Z' => {
+                builder.close(start);
+                curr = start;
+            }
+            _ => unreachable!("lexer only returns path commands"),
+        }
+
+        prev_cmd = Some(cmd);
+        prev_ctrl = ctrl.unwrap_or(curr);
+    }
+
+    builder.finish_subpath();
+    Ok(builder.plines)
+}
+
+/// Returns the first control point for a smooth curve command (`S` or `T`), which is the
+/// reflection of the previous control point about the current point if the previous command was
+/// the same type of curve, otherwise the current point.
+fn reflected_ctrl<T>(
+    prev_cmd: Option<u8>,
+    prev_ctrl: Vector2<T>,
+    curr: Vector2<T>,
+    curve_cmd: u8,
+    smooth_cmd: u8,
+) -> Vector2<T>
+where
+    T: Real,
+{
+    match prev_cmd {
+        Some(c) if c.eq_ignore_ascii_case(&curve_cmd) || c.eq_ignore_ascii_case(&smooth_cmd) => {
+            curr.scale(T::two()) - prev_ctrl
+        }
+        _ => curr,
+    }
+}
+
+/// Reads commands, numbers, and flags from SVG path data.
+struct PathLexer<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl PathLexer<'_> {
+    /// Skip whitespace and commas, returns false if the end of the data is reached.
+    fn skip_separators(&mut self) -> bool {
+        while let Some(&b) = self.bytes.get(self.pos) {
+            if b.is_ascii_whitespace() || b == b',' {
+                self.pos += 1;
+            } else {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Consume and return the next byte if it is a path command.
+    fn command(&mut self) -> Option<u8> {
+        let b = *self.bytes.get(self.pos)?;
+        if b"MmLlHhVvAaCcSsQqTtZz".contains(&b) {
+            self.pos += 1;
+            Some(b)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the next byte can start a number.
+    fn at_number(&self) -> bool {
+        self.bytes
+            .get(self.pos)
+            .is_some_and(|b| b.is_ascii_digit() || matches!(b, b'+' | b'-' | b'.'))
+    }
+
+    fn unexpected_char(&self) -> SvgPathError {
+        // path data is a str so decode the full character at the position
+        let found = std::str::from_utf8(&self.bytes[self.pos..])
+            .ok()
+            .and_then(|s| s.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+        SvgPathError::UnexpectedChar {
+            position: self.pos,
+            found,
+        }
+    }
+
+    fn number<T>(&mut self) -> Result<T, SvgPathError>
+    where
+        T: Real,
+    {
+        self.skip_separators();
+        let start = self.pos;
+        let digits = |lexer: &mut Self| {
+            let digits_start = lexer.pos;
+            while lexer.bytes.get(lexer.pos).is_some_and(u8::is_ascii_digit) {
+                lexer.pos += 1;
+            }
+            lexer.pos - digits_start
+        };
+
+        if matches!(self.bytes.get(self.pos), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        let mut digit_count = digits(self);
+        if self.bytes.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            digit_count += digits(self);
+        }
+        if digit_count == 0 {
+            self.pos = start;
+            return Err(SvgPathError::ExpectedNumber { position: start });
+        }
+        // exponent, only consumed if followed by digits (e.g. "1e" followed by something else)
+        if matches!(self.bytes.get(self.pos), Some(b'e' | b'E')) {
+            let mantissa_end = self.pos;
+            self.pos += 1;
+            if matches!(self.bytes.get(self.pos), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if digits(self) == 0 {
+                self.pos = mantissa_end;
+            }
+        }
+
+        // slice only contains ascii so it is valid utf8
+        let text = std::str::from_utf8(&self.bytes[start..self.pos]).unwrap();
+        match text.parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(T::from(n).unwrap()),
+            _ => {
+                self.pos = start;
+                Err(SvgPathError::ExpectedNumber { position: start })
+            }
+        }
+    }
+
+    /// Read an arc flag, flags are a single `0` or `1` and may not be separated from the next
+    /// value.
+    fn flag(&mut self) -> Result<bool, SvgPathError> {
+        self.skip_separators();
+        let flag = match self.bytes.get(self.pos) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(SvgPathError::ExpectedNumber { position: self.pos }),
+        };
+        self.pos += 1;
+        Ok(flag)
+    }
+}
+
+/// Elliptical arc parameters of an SVG path arc command.
+#[derive(Debug, Clone, Copy)]
+struct SvgArc<T> {
+    rx: T,
+    ry: T,
+    /// Rotation of the ellipse x axis in degrees.
+    rotation: T,
+    large_arc: bool,
+    sweep: bool,
+}
+
+/// Builds polylines from the subpaths of SVG path data.
+struct PathBuilder<'a, T>
+where
+    T: Real,
+{
+    plines: Vec<Polyline<T>>,
+    current: Polyline<T>,
+    options: &'a SvgPathParseOptions<T>,
+}
+
+impl<T> PathBuilder<'_, T>
+where
+    T: Real,
+{
+    /// Add the current subpath to the result if it has a segment and start a new empty subpath.
+    fn finish_subpath(&mut self) {
+        let pline = std::mem::take(&mut self.current);
+        if pline.vertex_count() > 1 {
+            self.plines.push(pline);
+        }
+    }
+
+    fn move_to(&mut self, point: Vector2<T>) {
+        self.finish_subpath();
+        self.current.add(point.x, point.y, T::zero());
+    }
+
+    fn line_to(&mut self, point: Vector2<T>) {
+        self.arc_seg_to(point, T::zero());
+    }
+
+    /// Add a segment to `point` with `bulge`, skipped if `point` is the current point.
+    fn arc_seg_to(&mut self, point: Vector2<T>, bulge: T) {
+        let last = self
+            .current
+            .last()
+            .expect("subpath always starts with a vertex");
+        if last.pos().fuzzy_eq_eps(point, self.options.pos_equal_eps) {
+            return;
+        }
+        self.current.set_last(last.with_bulge(bulge));
+        self.current.add(point.x, point.y, T::zero());
+    }
+
+    fn close(&mut self, start: Vector2<T>) {
+        let pline = &mut self.current;
+        if pline.vertex_count() > 1
+            && pline
+                .last()
+                .unwrap()
+                .pos()
+                .fuzzy_eq_eps(start, self.options.pos_equal_eps)
+        {
+            // segment ending at the start is the closing segment, bulge is kept on the vertex
+            // before the removed one
+            pline.remove_last();
+        }
+        pline.set_is_closed(true);
+        self.finish_subpath();
+        // drawing commands after close path continue from the subpath start point
+        self.current.add(start.x, start.y, T::zero());
+    }
+
+    /// Number of line segments to use for a curve with the given bound on its second derivative
+    /// magnitude, for parametric curves approximated with evenly spaced parameter values.
+    fn curve_seg_count(&self, second_derivative_bound: T) -> usize {
+        let tol = self.options.curve_tolerance;
+        // error of each line segment is at most bound * dt^2 / 8
+        let count = (second_derivative_bound / (T::from(8.0).unwrap() * tol)).sqrt();
+        count.ceil().to_usize().unwrap_or(1).clamp(1, 1000)
+    }
+
+    fn cubic_to(&mut self, p0: Vector2<T>, p1: Vector2<T>, p2: Vector2<T>, p3: Vector2<T>) {
+        let d1 = (p0 - p1.scale(T::two()) + p2).length();
+        let d2 = (p1 - p2.scale(T::two()) + p3).length();
+        let count =
+            self.curve_seg_count(T::from(6.0).unwrap() * num_traits::real::Real::max(d1, d2));
+        let three = T::from(3.0).unwrap();
+        for i in 1..count {
+            let t = T::from(i).unwrap() / T::from(count).unwrap();
+            let mt = T::one() - t;
+            let point = p0.scale(mt * mt * mt)
+                + p1.scale(three * mt * mt * t)
+                + p2.scale(three * mt * t * t)
+                + p3.scale(t * t * t);
+            self.line_to(point);
+        }
+        self.line_to(p3);
+    }
+
+    fn quadratic_to(&mut self, p0: Vector2<T>, p1: Vector2<T>, p2: Vector2<T>) {
+        let d = (p0 - p1.scale(T::two()) + p2).length();
+        let count = self.curve_seg_count(T::two() * d);
+        for i in 1..count {
+            let t = T::from(i).unwrap() / T::from(count).unwrap();
+            let mt = T::one() - t;
+            let point = p0.scale(mt * mt) + p1.scale(T::two() * mt * t) + p2.scale(t * t);
+            self.line_to(point);
+        }
+        self.line_to(p2);
+    }
+
+    /// Add an SVG elliptical arc from `start` to `end`.
+    fn arc_to(&mut self, start: Vector2<T>, end: Vector2<T>, arc: SvgArc<T>) {
+        let SvgArc {
+            rx,
+            ry,
+            rotation,
+            large_arc,
+            sweep,
+        } = arc;
+        let eps = self.options.pos_equal_eps;
+        if start.fuzzy_eq_eps(end, eps) {
+            // arc is omitted if end points are the same
+            return;
+        }
+        let rx = rx.abs();
+        let ry = ry.abs();
+        if rx.fuzzy_eq_zero_eps(eps) || ry.fuzzy_eq_zero_eps(eps) {
+            // arc is treated as a line if either radius is zero
+            self.line_to(end);
+            return;
+        }
+
+        if rx.fuzzy_eq_eps(ry, eps) {
+            // circular arc, radius is scaled up if too small to reach the end point
+            let half_chord = (end - start).length() / T::two();
+            let radius = num_traits::real::Real::max(rx, half_chord);
+            let half_angle = num_traits::real::Real::min(half_chord / radius, T::one()).asin();
+            let small_sweep = T::two() * half_angle;
+            let sweep_angle = if large_arc {
+                T::tau() - small_sweep
+            } else {
+                small_sweep
+            };
+            let bulge = bulge_from_angle(sweep_angle);
+            self.arc_seg_to(end, if sweep { bulge } else { -bulge });
+            return;
+        }
+
+        // elliptical arc, convert to center parameterization (SVG implementation notes) and
+        // approximate with line segments
+        let (sin_phi, cos_phi) = rotation.to_radians().sin_cos();
+        let half_diff = (start - end).scale(T::from(0.5).unwrap());
+        let x1 = cos_phi * half_diff.x + sin_phi * half_diff.y;
+        let y1 = -sin_phi * half_diff.x + cos_phi * half_diff.y;
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        let (rx, ry) = if lambda > T::one() {
+            let s = lambda.sqrt();
+            (rx * s, ry * s)
+        } else {
+            (rx, ry)
+        };
+        let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let mut coef = num_traits::real::Real::max(numerator / denominator, T::zero()).sqrt();
+        if large_arc == sweep {
+            coef = -coef;
+        }
+        let cx1 = coef * rx * y1 / ry;
+        let cy1 = -coef * ry * x1 / rx;
+        let mid = (start + end).scale(T::from(0.5).unwrap());
+        let center = Vector2::new(
+            cos_phi * cx1 - sin_phi * cy1 + mid.x,
+            sin_phi * cx1 + cos_phi * cy1 + mid.y,
+        );
+
+        let start_angle = angle(
+            Vector2::zero(),
+            Vector2::new((x1 - cx1) / rx, (y1 - cy1) / ry),
+        );
+        let end_angle = angle(
+            Vector2::zero(),
+            Vector2::new((-x1 - cx1) / rx, (-y1 - cy1) / ry),
+        );
+        let mut sweep_angle = end_angle - start_angle;
+        if sweep && sweep_angle < T::zero() {
+            sweep_angle = sweep_angle + T::tau();
+        } else if !sweep && sweep_angle > T::zero() {
+            sweep_angle = sweep_angle - T::tau();
+        }
+
+        // max angle step for chord error within tolerance on the larger radius
+        let max_radius = num_traits::real::Real::max(rx, ry);
+        let max_step = if self.options.curve_tolerance < max_radius {
+            T::two() * (T::one() - self.options.curve_tolerance / max_radius).acos()
+        } else {
+            T::pi() / T::two()
+        };
+        let count = (sweep_angle.abs() / max_step)
+            .ceil()
+            .to_usize()
+            .unwrap_or(1)
+            .max(1);
+        for i in 1..count {
+            let theta = start_angle + sweep_angle * T::from(i).unwrap() / T::from(count).unwrap();
+            let (sin_t, cos_t) = theta.sin_cos();
+            let point = Vector2::new(
+                center.x + rx * cos_phi * cos_t - ry * sin_phi * sin_t,
+                center.y + rx * sin_phi * cos_t + ry * cos_phi * sin_t,
+            );
+            self.line_to(point);
+        }
+        self.line_to(end);
+    }
+}
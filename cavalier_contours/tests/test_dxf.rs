@@ -0,0 +1,259 @@
+#![cfg(feature = "dxf")]
+
+mod test_dxf {
+    use std::f64::consts::PI;
+    use std::fmt::Write;
+
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::dxf::*;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    /// Create DXF data with the given entity groups in the ENTITIES section.
+    fn dxf_entities(entities: &[(i32, &str)]) -> String {
+        let mut data = String::from("0\nSECTION\n2\nENTITIES\n");
+        for (code, value) in entities {
+            writeln!(data, "{code}\n{value}").unwrap();
+        }
+        data.push_str("0\nENDSEC\n0\nEOF\n");
+        data
+    }
+
+    fn assert_plines_eq(result: &Polyline, expected: &Polyline) {
+        assert_eq!(result.is_closed(), expected.is_closed());
+        assert_eq!(
+            result.vertex_count(),
+            expected.vertex_count(),
+            "{result:?} != {expected:?}"
+        );
+        for (r, e) in result.iter_vertexes().zip(expected.iter_vertexes()) {
+            assert!(r.fuzzy_eq(e), "{r:?} != {e:?}");
+        }
+    }
+
+    #[test]
+    fn read_lwpolyline() {
+        // padded group codes and CRLF line endings
+        let data = "  0\r\nSECTION\r\n  2\r\nENTITIES\r\n  0\r\nLWPOLYLINE\r\n  8\r\nCUT\r\n 90\r\n3\r\n \
+                    70\r\n1\r\n 10\r\n0\r\n 20\r\n0\r\n 10\r\n4\r\n 20\r\n0\r\n 42\r\n0.5\r\n 10\r\n4\r\n \
+                    20\r\n3\r\n  0\r\nENDSEC\r\n  0\r\nEOF\r\n";
+        let result = read_dxf(data).unwrap();
+        assert_eq!(result.plines.len(), 1);
+        assert_eq!(result.layers, ["CUT"]);
+        assert_eq!(result.plines[0].userdata, [0]);
+        assert_plines_eq(
+            &result.plines[0],
+            &pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 0.5), (4.0, 3.0, 0.0)],
+        );
+    }
+
+    #[test]
+    fn read_polyline_with_vertexes() {
+        let data = dxf_entities(&[
+            (0, "POLYLINE"),
+            (8, "A"),
+            (66, "1"),
+            (70, "1"),
+            (0, "VERTEX"),
+            (10, "0"),
+            (20, "0"),
+            (42, "-1"),
+            (0, "VERTEX"),
+            (10, "2"),
+            (20, "0"),
+            (42, "-1"),
+            // spline frame control point is skipped
+            (0, "VERTEX"),
+            (10, "5"),
+            (20, "5"),
+            (70, "16"),
+            (0, "SEQEND"),
+            // 3D polyline is skipped
+            (0, "POLYLINE"),
+            (70, "8"),
+            (0, "VERTEX"),
+            (10, "0"),
+            (20, "0"),
+            (0, "VERTEX"),
+            (10, "1"),
+            (20, "1"),
+            (0, "SEQEND"),
+            (0, "LINE"),
+            (8, "B"),
+            (10, "0"),
+            (20, "0"),
+            (11, "1"),
+            (21, "1"),
+        ]);
+        let result = read_dxf(&data).unwrap();
+        assert_eq!(result.plines.len(), 2);
+        assert_plines_eq(
+            &result.plines[0],
+            &pline_closed![(0.0, 0.0, -1.0), (2.0, 0.0, -1.0)],
+        );
+        assert_plines_eq(
+            &result.plines[1],
+            &pline_open![(0.0, 0.0, 0.0), (1.0, 1.0, 0.0)],
+        );
+        assert_eq!(result.layer_name(&result.plines[0]), Some("A"));
+        assert_eq!(result.layer_name(&result.plines[1]), Some("B"));
+    }
+
+    #[test]
+    fn read_arcs_and_circles() {
+        let data = dxf_entities(&[
+            (0, "ARC"),
+            (10, "1"),
+            (20, "1"),
+            (40, "2"),
+            (50, "0"),
+            (51, "90"),
+            // wraps past 0 degrees
+            (0, "ARC"),
+            (10, "0"),
+            (20, "0"),
+            (40, "1"),
+            (50, "315"),
+            (51, "45"),
+            // larger than a half circle
+            (0, "ARC"),
+            (10, "0"),
+            (20, "0"),
+            (40, "1"),
+            (50, "0"),
+            (51, "270"),
+            (0, "CIRCLE"),
+            (8, "HOLES"),
+            (10, "5"),
+            (20, "5"),
+            (40, "0.5"),
+            // zero radius is skipped
+            (0, "CIRCLE"),
+            (10, "5"),
+            (20, "5"),
+            (40, "0"),
+        ]);
+        let result = read_dxf(&data).unwrap();
+        assert_eq!(result.plines.len(), 4);
+        let quarter = (PI / 8.0).tan();
+        assert_plines_eq(
+            &result.plines[0],
+            &pline_open![(3.0, 1.0, quarter), (1.0, 3.0, 0.0)],
+        );
+        let s = 0.5f64.sqrt();
+        assert_plines_eq(
+            &result.plines[1],
+            &pline_open![(s, -s, quarter), (s, s, 0.0)],
+        );
+        let three_eighths = (3.0 * PI / 16.0).tan();
+        assert_plines_eq(
+            &result.plines[2],
+            &pline_open![
+                (1.0, 0.0, three_eighths),
+                (-s, s, three_eighths),
+                (0.0, -1.0, 0.0)
+            ],
+        );
+        let circle = &result.plines[3];
+        assert!(circle.is_closed());
+        assert!(circle.area().fuzzy_eq(PI * 0.25));
+        assert!(circle.extents().unwrap().max_x.fuzzy_eq(5.5));
+        assert_eq!(result.layers, ["0", "HOLES"]);
+        assert_eq!(result.layer_name(circle), Some("HOLES"));
+    }
+
+    #[test]
+    fn read_mirrored_extrusion() {
+        let data = dxf_entities(&[
+            (0, "ARC"),
+            (10, "1"),
+            (20, "0"),
+            (40, "1"),
+            (50, "0"),
+            (51, "90"),
+            (210, "0"),
+            (220, "0"),
+            (230, "-1"),
+            (0, "LWPOLYLINE"),
+            (90, "2"),
+            (70, "0"),
+            (10, "1"),
+            (20, "2"),
+            (42, "0.5"),
+            (10, "3"),
+            (20, "2"),
+            (230, "-1.0"),
+        ]);
+        let result = read_dxf(&data).unwrap();
+        let quarter = (PI / 8.0).tan();
+        assert_plines_eq(
+            &result.plines[0],
+            &pline_open![(-2.0, 0.0, -quarter), (-1.0, 1.0, 0.0)],
+        );
+        assert_plines_eq(
+            &result.plines[1],
+            &pline_open![(-1.0, 2.0, -0.5), (-3.0, 2.0, 0.0)],
+        );
+    }
+
+    #[test]
+    fn only_entities_section_is_read() {
+        let data = "0\nSECTION\n2\nBLOCKS\n0\nBLOCK\n2\nB1\n0\nLINE\n10\n0\n20\n0\n11\n1\n21\n0\n\
+                    0\nENDBLK\n0\nENDSEC\n0\nSECTION\n2\nENTITIES\n0\nLINE\n10\n0\n20\n0\n11\n0\n21\n\
+                    2\n0\nENDSEC\n0\nEOF\n";
+        let result = read_dxf(data).unwrap();
+        assert_eq!(result.plines.len(), 1);
+        assert!(result.plines[0].path_length().fuzzy_eq(2.0));
+        assert!(read_dxf("").unwrap().plines.is_empty());
+    }
+
+    #[test]
+    fn read_errors() {
+        assert_eq!(
+            read_dxf("0\nSECTION\nENTITIES\n").unwrap_err(),
+            DxfError::InvalidGroupCode { line: 3 }
+        );
+        assert_eq!(
+            read_dxf("0\nSECTION\n2").unwrap_err(),
+            DxfError::MissingValue { line: 3 }
+        );
+        let data = dxf_entities(&[(0, "LINE"), (10, "abc")]);
+        assert_eq!(
+            read_dxf(&data).unwrap_err(),
+            DxfError::InvalidNumber { line: 8 }
+        );
+        assert_eq!(
+            DxfError::InvalidNumber { line: 8 }.to_string(),
+            "invalid number on line 8"
+        );
+    }
+
+    #[test]
+    fn write_round_trip() {
+        let plines = [
+            pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, -0.25), (10.0, 5.5, 2.0)],
+            pline_open![(1.0, 1.0, 0.0), (-1.0, 1e-3, 0.0)],
+            pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)],
+        ];
+        let layers = ["CUT", "SCORE", "CUT"];
+        let data = write_dxf(plines.iter().zip(layers));
+        assert!(data.starts_with("  0\nSECTION\n  2\nENTITIES\n  0\nLWPOLYLINE\n"));
+        assert!(data.ends_with("  0\nENDSEC\n  0\nEOF\n"));
+
+        let result = read_dxf(&data).unwrap();
+        assert_eq!(result.plines.len(), 3);
+        assert_eq!(result.layers, ["CUT", "SCORE"]);
+        for ((pline, expected), layer) in result.plines.iter().zip(&plines).zip(layers) {
+            assert_plines_eq(pline, expected);
+            assert_eq!(result.layer_name(pline), Some(layer));
+        }
+
+        // bulge groups are only written for arc segments
+        let data = write_dxf([(&plines[1], "SCORE")]);
+        assert!(!data.contains(" 42\n"));
+        assert!(data.contains(" 90\n2\n 70\n0\n"));
+
+        let result = read_dxf(&write_dxf::<Polyline, _, _>([])).unwrap();
+        assert!(result.plines.is_empty());
+    }
+}
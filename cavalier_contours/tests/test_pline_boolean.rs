@@ -831,3 +831,209 @@ mod test_specific {
         }
     }
 }
+
+mod test_fill_rule {
+    use cavalier_contours::{
+        core::traits::FuzzyEq,
+        pline_closed,
+        polyline::{FillRule, PlineOrientation},
+    };
+
+    use super::*;
+
+    /// Figure eight with a counter clockwise loop on the left and clockwise loop on the right.
+    fn bow_tie() -> Polyline {
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (2.0, 2.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (0.0, 2.0, 0.0)
+        ]
+    }
+
+    /// Five pointed star drawn with a single self intersecting polyline, the center pentagon has a
+    /// winding number of 2.
+    fn pentagram() -> Polyline {
+        let mut pline = Polyline::new_closed();
+        for i in 0..5 {
+            let angle =
+                std::f64::consts::FRAC_PI_2 + f64::from(i) * 4.0 * std::f64::consts::PI / 5.0;
+            pline.add(10.0 * angle.cos(), 10.0 * angle.sin(), 0.0);
+        }
+        pline
+    }
+
+    fn total_area(plines: &[BooleanResultPline<Polyline>]) -> f64 {
+        plines.iter().map(|p| p.pline.area()).sum()
+    }
+
+    #[test]
+    fn resolve_bow_tie() {
+        for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+            let result = bow_tie().resolve_self_intersects(fill_rule);
+            assert_eq!(result.len(), 2);
+            for pline in &result {
+                assert_eq!(pline.orientation(), PlineOrientation::CounterClockwise);
+                assert!(pline.area().fuzzy_eq(1.0));
+                assert_eq!(pline.vertex_count(), 3);
+            }
+        }
+    }
+
+    #[test]
+    fn resolve_pentagram() {
+        let non_zero = pentagram().resolve_self_intersects(FillRule::NonZero);
+        assert_eq!(non_zero.len(), 1);
+        assert_eq!(non_zero[0].vertex_count(), 10);
+        assert_eq!(
+            non_zero[0].orientation(),
+            PlineOrientation::CounterClockwise
+        );
+
+        // center pentagon is not filled with even odd, leaving the five star points
+        let even_odd = pentagram().resolve_self_intersects(FillRule::EvenOdd);
+        assert_eq!(even_odd.len(), 5);
+        assert!(
+            even_odd
+                .iter()
+                .all(|p| p.vertex_count() == 3 && p.area() > 0.0)
+        );
+        let points_area: f64 = even_odd.iter().map(PlineSource::area).sum();
+        assert!(points_area < non_zero[0].area());
+    }
+
+    #[test]
+    fn resolve_non_self_intersecting() {
+        let mut pline = pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)];
+        pline.invert_direction_mut();
+        let result = pline.resolve_self_intersects(FillRule::NonZero);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].area().fuzzy_eq(0.5));
+
+        let open = Polyline::<f64>::from_iter(pline.iter_vertexes(), false);
+        assert!(open.resolve_self_intersects(FillRule::NonZero).is_empty());
+    }
+
+    #[test]
+    fn boolean_bow_tie_with_rectangle() {
+        let rectangle = pline_closed![
+            (0.5, 0.0, 0.0),
+            (1.5, 0.0, 0.0),
+            (1.5, 2.0, 0.0),
+            (0.5, 2.0, 0.0)
+        ];
+        let options = PlineBooleanOptions {
+            fill_rule: Some(FillRule::NonZero),
+            ..Default::default()
+        };
+
+        let result = bow_tie().boolean_opt(&rectangle, BooleanOp::Or, &options);
+        assert!(matches!(result.result_info, BooleanResultInfo::Intersected));
+        assert_eq!(result.pos_plines.len(), 1);
+        assert!(result.neg_plines.is_empty());
+        assert!(total_area(&result.pos_plines).fuzzy_eq(3.5));
+
+        let result = bow_tie().boolean_opt(&rectangle, BooleanOp::And, &options);
+        assert_eq!(result.pos_plines.len(), 2);
+        assert!(total_area(&result.pos_plines).fuzzy_eq(0.5));
+
+        let result = bow_tie().boolean_opt(&rectangle, BooleanOp::Not, &options);
+        assert_eq!(result.pos_plines.len(), 2);
+        assert!(total_area(&result.pos_plines).fuzzy_eq(1.5));
+
+        let result = rectangle.boolean_opt(&bow_tie(), BooleanOp::Xor, &options);
+        assert!(total_area(&result.pos_plines).fuzzy_eq(3.0));
+    }
+
+    #[test]
+    fn boolean_pentagram_even_odd() {
+        let square = pline_closed![
+            (-1.0, -1.0, 0.0),
+            (1.0, -1.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (-1.0, 1.0, 0.0)
+        ];
+        let options = PlineBooleanOptions {
+            fill_rule: Some(FillRule::EvenOdd),
+            ..Default::default()
+        };
+        // square in the unfilled center fills part of the center pentagon
+        let result = pentagram().boolean_opt(&square, BooleanOp::Or, &options);
+        assert_eq!(result.pos_plines.len(), 6);
+        let result = pentagram().boolean_opt(&square, BooleanOp::And, &options);
+        assert!(result.pos_plines.is_empty());
+    }
+
+    #[test]
+    fn fill_rule_result_info() {
+        let options = PlineBooleanOptions {
+            fill_rule: Some(FillRule::NonZero),
+            ..Default::default()
+        };
+        let disjoint = pline_closed![
+            (3.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 1.0, 0.0),
+            (3.0, 1.0, 0.0)
+        ];
+        let result = bow_tie().boolean_opt(&disjoint, BooleanOp::Or, &options);
+        assert!(matches!(result.result_info, BooleanResultInfo::Disjoint));
+        assert_eq!(result.pos_plines.len(), 3);
+
+        let enclosing = pline_closed![
+            (-1.0, -1.0, 0.0),
+            (3.0, -1.0, 0.0),
+            (3.0, 3.0, 0.0),
+            (-1.0, 3.0, 0.0)
+        ];
+        let result = bow_tie().boolean_opt(&enclosing, BooleanOp::Or, &options);
+        assert!(matches!(
+            result.result_info,
+            BooleanResultInfo::Pline1InsidePline2
+        ));
+        assert!(total_area(&result.pos_plines).fuzzy_eq(16.0));
+        let result = enclosing.boolean_opt(&bow_tie(), BooleanOp::Or, &options);
+        assert!(matches!(
+            result.result_info,
+            BooleanResultInfo::Pline2InsidePline1
+        ));
+
+        // square within the unfilled center pentagon is disjoint from the even odd filled region
+        let square = pline_closed![
+            (-0.5, -0.5, 0.0),
+            (0.5, -0.5, 0.0),
+            (0.5, 0.5, 0.0),
+            (-0.5, 0.5, 0.0)
+        ];
+        let options = PlineBooleanOptions {
+            fill_rule: Some(FillRule::EvenOdd),
+            ..Default::default()
+        };
+        let result = pentagram().boolean_opt(&square, BooleanOp::Or, &options);
+        assert!(matches!(result.result_info, BooleanResultInfo::Disjoint));
+    }
+
+    #[test]
+    fn fill_rule_unused_without_self_intersects() {
+        let pline1 = pline_closed![
+            (0.0, 0.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (2.0, 2.0, 0.0),
+            (0.0, 2.0, 0.0)
+        ];
+        let pline2 = pline_closed![
+            (1.0, 1.0, 0.0),
+            (3.0, 1.0, 0.0),
+            (3.0, 3.0, 0.0),
+            (1.0, 3.0, 0.0)
+        ];
+        let options = PlineBooleanOptions {
+            fill_rule: Some(FillRule::NonZero),
+            ..Default::default()
+        };
+        let result = pline1.boolean_opt(&pline2, BooleanOp::Or, &options);
+        assert_eq!(result.pos_plines.len(), 1);
+        assert!(!result.pos_plines[0].subslices.is_empty());
+        assert!(total_area(&result.pos_plines).fuzzy_eq(7.0));
+    }
+}
@@ -0,0 +1,161 @@
+mod test_utils;
+
+mod test_pline_clip {
+    use cavalier_contours::core::{math::Vector2, traits::FuzzyEq};
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    use crate::test_utils::square;
+
+    fn total_length(parts: &[ClipResultPline<Polyline>]) -> f64 {
+        parts.iter().map(|p| p.pline.path_length()).sum()
+    }
+
+    #[test]
+    fn test_line_crossing_boundary() {
+        let line = pline_open![(-1.0, 2.0, 0.0), (5.0, 2.0, 0.0)];
+
+        let result = line.clip(&square(0.0, 0.0, 4.0), ClipKeep::Both);
+        assert_eq!(result.inside.len(), 1);
+        assert_eq!(result.outside.len(), 2);
+        let inside = &result.inside[0];
+        assert!(!inside.pline.is_closed());
+        assert!(inside.pline.at(0).pos().fuzzy_eq(Vector2::new(0.0, 2.0)));
+        assert!(inside.pline.at(1).pos().fuzzy_eq(Vector2::new(4.0, 2.0)));
+        assert_eq!((inside.start_index, inside.end_index), (0, 0));
+        assert!(result.outside[0].pline.path_length().fuzzy_eq(1.0));
+        assert!(
+            result.outside[0]
+                .pline
+                .at(0)
+                .pos()
+                .fuzzy_eq(line.at(0).pos())
+        );
+        assert!(result.outside[1].pline.path_length().fuzzy_eq(1.0));
+
+        let result = line.clip(&square(0.0, 0.0, 4.0), ClipKeep::Inside);
+        assert_eq!(result.inside.len(), 1);
+        assert!(result.outside.is_empty());
+
+        let result = line.clip(&square(0.0, 0.0, 4.0), ClipKeep::Outside);
+        assert!(result.inside.is_empty());
+        assert_eq!(result.outside.len(), 2);
+    }
+
+    #[test]
+    fn test_zigzag_source_indexes() {
+        // enters, leaves, and enters the boundary again
+        let zigzag = pline_open![
+            (-1.0, 1.0, 0.0),
+            (2.0, 1.0, 0.0),
+            (2.0, 6.0, 0.0),
+            (3.0, 6.0, 0.0),
+            (3.0, 2.0, 0.0),
+        ];
+
+        let result = zigzag.clip(&square(0.0, 0.0, 4.0), ClipKeep::Both);
+        assert_eq!(result.inside.len(), 2);
+        assert_eq!(result.outside.len(), 2);
+
+        let first_inside = &result.inside[0];
+        assert_eq!((first_inside.start_index, first_inside.end_index), (0, 1));
+        assert_eq!(first_inside.pline.vertex_count(), 3);
+        assert!(first_inside.pline.path_length().fuzzy_eq(5.0));
+
+        let outside_loop = &result.outside[1];
+        assert_eq!((outside_loop.start_index, outside_loop.end_index), (1, 3));
+        assert!(outside_loop.pline.path_length().fuzzy_eq(5.0));
+
+        let last_inside = &result.inside[1];
+        assert_eq!((last_inside.start_index, last_inside.end_index), (3, 3));
+        assert!(last_inside.pline.path_length().fuzzy_eq(2.0));
+
+        assert!(
+            (total_length(&result.inside) + total_length(&result.outside))
+                .fuzzy_eq(zigzag.path_length())
+        );
+    }
+
+    #[test]
+    fn test_no_intersects() {
+        let inside_line = pline_open![(1.0, 1.0, 0.0), (3.0, 3.0, 0.0)];
+        let result = inside_line.clip(&square(0.0, 0.0, 4.0), ClipKeep::Both);
+        assert_eq!(result.inside.len(), 1);
+        assert!(result.outside.is_empty());
+        assert_eq!(result.inside[0].pline.vertex_count(), 2);
+
+        let outside_line = pline_open![(5.0, 1.0, 0.0), (5.0, 3.0, 0.0)];
+        let result = outside_line.clip(&square(0.0, 0.0, 4.0), ClipKeep::Inside);
+        assert!(result.inside.is_empty() && result.outside.is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_boundary_is_inside() {
+        let line = pline_open![(-2.0, 0.0, 0.0), (6.0, 0.0, 0.0)];
+        let result = line.clip(&square(0.0, 0.0, 4.0), ClipKeep::Both);
+        assert_eq!(result.inside.len(), 1);
+        assert!(result.inside[0].pline.path_length().fuzzy_eq(4.0));
+        assert_eq!(result.outside.len(), 2);
+        assert!(total_length(&result.outside).fuzzy_eq(4.0));
+    }
+
+    #[test]
+    fn test_line_ending_on_boundary() {
+        let line = pline_open![(2.0, 2.0, 0.0), (2.0, 4.0, 0.0), (2.0, 6.0, 0.0)];
+        let result = line.clip(&square(0.0, 0.0, 4.0), ClipKeep::Both);
+        assert_eq!(result.inside.len(), 1);
+        assert_eq!(result.outside.len(), 1);
+        assert_eq!(
+            (result.inside[0].start_index, result.inside[0].end_index),
+            (0, 0)
+        );
+        assert_eq!(
+            (result.outside[0].start_index, result.outside[0].end_index),
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn test_closed_circle() {
+        // circle centered on the boundary corner, quarter of it inside
+        let circle = pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+        let result = circle.clip(&square(0.0, 0.0, 4.0), ClipKeep::Both);
+        assert_eq!(result.inside.len(), 1);
+        assert_eq!(result.outside.len(), 1);
+        let circumference = 2.0 * std::f64::consts::PI;
+        assert!(total_length(&result.inside).fuzzy_eq(circumference / 4.0));
+        assert!(total_length(&result.outside).fuzzy_eq(0.75 * circumference));
+        assert!(result.inside.iter().all(|p| !p.pline.is_closed()));
+
+        // closed polyline without intersects is returned whole
+        let small_circle = pline_closed![(1.0, 2.0, 1.0), (3.0, 2.0, 1.0)];
+        let result = small_circle.clip(&square(0.0, 0.0, 4.0), ClipKeep::Both);
+        assert_eq!(result.inside.len(), 1);
+        assert!(result.inside[0].pline.is_closed());
+        assert_eq!(
+            (result.inside[0].start_index, result.inside[0].end_index),
+            (0, 1)
+        );
+    }
+
+    #[test]
+    fn test_arc_crossing_boundary() {
+        // half circle arc bulging below the boundary, crosses the boundary four times
+        let arc = pline_open![(-0.5, 2.0, 1.0), (4.5, 2.0, 0.0)];
+        let result = arc.clip(&square(0.0, 0.0, 4.0), ClipKeep::Both);
+        assert_eq!(result.inside.len(), 2);
+        assert_eq!(result.outside.len(), 3);
+        assert!(
+            (total_length(&result.inside) + total_length(&result.outside))
+                .fuzzy_eq(arc.path_length())
+        );
+    }
+
+    #[test]
+    fn test_invalid_boundary() {
+        let line = pline_open![(-1.0, 2.0, 0.0), (5.0, 2.0, 0.0)];
+        let open_boundary = pline_open![(0.0, 0.0, 0.0), (0.0, 4.0, 0.0)];
+        let result = line.clip(&open_boundary, ClipKeep::Both);
+        assert!(result.inside.is_empty() && result.outside.is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+mod test_pline_convex_hull {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::math::Vector2;
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    /// Assert `hull` is a convex counter clockwise polyline containing all of `plines`.
+    fn assert_hull_of(hull: &Polyline, plines: &[Polyline]) {
+        assert!(hull.is_closed());
+        assert!(hull.area() > 0.0);
+        assert!(hull.iter_vertexes().all(|v| v.bulge >= 0.0));
+        for i in 0..hull.vertex_count() {
+            let prev = hull.at(hull.prev_wrapping_index(i));
+            let v = hull.at(i);
+            let next = hull.at(hull.next_wrapping_index(i));
+            let t_in = seg_tangent_vector(prev, v, v.pos()).normalize();
+            let t_out = seg_tangent_vector(v, next, v.pos()).normalize();
+            assert!(t_in.perp_dot(t_out) > -1e-6, "not convex at vertex {i}");
+        }
+
+        for pline in plines {
+            let points = pline
+                .iter_segments()
+                .flat_map(|(v1, v2)| [v1.pos(), seg_midpoint(v1, v2)])
+                .chain(pline.iter_vertexes().map(|v| v.pos()));
+            for p in points {
+                let inside = hull.winding_number(p) != 0;
+                let on_boundary = hull.closest_point(p, 1e-5).unwrap().distance < 1e-6;
+                assert!(inside || on_boundary, "{p:?} not in hull");
+            }
+        }
+    }
+
+    #[test]
+    fn points() {
+        assert_eq!(convex_hull_of_points::<f64>(&[], 1e-5).vertex_count(), 0);
+
+        let single = convex_hull_of_points(&[Vector2::new(1.0, 2.0)], 1e-5);
+        assert_eq!(single.vertex_count(), 1);
+
+        // interior, repeated, and collinear points are excluded
+        let points = [
+            Vector2::new(2.0, 2.0),
+            Vector2::new(0.0, 0.0),
+            Vector2::new(2.0, 0.0),
+            Vector2::new(4.0, 0.0),
+            Vector2::new(4.0, 4.0),
+            Vector2::new(4.0, 4.0),
+            Vector2::new(1.0, 3.0),
+            Vector2::new(0.0, 4.0),
+        ];
+        let hull = convex_hull_of_points(&points, 1e-5);
+        assert_eq!(hull.vertex_count(), 4);
+        assert!(hull.area().fuzzy_eq(16.0));
+        assert!(hull[0].pos().fuzzy_eq(Vector2::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn line_polyline() {
+        // L shape, hull cuts across the inside corner
+        let pline = pline_closed![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 1.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (1.0, 4.0, 0.0),
+            (0.0, 4.0, 0.0),
+        ];
+        let hull = pline.convex_hull();
+        assert_eq!(hull.vertex_count(), 5);
+        assert!(hull.area().fuzzy_eq(16.0 - 4.5));
+        assert_hull_of(&hull, &[pline]);
+    }
+
+    #[test]
+    fn pos_equal_eps_option() {
+        // square with a tiny chamfer on the top right corner
+        let pline = pline_closed![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 3.999, 0.0),
+            (3.999, 4.0, 0.0),
+            (0.0, 4.0, 0.0),
+        ];
+        let hull = pline.convex_hull();
+        assert_eq!(hull.vertex_count(), 5);
+        assert_hull_of(&hull, std::slice::from_ref(&pline));
+
+        // chamfer vertexes are equal positions with a larger epsilon
+        let options = PlineConvexHullOptions {
+            pos_equal_eps: 1e-2,
+        };
+        let hull = pline.convex_hull_opt(&options);
+        assert_eq!(hull.vertex_count(), 4);
+        assert!(hull.area().fuzzy_eq_eps(16.0, 1e-2));
+    }
+
+    #[test]
+    fn circles() {
+        let circle = pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+        let hull = circle.convex_hull();
+        assert_eq!(hull.vertex_count(), 2);
+        assert!(hull.area().fuzzy_eq(PI));
+
+        let mut cw_circle = circle.clone();
+        cw_circle.invert_direction_mut();
+        let hull = cw_circle.convex_hull();
+        assert!(hull.area().fuzzy_eq(PI));
+
+        // circle made of four quarter arcs
+        let bulge = (PI / 8.0).tan();
+        let circle = pline_closed![
+            (1.0, 0.0, bulge),
+            (0.0, 1.0, bulge),
+            (-1.0, 0.0, bulge),
+            (0.0, -1.0, bulge),
+        ];
+        let hull = circle.convex_hull();
+        assert!(hull.area().fuzzy_eq(PI));
+        assert_hull_of(&hull, &[circle]);
+    }
+
+    #[test]
+    fn arcs_on_hull() {
+        // bump on the right kept and notch in the top filled in
+        let pline = pline_closed![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 1.0),
+            (4.0, 4.0, 0.0),
+            (3.0, 4.0, -1.0),
+            (1.0, 4.0, 0.0),
+            (0.0, 4.0, 0.0),
+        ];
+        let hull = pline.convex_hull();
+        assert_eq!(hull.vertex_count(), 4);
+        assert!(hull.area().fuzzy_eq(16.0 + 2.0 * PI));
+        assert_hull_of(&hull, &[pline]);
+
+        // arc bulging below its end points, only part of the arc is on the hull with tangent lines
+        // to the end points of the other segments
+        let pline = pline_open![
+            (0.0, 0.0, 1.0),
+            (4.0, 0.0, 0.0),
+            (6.0, 3.0, 0.0),
+            (-2.0, 3.0, 0.0)
+        ];
+        let hull = pline.convex_hull();
+        assert_hull_of(&hull, std::slice::from_ref(&pline));
+        let arc_vertexes = hull.iter_vertexes().filter(|v| !v.bulge_is_zero()).count();
+        assert_eq!(arc_vertexes, 1);
+        // bottom of the half circle is on the hull
+        assert!(
+            hull.closest_point(Vector2::new(2.0, -2.0), 1e-5)
+                .unwrap()
+                .distance
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn collection_of_plines() {
+        let plines = vec![
+            pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)],
+            pline_closed![(3.0, 0.0, 1.0), (5.0, 0.0, 1.0)],
+            pline_open![(2.0, 3.0, 0.0), (2.0, 4.0, 0.0)],
+        ];
+        let hull = convex_hull_of_plines(&plines, 1e-5);
+        assert_hull_of(&hull, &plines);
+        // point at the top connects with tangent lines to both circles
+        assert!(
+            hull.iter_vertexes()
+                .any(|v| v.pos().fuzzy_eq(Vector2::new(2.0, 4.0)))
+        );
+
+        // two circles make a stadium
+        let hull = convex_hull_of_plines(&plines[..2], 1e-5);
+        assert_eq!(hull.vertex_count(), 4);
+        assert!(hull.area().fuzzy_eq(8.0 + PI));
+    }
+
+    #[test]
+    fn circle_inside_other() {
+        let plines = vec![
+            pline_closed![(-4.0, 0.0, 1.0), (4.0, 0.0, 1.0)],
+            pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)],
+            pline_open![(1.0, 1.0, 0.0), (2.0, 2.0, 0.0)],
+        ];
+        let hull = convex_hull_of_plines(&plines, 1e-5);
+        assert!(hull.area().fuzzy_eq(16.0 * PI));
+        assert_hull_of(&hull, &plines);
+    }
+}
@@ -0,0 +1,182 @@
+mod test_utils;
+
+mod test_pline_corners {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::math::Vector2;
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    use crate::test_utils::square;
+
+    /// Assert the segments meeting at every vertex (except the ends of open polylines) are tangent.
+    fn assert_tangent_continuous(pline: &Polyline) {
+        let start = usize::from(!pline.is_closed());
+        let end = if pline.is_closed() {
+            pline.vertex_count()
+        } else {
+            pline.vertex_count() - 1
+        };
+        for i in start..end {
+            let prev = pline.at(pline.prev_wrapping_index(i));
+            let v = pline.at(i);
+            let next = pline.at(pline.next_wrapping_index(i));
+            let t_in = seg_tangent_vector(prev, v, v.pos()).normalize();
+            let t_out = seg_tangent_vector(v, next, v.pos()).normalize();
+            assert!(
+                t_in.fuzzy_eq_eps(t_out, 1e-5),
+                "not tangent at vertex {i}: {t_in:?} != {t_out:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn fillet_line_line() {
+        let radius = 1.0;
+        let expected_area = 16.0 - 4.0 * (radius * radius) + PI * radius * radius;
+        let result = square(0.0, 0.0, 4.0).treat_corners(CornerTreatment::Fillet { radius });
+        assert_eq!(result.vertex_count(), 8);
+        assert!(result.area().fuzzy_eq(expected_area));
+        assert_tangent_continuous(&result);
+
+        // clockwise
+        let mut cw = square(0.0, 0.0, 4.0);
+        cw.invert_direction_mut();
+        let result = cw.treat_corners(CornerTreatment::Fillet { radius });
+        assert!(result.area().fuzzy_eq(-expected_area));
+        assert_tangent_continuous(&result);
+    }
+
+    #[test]
+    fn chamfer_line_line() {
+        let result =
+            square(0.0, 0.0, 4.0).treat_corners(CornerTreatment::Chamfer { distance: 1.0 });
+        assert_eq!(result.vertex_count(), 8);
+        assert!(result.iter_vertexes().all(|v| v.bulge_is_zero()));
+        assert!(result.area().fuzzy_eq(16.0 - 4.0 * 0.5));
+
+        // chamfers meeting at the middle of each side do not leave repeat vertexes
+        let result =
+            square(0.0, 0.0, 4.0).treat_corners(CornerTreatment::Chamfer { distance: 2.0 });
+        assert_eq!(result.vertex_count(), 4);
+        assert!(result.area().fuzzy_eq(8.0));
+    }
+
+    #[test]
+    fn non_finite_size_unchanged() {
+        let pline = square(0.0, 0.0, 4.0);
+        for size in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let result = pline.treat_corners(CornerTreatment::Chamfer { distance: size });
+            assert!(result.fuzzy_eq(&pline), "chamfer {size}");
+            let result = pline.treat_corners(CornerTreatment::Fillet { radius: size });
+            assert!(result.fuzzy_eq(&pline), "fillet {size}");
+        }
+    }
+
+    #[test]
+    fn fillet_line_arc() {
+        // half circle below the x axis closed by a line along the x axis
+        let d_shape = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 0.0)];
+        let result = d_shape.treat_corners(CornerTreatment::Fillet { radius: 0.2 });
+        assert_eq!(result.vertex_count(), 4);
+        assert_tangent_continuous(&result);
+        assert!(result.area() < d_shape.area());
+        assert!(result.area() > d_shape.area() - 0.1);
+        // fillet arcs stay inside the original shape
+        for v in result.iter_vertexes() {
+            assert!(v.y <= 1e-9);
+            assert!((v.pos() - Vector2::new(1.0, 0.0)).length() <= 1.0 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn fillet_arc_arc() {
+        // lens made of two arcs
+        let lens = pline_closed![(0.0, 0.0, 0.5), (4.0, 0.0, 0.5)];
+        let result = lens.treat_corners(CornerTreatment::Fillet { radius: 0.3 });
+        assert_eq!(result.vertex_count(), 4);
+        assert_tangent_continuous(&result);
+        assert!(result.area() < lens.area());
+
+        // convex corner between an arc bulging outward and a line
+        let pline = pline_open![(0.0, 0.0, -0.5), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0)];
+        let result = pline.treat_corners(CornerTreatment::Fillet { radius: 0.5 });
+        assert_eq!(result.vertex_count(), 4);
+        assert_tangent_continuous(&result);
+    }
+
+    #[test]
+    fn chamfer_arc() {
+        let d_shape = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 0.0)];
+        let distance = 0.25;
+        let result = d_shape.treat_corners(CornerTreatment::Chamfer { distance });
+        assert_eq!(result.vertex_count(), 4);
+        // chamfer lines from 0 to 1 and from 2 to 3, arc trimmed by the distance along the arc at
+        // both ends
+        assert!(result[0].bulge_is_zero() && result[2].bulge_is_zero());
+        let arc_length = seg_length(result[1], result[2]);
+        assert!(arc_length.fuzzy_eq(PI - 2.0 * distance));
+        let line_length = seg_length(result[3], result[0]);
+        assert!(line_length.fuzzy_eq(2.0 - 2.0 * distance));
+    }
+
+    #[test]
+    fn per_vertex_and_unfit_corners() {
+        // treatments overlapping along a side leave both corners sharp
+        let result = square(0.0, 0.0, 4.0).treat_corners(CornerTreatment::Fillet { radius: 3.0 });
+        assert!(result.fuzzy_eq(&square(0.0, 0.0, 4.0)));
+
+        // single large fillet fits
+        let treatments = [
+            CornerTreatment::Fillet { radius: 3.0 },
+            CornerTreatment::Sharp,
+            CornerTreatment::Sharp,
+            CornerTreatment::Sharp,
+        ];
+        let result = square(0.0, 0.0, 4.0).treat_corners_opt(
+            CornerTreatment::Sharp,
+            &PlineCornerOptions {
+                vertex_treatments: Some(&treatments),
+                ..Default::default()
+            },
+        );
+        assert_eq!(result.vertex_count(), 5);
+        assert!(result.area().fuzzy_eq(16.0 - 9.0 + 0.25 * PI * 9.0));
+
+        // vertexes past the end of the slice use the treatment given
+        let treatments = [CornerTreatment::Sharp];
+        let result = square(0.0, 0.0, 4.0).treat_corners_opt(
+            CornerTreatment::Chamfer { distance: 1.0 },
+            &PlineCornerOptions {
+                vertex_treatments: Some(&treatments),
+                ..Default::default()
+            },
+        );
+        assert_eq!(result.vertex_count(), 7);
+        assert!(result[0].pos().fuzzy_eq(Vector2::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn open_ends_and_tangent_vertexes_unchanged() {
+        // line tangent to the arc that follows it
+        let pline = pline_open![
+            (0.0, 0.0, 0.0),
+            (2.0, 0.0, 1.0),
+            (2.0, 2.0, 0.0),
+            (0.0, 2.0, 0.0),
+        ];
+        let result = pline.treat_corners(CornerTreatment::Fillet { radius: 0.5 });
+        assert!(result.fuzzy_eq(&pline));
+        let result = pline.treat_corners(CornerTreatment::Chamfer { distance: 0.5 });
+        assert!(result.fuzzy_eq(&pline));
+
+        let mut pline = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 2.0, 0.0)];
+        pline.set_userdata_values([5]);
+        let result = pline.treat_corners(CornerTreatment::Chamfer { distance: 0.5 });
+        assert_eq!(result.vertex_count(), 4);
+        assert!(result[0].pos().fuzzy_eq(Vector2::new(0.0, 0.0)));
+        assert!(result[3].pos().fuzzy_eq(Vector2::new(2.0, 2.0)));
+        assert_eq!(result.get_userdata_values().collect::<Vec<_>>(), vec![5]);
+    }
+}
@@ -0,0 +1,244 @@
+mod test_pline_distance {
+    use cavalier_contours::core::math::Vector2;
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    fn circle(radius: f64, center: Vector2) -> Polyline {
+        pline_closed![
+            (center.x - radius, center.y, 1.0),
+            (center.x + radius, center.y, 1.0),
+        ]
+    }
+
+    /// Closed polyline with lines and arcs of both directions.
+    fn mixed_shape() -> Polyline {
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (8.0, 0.0, 0.5),
+            (10.0, 4.0, 0.0),
+            (6.0, 6.0, -0.6),
+            (2.0, 7.0, 0.0),
+            (0.0, 4.0, 0.3),
+        ]
+    }
+
+    /// Wavy open polyline of arcs crossing over `mixed_shape`.
+    fn wavy_path() -> Polyline {
+        pline_open![
+            (-3.0, 9.0, 0.8),
+            (1.0, 10.0, -0.8),
+            (5.0, 9.0, 0.4),
+            (9.0, 8.5, -0.2),
+            (13.0, 5.0, 0.0),
+            (12.0, -2.0, 0.0),
+        ]
+    }
+
+    fn assert_on_pline(pline: &Polyline, seg_index: usize, point: Vector2) {
+        let v1 = pline[seg_index];
+        let v2 = pline[pline.next_wrapping_index(seg_index)];
+        let cp = seg_closest_point(v1, v2, point, 1e-5);
+        assert!(cp.fuzzy_eq_eps(point, 1e-5));
+    }
+
+    fn assert_result(pline: &Polyline, other: &Polyline, result: &PlineDistanceResult) {
+        assert_on_pline(pline, result.seg_index, result.point);
+        assert_on_pline(other, result.other_seg_index, result.other_point);
+        assert!(
+            (result.other_point - result.point)
+                .length()
+                .fuzzy_eq(result.distance)
+        );
+    }
+
+    /// Maximum distance from sampled points on `pline` to `other`.
+    fn sampled_hausdorff(pline: &Polyline, other: &Polyline) -> f64 {
+        pline
+            .sample_at_spacing(0.01)
+            .iter()
+            .map(|s| other.closest_point(s.point, 1e-5).unwrap().distance)
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn min_distance_arcs_and_lines() {
+        let c = circle(5.0, Vector2::zero());
+        let line = pline_open![(-10.0, 8.0, 0.0), (10.0, 8.0, 0.0)];
+        let result = c.min_distance(&line).unwrap();
+        assert!(result.distance.fuzzy_eq(3.0));
+        assert!(result.point.fuzzy_eq(Vector2::new(0.0, 5.0)));
+        assert_result(&c, &line, &result);
+        // other direction swaps the points
+        let result = line.min_distance(&c).unwrap();
+        assert!(result.distance.fuzzy_eq(3.0));
+        assert!(result.point.fuzzy_eq(Vector2::new(0.0, 8.0)));
+
+        // closest points on arc interiors along the line through the centers
+        let other = circle(2.0, Vector2::new(7.0, 7.0));
+        let result = c.min_distance(&other).unwrap();
+        let expected = 7.0 * 2.0_f64.sqrt() - 7.0;
+        assert!(result.distance.fuzzy_eq(expected));
+        let dir = Vector2::new(1.0, 1.0).normalize();
+        assert!(result.point.fuzzy_eq(dir.scale(5.0)));
+        assert_result(&c, &other, &result);
+
+        // concentric (no interior normal direction) and inside
+        let inner = circle(2.0, Vector2::zero());
+        assert!(c.min_distance(&inner).unwrap().distance.fuzzy_eq(3.0));
+
+        // intersecting
+        let crossing = pline_open![(0.0, 0.0, 0.0), (10.0, 1.0, 0.0)];
+        let result = c.min_distance(&crossing).unwrap();
+        assert!(result.distance.fuzzy_eq(0.0));
+        assert!(result.point.length().fuzzy_eq(5.0));
+    }
+
+    #[test]
+    fn min_distance_matches_brute_force() {
+        let shape = mixed_shape();
+        for offset in [0.5, 2.0, 5.0] {
+            let mut path = wavy_path();
+            path.translate_mut(0.0, offset);
+            let shape_index = shape.create_aabb_index();
+            let path_index = path.create_aabb_index();
+            let options = PlineDistanceOptions {
+                pline1_aabb_index: Some(&shape_index),
+                pline2_aabb_index: Some(&path_index),
+                ..Default::default()
+            };
+            let result = shape.min_distance_opt(&path, &options).unwrap();
+            assert_result(&shape, &path, &result);
+
+            let brute_force = shape
+                .sample_at_spacing(0.001)
+                .iter()
+                .map(|s| path.closest_point(s.point, 1e-5).unwrap().distance)
+                .fold(f64::MAX, f64::min);
+            assert!(result.distance <= brute_force + 1e-9);
+            assert!(result.distance.fuzzy_eq_eps(brute_force, 1e-3));
+            // same result without given indexes
+            let default = shape.min_distance(&path).unwrap();
+            assert!(default.distance.fuzzy_eq(result.distance));
+        }
+    }
+
+    #[test]
+    fn min_distance_single_vertex_and_empty() {
+        let c = circle(5.0, Vector2::zero());
+        let point = pline_open![(0.0, 9.0, 0.0)];
+        let result = c.min_distance(&point).unwrap();
+        assert!(result.distance.fuzzy_eq(4.0));
+        assert!(result.point.fuzzy_eq(Vector2::new(0.0, 5.0)));
+        assert_eq!(result.other_seg_index, 0);
+        let result = point.min_distance(&c).unwrap();
+        assert!(result.distance.fuzzy_eq(4.0));
+        assert!(result.other_point.fuzzy_eq(Vector2::new(0.0, 5.0)));
+        let other_point = pline_open![(3.0, 13.0, 0.0)];
+        assert!(
+            point
+                .min_distance(&other_point)
+                .unwrap()
+                .distance
+                .fuzzy_eq(5.0)
+        );
+
+        let empty = Polyline::new();
+        assert!(c.min_distance(&empty).is_none());
+        assert!(empty.min_distance(&c).is_none());
+        assert!(c.hausdorff_distance(&empty).is_none());
+        assert!(empty.symmetric_hausdorff_distance(&c).is_none());
+    }
+
+    #[test]
+    fn non_finite_vertexes() {
+        let c = circle(5.0, Vector2::zero());
+        let line = pline_open![(-10.0, 8.0, 0.0), (10.0, 8.0, 0.0)];
+        for bad in [
+            pline_open![(f64::NAN, 0.0, 0.0), (1.0, 0.0, 0.0)],
+            pline_open![(0.0, 0.0, 0.0), (1.0, f64::INFINITY, 0.0)],
+            pline_open![(0.0, 0.0, f64::NAN), (1.0, 0.0, 0.0)],
+            pline_open![(f64::NAN, f64::NAN, 0.0)],
+        ] {
+            assert!(c.min_distance(&bad).is_none());
+            assert!(bad.min_distance(&line).is_none());
+            assert!(c.hausdorff_distance(&bad).is_none());
+            assert!(bad.hausdorff_distance(&c).is_none());
+            assert!(bad.symmetric_hausdorff_distance(&line).is_none());
+        }
+    }
+
+    #[test]
+    fn hausdorff_exact_cases() {
+        let c = circle(5.0, Vector2::zero());
+        assert!(c.hausdorff_distance(&c).unwrap().distance.fuzzy_eq(0.0));
+
+        // offset circle is equally distant everywhere
+        let outer = circle(6.0, Vector2::zero());
+        let result = c.hausdorff_distance(&outer).unwrap();
+        assert!(result.distance.fuzzy_eq(1.0));
+        assert_result(&c, &outer, &result);
+        assert!(outer.hausdorff_distance(&c).unwrap().distance.fuzzy_eq(1.0));
+
+        // arc height in both directions (arc middle to chord and chord middle to arc)
+        let arc = pline_open![(-5.0, 0.0, -0.5), (5.0, 0.0, 0.0)];
+        let chord = pline_open![(-5.0, 0.0, 0.0), (5.0, 0.0, 0.0)];
+        let sagitta = 0.5 * 5.0;
+        let result = arc.hausdorff_distance(&chord).unwrap();
+        assert!(result.distance.fuzzy_eq_eps(sagitta, 1e-5));
+        assert!(result.point.fuzzy_eq_eps(Vector2::new(0.0, sagitta), 1e-2));
+        let result = chord.hausdorff_distance(&arc).unwrap();
+        assert!(result.distance.fuzzy_eq_eps(sagitta, 1e-5));
+        assert!(result.point.fuzzy_eq_eps(Vector2::zero(), 1e-2));
+    }
+
+    #[test]
+    fn hausdorff_matches_sampling() {
+        let shape = mixed_shape();
+        let mut path = wavy_path();
+        path.translate_mut(0.0, -2.0);
+        let tolerance = 1e-6;
+        let options = PlineDistanceOptions {
+            hausdorff_tolerance: tolerance,
+            ..Default::default()
+        };
+        for (a, b) in [(&shape, &path), (&path, &shape)] {
+            let result = a.hausdorff_distance_opt(b, &options).unwrap();
+            assert_result(a, b, &result);
+            let sampled = sampled_hausdorff(a, b);
+            // sampled is a lower bound and the result is within tolerance of the true maximum
+            assert!(result.distance >= sampled - tolerance);
+            assert!(result.distance.fuzzy_eq_eps(sampled, 1e-3));
+        }
+    }
+
+    #[test]
+    fn symmetric_hausdorff() {
+        let short = pline_open![(0.0, 0.0, 0.0), (5.0, 0.0, 0.0)];
+        let long = pline_open![(0.0, 1.0, 0.0), (10.0, 1.0, 0.0)];
+        let forward = short.hausdorff_distance(&long).unwrap();
+        assert!(forward.distance.fuzzy_eq(1.0));
+        let reverse = long.hausdorff_distance(&short).unwrap();
+        assert!(reverse.distance.fuzzy_eq(26.0_f64.sqrt()));
+        assert!(reverse.point.fuzzy_eq(Vector2::new(10.0, 1.0)));
+
+        // points are swapped so point is on short
+        let result = short.symmetric_hausdorff_distance(&long).unwrap();
+        assert!(result.distance.fuzzy_eq(reverse.distance));
+        assert!(result.point.fuzzy_eq(Vector2::new(5.0, 0.0)));
+        assert!(result.other_point.fuzzy_eq(Vector2::new(10.0, 1.0)));
+        assert_result(&short, &long, &result);
+
+        let short_index = short.create_aabb_index();
+        let long_index = long.create_aabb_index();
+        let options = PlineDistanceOptions {
+            pline1_aabb_index: Some(&short_index),
+            pline2_aabb_index: Some(&long_index),
+            ..Default::default()
+        };
+        let result = short
+            .symmetric_hausdorff_distance_opt(&long, &options)
+            .unwrap();
+        assert!(result.distance.fuzzy_eq(reverse.distance));
+    }
+}
@@ -0,0 +1,216 @@
+mod test_utils;
+
+mod test_pline_errors {
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::shape_algorithms::{Shape, ShapeOffsetOptions};
+    use cavalier_contours::{pline_closed, pline_open};
+
+    use crate::test_utils::square;
+
+    #[test]
+    fn offset_non_finite_input() {
+        let nan_coord = pline_closed![(0.0, 0.0, 0.0), (1.0, f64::NAN, 0.0), (0.0, 1.0, 0.0)];
+        assert_eq!(
+            nan_coord.try_parallel_offset(0.1).unwrap_err(),
+            PlineError::NonFiniteCoordinate { input: 0, index: 1 }
+        );
+
+        let inf_coord = pline_open![(f64::INFINITY, 0.0, 0.0), (1.0, 0.0, 0.0)];
+        assert_eq!(
+            inf_coord.try_parallel_offset(0.1).unwrap_err(),
+            PlineError::NonFiniteCoordinate { input: 0, index: 0 }
+        );
+
+        let nan_bulge = pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, f64::NAN)];
+        assert_eq!(
+            nan_bulge.try_parallel_offset(0.1).unwrap_err(),
+            PlineError::NonFiniteBulge { input: 0, index: 2 }
+        );
+
+        assert_eq!(
+            square(0.0, 0.0, 1.0)
+                .try_parallel_offset(f64::NAN)
+                .unwrap_err(),
+            PlineError::NonFiniteParameter { name: "offset" }
+        );
+    }
+
+    #[test]
+    fn offset_too_few_vertexes() {
+        let single = pline_open![(0.0, 0.0, 0.0)];
+        assert_eq!(
+            single.try_parallel_offset(0.1).unwrap_err(),
+            PlineError::TooFewVertexes {
+                input: 0,
+                count: 1,
+                required: 2
+            }
+        );
+    }
+
+    #[test]
+    fn offset_matches_parallel_offset() {
+        let pline = square(0.0, 0.0, 10.0);
+        let result = pline.try_parallel_offset(1.0).unwrap();
+        let expected = pline.parallel_offset(1.0);
+        assert_eq!(result.len(), expected.len());
+        assert!(result[0].area().fuzzy_eq(expected[0].area()));
+
+        // open polylines are valid offset input
+        let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        assert_eq!(line.try_parallel_offset(1.0).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn boolean_invalid_input() {
+        let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        assert_eq!(
+            line.try_boolean(&square(0.0, 0.0, 1.0), BooleanOp::Or)
+                .unwrap_err(),
+            PlineError::NotClosed { input: 0 }
+        );
+        assert_eq!(
+            square(0.0, 0.0, 1.0)
+                .try_boolean(&line, BooleanOp::Or)
+                .unwrap_err(),
+            PlineError::NotClosed { input: 1 }
+        );
+
+        let nan_pline = pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (f64::NAN, 1.0, 0.0)];
+        assert_eq!(
+            square(0.0, 0.0, 1.0)
+                .try_boolean(&nan_pline, BooleanOp::And)
+                .unwrap_err(),
+            PlineError::NonFiniteCoordinate { input: 1, index: 2 }
+        );
+
+        let empty = Polyline::<f64>::new_closed();
+        assert_eq!(
+            empty
+                .try_boolean(&square(0.0, 0.0, 1.0), BooleanOp::Not)
+                .unwrap_err(),
+            PlineError::TooFewVertexes {
+                input: 0,
+                count: 0,
+                required: 2
+            }
+        );
+    }
+
+    #[test]
+    fn boolean_matches_boolean() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(5.0, 5.0, 10.0);
+        for op in [
+            BooleanOp::Or,
+            BooleanOp::And,
+            BooleanOp::Not,
+            BooleanOp::Xor,
+        ] {
+            let result = a.try_boolean(&b, op).unwrap();
+            let expected = a.boolean(&b, op);
+            assert_eq!(result.pos_plines.len(), expected.pos_plines.len());
+            assert_eq!(result.neg_plines.len(), expected.neg_plines.len());
+            let area = |r: &BooleanResult<Polyline>| {
+                r.pos_plines.iter().map(|p| p.pline.area()).sum::<f64>()
+            };
+            assert!(area(&result).fuzzy_eq(area(&expected)));
+        }
+    }
+
+    #[test]
+    fn shape_invalid_input() {
+        let open = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        assert_eq!(
+            Shape::try_from_plines([square(0.0, 0.0, 10.0), open]).unwrap_err(),
+            PlineError::NotClosed { input: 1 }
+        );
+
+        let nan_pline = pline_closed![(0.0, 0.0, f64::NAN), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)];
+        assert_eq!(
+            Shape::try_from_plines([nan_pline]).unwrap_err(),
+            PlineError::NonFiniteBulge { input: 0, index: 0 }
+        );
+
+        // polylines with fewer than 2 vertexes are skipped the same as from_plines
+        let shape =
+            Shape::try_from_plines([square(0.0, 0.0, 10.0), Polyline::new_closed()]).unwrap();
+        assert_eq!(shape.ccw_plines.len(), 1);
+
+        let options = ShapeOffsetOptions::default();
+        assert_eq!(
+            shape
+                .try_parallel_offset(f64::INFINITY, &options)
+                .unwrap_err(),
+            PlineError::NonFiniteParameter { name: "offset" }
+        );
+
+        // loop indexes of other continue after the loops of self
+        let mut other = Shape::from_plines([square(20.0, 20.0, 10.0)]);
+        other.ccw_plines[0]
+            .polyline
+            .set_vertex(1, PlineVertex::new(f64::NAN, 20.0, 0.0));
+        assert_eq!(
+            shape.try_boolean(&other, BooleanOp::Or).unwrap_err(),
+            PlineError::NonFiniteCoordinate { input: 1, index: 1 }
+        );
+    }
+
+    #[test]
+    fn shape_matches_non_try() {
+        let mut island = square(3.0, 3.0, 4.0);
+        island.invert_direction_mut();
+        let plines = [square(0.0, 0.0, 10.0), island];
+        let shape = Shape::try_from_plines(plines.clone()).unwrap();
+        assert_eq!(shape.ccw_plines.len(), 1);
+        assert_eq!(shape.cw_plines.len(), 1);
+
+        let options = ShapeOffsetOptions::default();
+        let result = shape.try_parallel_offset(1.0, &options).unwrap();
+        let expected = Shape::from_plines(plines).parallel_offset(1.0, &options);
+        assert_eq!(result.ccw_plines.len(), expected.ccw_plines.len());
+        assert_eq!(result.cw_plines.len(), expected.cw_plines.len());
+
+        let other = Shape::from_plines([square(8.0, 8.0, 4.0)]);
+        let result = shape.try_boolean(&other, BooleanOp::Or).unwrap();
+        let expected = shape.boolean(&other, BooleanOp::Or);
+        assert_eq!(result.ccw_plines.len(), expected.ccw_plines.len());
+        assert_eq!(result.cw_plines.len(), expected.cw_plines.len());
+    }
+
+    #[test]
+    fn spatial_index_matches_non_try() {
+        let pline = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+        let index = pline.try_create_aabb_index().unwrap();
+        assert_eq!(index.bounds(), pline.create_aabb_index().bounds());
+        let index = pline.try_create_approx_aabb_index().unwrap();
+        assert_eq!(index.bounds(), pline.create_approx_aabb_index().bounds());
+        assert_eq!(index.count(), 3);
+        let single = pline_open![(0.0, 0.0, 0.0)];
+        assert_eq!(single.try_create_approx_aabb_index().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            PlineError::NonFiniteCoordinate { input: 1, index: 3 }.to_string(),
+            "vertex 3 of input 1 has a NaN or infinite coordinate"
+        );
+        assert_eq!(
+            PlineError::NotClosed { input: 0 }.to_string(),
+            "input 0 must be closed"
+        );
+        assert_eq!(
+            PlineError::NonFiniteParameter { name: "offset" }.to_string(),
+            "parameter `offset` is NaN or infinite"
+        );
+        assert_eq!(
+            PlineError::SpatialIndex {
+                message: "oops".to_owned()
+            }
+            .to_string(),
+            "failed to build spatial index: oops"
+        );
+    }
+}
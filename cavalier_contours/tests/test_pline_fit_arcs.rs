@@ -0,0 +1,127 @@
+mod test_pline_fit_arcs {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    /// Max distance from the points of `dense` to the path of `fitted`.
+    fn max_deviation(dense: &Polyline, fitted: &Polyline) -> f64 {
+        dense
+            .iter_vertexes()
+            .map(|v| fitted.closest_point(v.pos(), 1e-5).unwrap().distance)
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn empty_and_short_polylines() {
+        let empty = Polyline::<f64>::new();
+        assert_eq!(empty.fit_arcs(0.1).vertex_count(), 0);
+
+        let line = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)];
+        assert!(line.fit_arcs(0.1).fuzzy_eq(&line));
+    }
+
+    #[test]
+    fn collinear_lines_merged() {
+        let mut pline = Polyline::new();
+        for i in 0..=10 {
+            pline.add(f64::from(i), 0.0, 0.0);
+        }
+        let fitted = pline.fit_arcs(1e-3);
+        assert_eq!(fitted.vertex_count(), 2);
+        assert!(fitted.at(1).x.fuzzy_eq(10.0));
+        assert!(fitted.at(0).bulge_is_zero());
+    }
+
+    #[test]
+    fn dense_circle_to_arcs() {
+        let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+        let dense = circle.arcs_to_approx_lines(1e-4).unwrap();
+        assert!(dense.vertex_count() > 300);
+
+        let fitted = dense.fit_arcs(1e-3);
+        assert_eq!(fitted.vertex_count(), 2);
+        assert!(fitted.is_closed());
+        assert!(fitted.area().fuzzy_eq_eps(25.0 * PI, 1e-2));
+        assert!(max_deviation(&dense, &fitted) < 1e-3);
+
+        // clockwise
+        let mut dense_cw = dense.clone();
+        dense_cw.invert_direction_mut();
+        let fitted = dense_cw.fit_arcs(1e-3);
+        assert!(fitted.vertex_count() <= 3);
+        assert!(fitted.area().fuzzy_eq_eps(-25.0 * PI, 1e-2));
+    }
+
+    #[test]
+    fn mixed_lines_and_curves() {
+        // slot: two straight sides joined by half circle ends
+        let slot = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 1.0),
+            (10.0, 4.0, 0.0),
+            (0.0, 4.0, 1.0),
+        ];
+        let dense = slot.arcs_to_approx_lines(1e-4).unwrap();
+        let fitted = dense.fit_arcs(1e-3);
+        assert_eq!(fitted.vertex_count(), 4);
+        assert!(fitted.area().fuzzy_eq_eps(slot.area(), 1e-2));
+        assert!(fitted.path_length().fuzzy_eq_eps(slot.path_length(), 1e-2));
+        assert!(max_deviation(&dense, &fitted) < 1e-3);
+    }
+
+    #[test]
+    fn keeps_existing_arcs_and_end_points() {
+        let pline = pline_open![
+            (0.0, 0.0, 0.5),
+            (2.0, 0.0, 0.0),
+            (3.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+        ];
+        let fitted = pline.fit_arcs(1e-3);
+        assert_eq!(fitted.vertex_count(), 3);
+        assert!(fitted.at(0).fuzzy_eq(pline.at(0)));
+        assert!(fitted.at(1).fuzzy_eq(pline.at(1)));
+        assert!(fitted.at(2).fuzzy_eq(pline.at(3)));
+    }
+
+    #[test]
+    fn corners_not_rounded() {
+        let square = pline_closed![
+            (0.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (0.0, 1.0, 0.0),
+        ];
+        let fitted = square.fit_arcs(1e-3);
+        assert!(fitted.fuzzy_eq(&square));
+    }
+
+    #[test]
+    fn repeat_positions_skipped() {
+        // quarter circle of points with near repeat positions (including at the end of the run)
+        let mut dense = Polyline::new();
+        for i in 0..=16 {
+            let a = 0.5 * PI * f64::from(i) / 16.0;
+            dense.add(5.0 * a.cos(), 5.0 * a.sin(), 0.0);
+        }
+        let v = dense[8];
+        dense.insert(9, v.x + 1e-4, v.y - 1e-4, 0.0);
+        let end = dense[dense.vertex_count() - 1];
+        dense.add(end.x + 1e-4, end.y, 0.0);
+
+        let options = PlineFitArcsOptions {
+            pos_equal_eps: 1e-3,
+        };
+        let fitted = dense.fit_arcs_opt(0.05, &options);
+        assert_eq!(fitted.vertex_count(), 2);
+        let quarter_bulge = (PI / 8.0).tan();
+        assert!(fitted.at(0).bulge.fuzzy_eq_eps(quarter_bulge, 1e-3));
+        assert!(fitted.at(1).pos().fuzzy_eq(dense.last().unwrap().pos()));
+        assert!(max_deviation(&dense, &fitted) < 0.05);
+
+        // repeat positions are not skipped with the default epsilon
+        assert!(dense.fit_arcs(0.05).vertex_count() > 2);
+    }
+}
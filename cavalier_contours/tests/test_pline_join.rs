@@ -0,0 +1,128 @@
+mod test_pline_join {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    #[test]
+    fn empty_and_closed_input() {
+        assert!(join_plines::<Polyline, f64>(&[], 1e-5).is_empty());
+
+        let square = pline_closed![
+            (0.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (0.0, 1.0, 0.0),
+        ];
+        let single = pline_open![(5.0, 5.0, 0.0)];
+        let result = join_plines(&[square.clone(), single], 1e-5);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].fuzzy_eq(&square));
+    }
+
+    #[test]
+    fn joins_chain_with_reversal() {
+        let fragments = [
+            pline_open![(2.0, 0.0, 0.0), (3.0, 0.0, 0.0)],
+            pline_open![(1.0, 0.0, 0.0), (0.0, 0.0, 0.0)],
+            pline_open![(2.0, 0.0, 0.0), (1.0, 0.0, 0.0)],
+        ];
+        let result = join_plines(&fragments, 1e-5);
+        assert_eq!(result.len(), 1);
+        let chain = &result[0];
+        assert!(!chain.is_closed());
+        assert_eq!(chain.vertex_count(), 4);
+        assert!(chain.path_length().fuzzy_eq(3.0));
+        // first fragment keeps its direction, the others are reversed to follow it
+        let xs = chain.iter_vertexes().map(|v| v.x).collect::<Vec<_>>();
+        assert_eq!(xs, vec![0.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn reversed_arcs_keep_direction() {
+        // upper half circle clockwise from (-1, 0) to (1, 0), lower half given counter clockwise
+        // from (-1, 0) to (1, 0) so it must be reversed
+        let fragments = [
+            pline_open![(-1.0, 0.0, -1.0), (1.0, 0.0, 0.0)],
+            pline_open![(-1.0, 0.0, 1.0), (1.0, 0.0, 0.0)],
+        ];
+        let result = join_plines(&fragments, 1e-5);
+        assert_eq!(result.len(), 1);
+        let circle = &result[0];
+        assert!(circle.is_closed());
+        assert_eq!(circle.vertex_count(), 2);
+        assert!(circle.area().fuzzy_eq(-PI));
+        assert!(circle.path_length().fuzzy_eq(2.0 * PI));
+    }
+
+    #[test]
+    fn joins_within_eps() {
+        let fragments = [
+            pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)],
+            pline_open![(4.0, 4.0, 0.0), (4.000_001, 0.0, 0.0)],
+            pline_open![(0.0, 4.0, 0.0), (4.0, 4.0, 0.0)],
+            pline_open![(0.0, 4.0, 0.0), (0.0, 0.000_001, 0.0)],
+        ];
+        let result = join_plines(&fragments, 1e-5);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].is_closed());
+        assert_eq!(result[0].vertex_count(), 4);
+        assert!(result[0].area().fuzzy_eq_eps(16.0, 1e-4));
+
+        // eps too small to join
+        assert_eq!(join_plines(&fragments, 1e-8).len(), 2);
+    }
+
+    #[test]
+    fn separate_chains_and_loops() {
+        let mut fragments = vec![
+            // triangle loop
+            pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)],
+            pline_open![(1.0, 0.0, 0.0), (0.0, 1.0, 0.0)],
+            pline_open![(0.0, 1.0, 0.0), (0.0, 0.0, 0.0)],
+            // open chain
+            pline_open![(10.0, 0.0, 0.0), (11.0, 0.0, 0.0)],
+            pline_open![(12.0, 0.0, 0.0), (11.0, 0.0, 0.0)],
+            // single fragment closed by position
+            pline_open![
+                (20.0, 0.0, 0.0),
+                (21.0, 0.0, 0.0),
+                (21.0, 1.0, 0.0),
+                (20.0, 0.0, 0.0)
+            ],
+        ];
+        fragments[3].set_userdata_values([1]);
+        fragments[4].set_userdata_values([2]);
+
+        let result = join_plines(&fragments, 1e-5);
+        assert_eq!(result.len(), 3);
+        assert!(result[0].is_closed());
+        assert_eq!(result[0].vertex_count(), 3);
+        assert!(result[0].area().fuzzy_eq(0.5));
+        assert!(!result[1].is_closed());
+        assert_eq!(result[1].vertex_count(), 3);
+        assert_eq!(
+            result[1].get_userdata_values().collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert!(result[2].is_closed());
+        assert_eq!(result[2].vertex_count(), 3);
+    }
+
+    #[test]
+    fn extends_backwards_from_middle() {
+        // first fragment is in the middle of the chain
+        let fragments = [
+            pline_open![(1.0, 0.0, 0.0), (2.0, 0.0, 0.0)],
+            pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)],
+            pline_open![(-1.0, 0.0, 0.0), (0.0, 0.0, 0.0)],
+            pline_open![(3.0, 0.0, 0.0), (2.0, 0.0, 0.0)],
+        ];
+        let result = join_plines(&fragments, 1e-5);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].vertex_count(), 5);
+        assert!(result[0].at(0).x.fuzzy_eq(-1.0));
+        assert!(result[0].last().unwrap().x.fuzzy_eq(3.0));
+    }
+}
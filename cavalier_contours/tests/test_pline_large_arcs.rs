@@ -0,0 +1,283 @@
+mod test_utils;
+
+mod test_pline_large_arcs {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::{math::Vector2, traits::FuzzyEq};
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::shape_algorithms::{Shape, ShapeOffsetOptions};
+    use cavalier_contours::{pline_closed, pline_open};
+
+    use crate::test_utils::rectangle;
+
+    /// Circle of radius 5 centered at the origin made of a three quarter arc and a quarter arc.
+    fn large_arc_circle() -> Polyline {
+        pline_closed![
+            (5.0, 0.0, (3.0 * PI / 8.0).tan()),
+            (0.0, -5.0, (PI / 8.0).tan()),
+        ]
+    }
+
+    /// Same circle as [`large_arc_circle`] made of two half circle arcs.
+    fn half_arc_circle() -> Polyline {
+        pline_closed![(5.0, 0.0, 1.0), (-5.0, 0.0, 1.0)]
+    }
+
+    /// Unit radius three quarter arc counter clockwise from (1, 0) to (0, -1) closed by a line.
+    fn three_quarter_arc() -> Polyline {
+        pline_closed![(1.0, 0.0, (3.0 * PI / 8.0).tan()), (0.0, -1.0, 0.0)]
+    }
+
+    fn assert_same_areas(result: &[Polyline], expected: &[Polyline]) {
+        assert_eq!(result.len(), expected.len());
+        let mut result_areas = result.iter().map(PlineSource::area).collect::<Vec<_>>();
+        let mut expected_areas = expected.iter().map(PlineSource::area).collect::<Vec<_>>();
+        result_areas.sort_by(f64::total_cmp);
+        expected_areas.sort_by(f64::total_cmp);
+        for (r, e) in result_areas.iter().zip(&expected_areas) {
+            assert!(r.fuzzy_eq_eps(*e, 1e-5), "{r} != {e}");
+        }
+    }
+
+    #[test]
+    fn basic_properties() {
+        let circle = large_arc_circle();
+        assert!(circle.area().fuzzy_eq(25.0 * PI));
+        assert!(circle.path_length().fuzzy_eq(10.0 * PI));
+        let extents = circle.extents().unwrap();
+        assert!(extents.min_x.fuzzy_eq(-5.0) && extents.max_x.fuzzy_eq(5.0));
+        assert!(extents.min_y.fuzzy_eq(-5.0) && extents.max_y.fuzzy_eq(5.0));
+
+        for point in [
+            Vector2::new(0.0, 0.0),
+            Vector2::new(-4.0, 0.5),
+            Vector2::new(0.5, 4.0),
+            Vector2::new(-3.0, -3.0),
+            Vector2::new(3.0, -3.0),
+        ] {
+            assert_eq!(circle.winding_number(point), 1, "{point:?}");
+        }
+        assert_eq!(circle.winding_number(Vector2::new(-4.0, -4.0)), 0);
+
+        let closest = circle.closest_point(Vector2::new(-8.0, 0.0), 1e-5).unwrap();
+        assert!(closest.seg_point.fuzzy_eq(Vector2::new(-5.0, 0.0)));
+        assert!(closest.distance.fuzzy_eq(3.0));
+
+        let lines = circle.arcs_to_approx_lines(0.01).unwrap();
+        assert!((lines.area() - circle.area()).abs() < 0.5);
+    }
+
+    #[test]
+    fn split_large_arcs_keeps_path() {
+        let circle = large_arc_circle();
+        let split = circle.split_large_arcs(1e-5).unwrap();
+        assert_eq!(split.vertex_count(), 3);
+        assert!(split.iter_vertexes().all(|v| v.bulge.abs() <= 1.0));
+        assert!(split.area().fuzzy_eq(circle.area()));
+        assert!(split.path_length().fuzzy_eq(circle.path_length()));
+        assert!(half_arc_circle().split_large_arcs(1e-5).is_none());
+
+        // last vertex bulge of an open polyline is not a segment
+        let open = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 3.0)];
+        assert!(open.split_large_arcs(1e-5).is_none());
+
+        // arc with end points equal within the epsilon given is not split
+        let collapsed = pline_open![(0.0, 0.0, 3.0), (1e-6, 0.0, 0.0)];
+        assert!(collapsed.split_large_arcs(1e-5).is_none());
+        assert_eq!(collapsed.split_large_arcs(1e-8).unwrap().vertex_count(), 3);
+    }
+
+    #[test]
+    fn parallel_offset_matches_half_arcs() {
+        for offset in [-1.0, 1.0, 4.0] {
+            let result = large_arc_circle().parallel_offset(offset);
+            let expected = half_arc_circle().parallel_offset(offset);
+            assert_same_areas(&result, &expected);
+            for pline in &result {
+                assert!(pline.path_length().fuzzy_eq(2.0 * PI * (5.0 - offset)));
+            }
+        }
+
+        // distance at the split arc midpoint is interpolated
+        let result = large_arc_circle().variable_parallel_offset(&[-1.0, -1.0]);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].area().fuzzy_eq(36.0 * PI));
+
+        let open_arc = pline_open![(5.0, 0.0, (3.0 * PI / 8.0).tan()), (0.0, -5.0, 0.0)];
+        let result = open_arc.parallel_offset(1.0);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].path_length().fuzzy_eq(1.5 * PI * 4.0));
+    }
+
+    #[test]
+    fn boolean_matches_half_arcs() {
+        for op in [
+            BooleanOp::Or,
+            BooleanOp::And,
+            BooleanOp::Not,
+            BooleanOp::Xor,
+        ] {
+            let rectangle = rectangle(0.0, -2.0, 10.0, 2.0);
+            let result = large_arc_circle().boolean(&rectangle, op);
+            let expected = half_arc_circle().boolean(&rectangle, op);
+            let to_plines = |r: &BooleanResult<Polyline>| {
+                r.pos_plines
+                    .iter()
+                    .chain(&r.neg_plines)
+                    .map(|p| p.pline.clone())
+                    .collect::<Vec<_>>()
+            };
+            assert_same_areas(&to_plines(&result), &to_plines(&expected));
+        }
+    }
+
+    #[test]
+    fn contains_and_fill() {
+        let small = pline_closed![(-1.0, 3.0, 0.0), (1.0, 3.0, 0.0), (0.0, 4.0, 0.0)];
+        assert_eq!(
+            large_arc_circle().contains(&small),
+            PlineContainsResult::Pline2InsidePline1
+        );
+
+        let resolved: Vec<Polyline> = large_arc_circle().resolve_self_intersects(FillRule::NonZero);
+        assert_eq!(resolved.len(), 1);
+        assert!(resolved[0].area().fuzzy_eq(25.0 * PI));
+    }
+
+    #[test]
+    fn clip_reports_source_indexes() {
+        // three quarter arc from (5, 0) to (0, -5) then line back toward the origin
+        let pline = pline_open![
+            (5.0, 0.0, (3.0 * PI / 8.0).tan()),
+            (0.0, -5.0, 0.0),
+            (0.0, 0.0, 0.0),
+        ];
+        let boundary = pline_closed![
+            (-10.0, -1.0, 0.0),
+            (10.0, -1.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (-10.0, 10.0, 0.0),
+        ];
+        let result = pline.clip(&boundary, ClipKeep::Both);
+        assert_eq!(result.inside.len(), 2);
+        assert_eq!(result.outside.len(), 1);
+        assert_eq!(
+            (result.inside[0].start_index, result.inside[0].end_index),
+            (0, 0)
+        );
+        assert_eq!(
+            (result.outside[0].start_index, result.outside[0].end_index),
+            (0, 1)
+        );
+        assert_eq!(
+            (result.inside[1].start_index, result.inside[1].end_index),
+            (1, 1)
+        );
+        let total = result
+            .inside
+            .iter()
+            .chain(&result.outside)
+            .map(|p| p.pline.path_length())
+            .sum::<f64>();
+        assert!(total.fuzzy_eq(pline.path_length()));
+    }
+
+    #[test]
+    fn intersects_on_far_side() {
+        let arc = three_quarter_arc();
+        let x = 0.75f64.sqrt();
+        // line crossing the arc on both sides of the circle
+        let line = pline_open![(-2.0, 0.5, 0.0), (2.0, 0.5, 0.0)];
+        let intrs = arc.find_intersects(&line);
+        assert_eq!(intrs.basic_intersects.len(), 2);
+        assert!(intrs.overlapping_intersects.is_empty());
+        for expected in [Vector2::new(-x, 0.5), Vector2::new(x, 0.5)] {
+            assert!(
+                intrs
+                    .basic_intersects
+                    .iter()
+                    .any(|intr| intr.start_index1 == 0 && intr.point.fuzzy_eq(expected)),
+                "{expected:?}"
+            );
+        }
+        // same intersects with the polylines swapped
+        let intrs = line.find_intersects(&arc);
+        assert_eq!(intrs.basic_intersects.len(), 2);
+        assert!(intrs.basic_intersects.iter().all(|i| i.start_index2 == 0));
+
+        // line crossing the far side of the arc and the closing line segment
+        let line = pline_open![(-2.0, -0.5, 0.0), (2.0, -0.5, 0.0)];
+        let intrs = arc.find_intersects(&line);
+        assert_eq!(intrs.basic_intersects.len(), 2);
+        assert!(
+            intrs
+                .basic_intersects
+                .iter()
+                .any(|intr| intr.start_index1 == 0 && intr.point.fuzzy_eq(Vector2::new(-x, -0.5)))
+        );
+        assert!(
+            intrs
+                .basic_intersects
+                .iter()
+                .any(|intr| intr.start_index1 == 1 && intr.point.fuzzy_eq(Vector2::new(0.5, -0.5)))
+        );
+
+        // open polyline crossing back over the far side of its own arc
+        let pline = pline_open![
+            (1.0, 0.0, (3.0 * PI / 8.0).tan()),
+            (0.0, -1.0, 0.0),
+            (-1.0, 1.0, 0.0),
+        ];
+        assert!(pline.scan_for_self_intersect());
+        let mut intrs = Vec::new();
+        pline.visit_self_intersects(&mut |intr: PlineIntersect<f64>| intrs.push(intr));
+        assert_eq!(intrs.len(), 1);
+        let PlineIntersect::Basic(intr) = intrs[0] else {
+            panic!("expected basic intersect");
+        };
+        assert_eq!(
+            (
+                intr.start_index1.min(intr.start_index2),
+                intr.start_index1.max(intr.start_index2)
+            ),
+            (0, 1)
+        );
+        assert!(intr.point.fuzzy_eq(Vector2::new(-0.8, 0.6)));
+        assert!(!pline.validate(&PlineValidateOptions::default()).is_valid());
+    }
+
+    #[test]
+    fn clip_tiny_large_arc_with_small_eps() {
+        // arc end points are equal with the default fuzzy epsilon but not with the eps given
+        let pline = pline_closed![
+            (0.0, 0.0, 0.0),
+            (5.0, 0.0, 3.0),
+            (5.0 + 1e-9, 0.0, 0.0),
+            (5.0, 5.0, 0.0),
+        ];
+        let boundary = rectangle(-1.0, -1.0, 10.0, 10.0);
+        let clip_options = PlineClipOptions {
+            pos_equal_eps: 1e-10,
+            ..Default::default()
+        };
+        let result = pline.clip_opt(&boundary, ClipKeep::Both, &clip_options);
+        assert!(result.outside.is_empty());
+        assert_eq!(result.inside.len(), 1);
+        assert_eq!(result.inside[0].end_index, 3);
+    }
+
+    #[test]
+    fn shape_offset_matches_half_arcs() {
+        let shape = Shape::from_plines([large_arc_circle()]);
+        let expected = Shape::from_plines([half_arc_circle()]);
+        let result = shape.parallel_offset(1.0, &ShapeOffsetOptions::default());
+        let expected = expected.parallel_offset(1.0, &ShapeOffsetOptions::default());
+        assert_eq!(result.ccw_plines.len(), expected.ccw_plines.len());
+        assert!(
+            result.ccw_plines[0]
+                .polyline
+                .area()
+                .fuzzy_eq(expected.ccw_plines[0].polyline.area())
+        );
+    }
+}
@@ -0,0 +1,144 @@
+mod test_utils;
+
+mod test_pline_medial_axis {
+    use cavalier_contours::core::math::Vector2;
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::shape_algorithms::Shape;
+
+    use crate::test_utils::square;
+
+    fn nodes_with_degree(axis: &MedialAxis, degree: usize) -> Vec<MedialAxisPoint> {
+        (0..axis.nodes.len())
+            .filter(|&i| axis.node_degree(i) == degree)
+            .map(|i| axis.nodes[i])
+            .collect()
+    }
+
+    /// Assert every point of the medial axis is inside `boundary` with the clearance radius being
+    /// the distance to it.
+    fn assert_clearance(axis: &MedialAxis, boundary: &[&Polyline]) {
+        for p in axis.edges.iter().flat_map(|e| &e.points) {
+            let winding: i32 = boundary.iter().map(|b| b.winding_number(p.pos)).sum();
+            assert_ne!(winding, 0, "{:?} not inside", p.pos);
+            let distance = boundary
+                .iter()
+                .map(|b| b.closest_point(p.pos, 1e-5).unwrap().distance)
+                .fold(f64::MAX, f64::min);
+            assert!(p.radius.fuzzy_eq(distance));
+        }
+    }
+
+    #[test]
+    fn square_diagonals() {
+        let pline = square(0.0, 0.0, 10.0);
+        let axis = pline.medial_axis(0.1);
+        assert_eq!(axis.edges.len(), 4);
+        let center = nodes_with_degree(&axis, 4);
+        assert_eq!(center.len(), 1);
+        assert!(center[0].pos.fuzzy_eq_eps(Vector2::new(5.0, 5.0), 1e-3));
+        assert!(center[0].radius.fuzzy_eq_eps(5.0, 1e-3));
+
+        // branches end close to the corners
+        let ends = nodes_with_degree(&axis, 1);
+        assert_eq!(ends.len(), 4);
+        for end in ends {
+            assert!(end.radius < 0.2);
+        }
+        assert_clearance(&axis, &[&pline]);
+
+        // same result for clockwise direction
+        let mut cw = pline.clone();
+        cw.invert_direction_mut();
+        let cw_axis = cw.medial_axis(0.1);
+        assert_eq!(cw_axis.edges.len(), 4);
+        assert!(
+            cw_axis
+                .total_length()
+                .fuzzy_eq_eps(axis.total_length(), 1e-3)
+        );
+    }
+
+    #[test]
+    fn stroke_center_line() {
+        // stadium around a line of length 10, medial axis is the line between the cap centers
+        let path = cavalier_contours::pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        let outline = &path.stroke(1.0)[0];
+        let axis = outline.medial_axis(0.05);
+        assert_eq!(axis.edges.len(), 1);
+        let edge = &axis.edges[0];
+        assert!(edge.length().fuzzy_eq_eps(10.0, 0.1));
+        for p in &edge.points {
+            assert!(p.pos.y.fuzzy_eq_eps(0.0, 1e-3));
+            assert!(p.radius.fuzzy_eq_eps(1.0, 1e-3));
+        }
+        assert_eq!(edge.to_polyline().vertex_count(), edge.points.len());
+        assert_clearance(&axis, &[outline]);
+    }
+
+    #[test]
+    fn shape_with_hole() {
+        let outer = square(0.0, 0.0, 20.0);
+        let mut hole = square(5.0, 5.0, 10.0);
+        hole.invert_direction_mut();
+        let shape = Shape::from_plines([outer.clone(), hole.clone()]);
+        let axis = shape.medial_axis(0.1, &PlineMedialAxisOptions::default());
+
+        // loop around the hole with a branch to each outer corner
+        let junctions = nodes_with_degree(&axis, 3);
+        assert_eq!(junctions.len(), 4);
+        assert_eq!(nodes_with_degree(&axis, 1).len(), 4);
+        assert_eq!(axis.edges.len(), 8);
+
+        // midway between the sides
+        for p in axis.edges.iter().flat_map(|e| &e.points) {
+            if p.pos.x > 6.0 && p.pos.x < 14.0 && p.pos.y < 5.0 {
+                assert!(p.pos.y.fuzzy_eq_eps(2.5, 1e-3));
+                assert!(p.radius.fuzzy_eq_eps(2.5, 1e-3));
+            }
+        }
+        assert_clearance(&axis, &[&outer, &hole]);
+    }
+
+    #[test]
+    fn min_angle_removes_obtuse_corner_branches() {
+        // regular hexagon, interior angles of 120 degrees
+        let hexagon = (0..6)
+            .map(|i| {
+                let a = f64::from(i) * std::f64::consts::PI / 3.0;
+                PlineVertex::new(10.0 * a.cos(), 10.0 * a.sin(), 0.0)
+            })
+            .collect::<Vec<_>>();
+        let mut pline = Polyline::new_closed();
+        for v in hexagon {
+            pline.add_vertex(v);
+        }
+
+        let axis = pline.medial_axis(0.1);
+        assert_eq!(axis.edges.len(), 6);
+        assert_eq!(nodes_with_degree(&axis, 6).len(), 1);
+
+        // branches into corners with angle greater than 180 - 70 degrees are removed
+        let options = PlineMedialAxisOptions {
+            min_angle: 70f64.to_radians(),
+            ..Default::default()
+        };
+        let axis = pline.medial_axis_opt(0.1, &options);
+        assert!(axis.edges.is_empty());
+        assert_eq!(axis.nodes.len(), 1);
+        assert!(axis.nodes[0].pos.fuzzy_eq_eps(Vector2::zero(), 1e-3));
+    }
+
+    #[test]
+    fn empty_cases() {
+        let open = cavalier_contours::pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        let axis = open.medial_axis(0.1);
+        assert!(axis.nodes.is_empty() && axis.edges.is_empty());
+
+        let axis = square(0.0, 0.0, 10.0).medial_axis(0.0);
+        assert!(axis.nodes.is_empty() && axis.edges.is_empty());
+
+        let axis = Shape::<f64>::empty().medial_axis(0.1, &PlineMedialAxisOptions::default());
+        assert!(axis.nodes.is_empty() && axis.edges.is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+mod test_utils;
+
+mod test_pline_minkowski {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::math::Vector2;
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    use crate::test_utils::rectangle;
+
+    #[test]
+    fn line_path() {
+        let path = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+        let result = path.minkowski_sum(&rectangle(-1.0, -1.0, 1.0, 1.0));
+        assert_eq!(result.len(), 1);
+        assert!(result[0].is_closed());
+        // two 12 x 2 rectangles overlapping in a 2 x 2 square at the corner
+        assert!(result[0].area().fuzzy_eq(2.0 * 24.0 - 4.0));
+        assert_eq!(result[0].vertex_count(), 6);
+
+        // single vertex path is the shape moved to the vertex
+        let point = pline_open![(5.0, 5.0, 0.0)];
+        let result = point.minkowski_sum(&rectangle(-1.0, -0.5, 1.0, 0.5));
+        assert_eq!(result.len(), 1);
+        assert!(result[0].area().fuzzy_eq(2.0));
+        let extents = result[0].extents().unwrap();
+        assert!(extents.min_x.fuzzy_eq(4.0) && extents.max_y.fuzzy_eq(5.5));
+    }
+
+    #[test]
+    fn circle_shape_matches_stroke() {
+        let path = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+        let circle = pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+        let result = path.minkowski_sum(&circle);
+        let stroked = path.stroke(1.0);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].area().fuzzy_eq(stroked[0].area()));
+    }
+
+    #[test]
+    fn closed_path_has_hole() {
+        let path = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0),
+        ];
+        let mut cw_path = path.clone();
+        cw_path.invert_direction_mut();
+        for path in [path, cw_path] {
+            let result = path.minkowski_sum(&rectangle(-2.0, -1.0, 2.0, 1.0));
+            assert_eq!(result.len(), 2);
+            // outer 14 x 12 and inner 6 x 8
+            assert!(result[0].area().fuzzy_eq(14.0 * 12.0));
+            assert!(result[1].area().fuzzy_eq(-6.0 * 8.0));
+        }
+    }
+
+    #[test]
+    fn arc_path_with_polygon_shape() {
+        // circle of radius 5 swept by a 2 x 2 square
+        let path = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+        let square = rectangle(-1.0, -1.0, 1.0, 1.0);
+        let result = path.minkowski_sum(&square);
+        assert_eq!(result.len(), 2);
+        // outer boundary is the square grown by the circle, area + perimeter * r + PI * r^2
+        assert!(result[0].area().fuzzy_eq(4.0 + 8.0 * 5.0 + 25.0 * PI));
+        assert!(result[0].iter_vertexes().any(|v| !v.bulge_is_zero()));
+        // inner boundary is where the furthest square corner touches the circle
+        assert!(result[1].area() < 0.0);
+        for v in result[1].iter_vertexes() {
+            let furthest = square
+                .iter_vertexes()
+                .map(|k| (v.pos() + k.pos()).length())
+                .fold(0.0, f64::max);
+            assert!(furthest.fuzzy_eq_eps(5.0, 1e-5));
+        }
+
+        // half circle, swept region contains the square at every position along the arc
+        let path = pline_open![(-5.0, 0.0, -1.0), (5.0, 0.0, 0.0)];
+        let result = path.minkowski_sum(&square);
+        assert_eq!(result.len(), 1);
+        for i in 0..=32 {
+            let a = PI - PI * f64::from(i) / 32.0;
+            let p = Vector2::new(5.0 * a.cos(), 5.0 * a.sin());
+            for k in square.iter_vertexes() {
+                let q = p + k.pos();
+                let inside = result[0].winding_number(q) != 0;
+                let on_boundary = result[0].closest_point(q, 1e-5).unwrap().distance < 1e-6;
+                assert!(inside || on_boundary, "{q:?} not in result");
+            }
+        }
+    }
+
+    #[test]
+    fn arc_path_with_arc_shape() {
+        // circle of radius 5 swept by a circle of radius 1 is an annulus
+        let path = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+        let circle = pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+        let result = path.minkowski_sum_opt(
+            &circle,
+            &PlineMinkowskiOptions {
+                arc_approx_error: 1e-4,
+                ..Default::default()
+            },
+        );
+        assert_eq!(result.len(), 2);
+        let area = result[0].area() + result[1].area();
+        assert!(f64::abs(area - 20.0 * PI) < 1e-2);
+    }
+
+    #[test]
+    fn non_convex_and_degenerate_shapes() {
+        let path = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        // L shape is swept as its convex hull
+        let l_shape = pline_closed![
+            (0.0, 0.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (2.0, 1.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (1.0, 2.0, 0.0),
+            (0.0, 2.0, 0.0),
+        ];
+        let result = path.minkowski_sum(&l_shape);
+        assert_eq!(result.len(), 1);
+        let hull_area = l_shape.convex_hull().area();
+        assert!(result[0].area().fuzzy_eq(hull_area + 10.0 * 2.0));
+
+        let line_shape = pline_open![(0.0, 0.0, 0.0), (0.0, 1.0, 0.0)];
+        assert!(path.minkowski_sum(&line_shape).is_empty());
+        assert!(Polyline::new().minkowski_sum(&l_shape).is_empty());
+
+        let mut path = path;
+        path.set_userdata_values([3]);
+        let result = path.minkowski_sum(&l_shape);
+        assert_eq!(result[0].get_userdata_values().collect::<Vec<_>>(), vec![3]);
+    }
+}
@@ -1,7 +1,8 @@
 mod test_utils;
 
 use cavalier_contours::polyline::{
-    CoincidentSegmentBehavior, PlineOffsetOptions, PlineSource, Polyline, TouchingLoopBehavior,
+    CoincidentSegmentBehavior, OffsetCapType, OffsetJoinType, PlineOffsetOptions, PlineSource,
+    PlineSourceMut, Polyline, TouchingLoopBehavior,
 };
 use test_utils::{
     ModifiedPlineSet, ModifiedPlineSetVisitor, ModifiedPlineState, PlineProperties,
@@ -165,6 +166,318 @@ macro_rules! declare_self_intersecting_offset_tests {
     };
 }
 
+fn join_type_options(join_type: OffsetJoinType) -> PlineOffsetOptions<'static, f64> {
+    PlineOffsetOptions {
+        join_type,
+        ..Default::default()
+    }
+}
+
+/// Test cases for miter and bevel joins.
+mod test_join_types {
+    use super::*;
+    use cavalier_contours::{pline_closed_userdata, pline_open_userdata};
+
+    const MITER: OffsetJoinType = OffsetJoinType::Miter { limit: 2.0 };
+
+    declare_offset_tests!(
+        closed_rectangle_outward_miter {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, join_type_options(MITER)) =>
+            [PlineProperties::new(4, 336.0, 76.0, -2.0, -2.0, 22.0, 12.0, vec![4])]
+        }
+        closed_rectangle_outward_bevel {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, join_type_options(OffsetJoinType::Bevel)) =>
+            [PlineProperties::new(8, 328.0, 71.31370849898476, -2.0, -2.0, 22.0, 12.0, vec![4])]
+        }
+        closed_rectangle_outward_miter_over_limit {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, join_type_options(OffsetJoinType::Miter { limit: 1.2 })) =>
+            [PlineProperties::new(8, 328.0, 71.31370849898476, -2.0, -2.0, 22.0, 12.0, vec![4])]
+        }
+        closed_rectangle_inward_miter {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], 2.0, join_type_options(MITER)) =>
+            [PlineProperties::new(4, 96.0, 44.0, 2.0, 2.0, 18.0, 8.0, vec![4])]
+        }
+        closed_line_arc_outward_miter {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, join_type_options(MITER)) =>
+            [PlineProperties::new(4, 244.96902001294993, 59.99114857512855, -2.0, -2.0, 17.0, 12.0, vec![4])]
+        }
+        open_corner_outward_miter {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0) ], -2.0, join_type_options(MITER)) =>
+            [PlineProperties::new(3, 0.0, 24.0, 0.0, -2.0, 12.0, 10.0, vec![4])]
+        }
+        open_corner_outward_bevel {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0) ], -2.0, join_type_options(OffsetJoinType::Bevel)) =>
+            [PlineProperties::new(4, 0.0, 22.82842712474619, 0.0, -2.0, 12.0, 10.0, vec![4])]
+        }
+        open_corner_inward_bevel {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0) ], 2.0, join_type_options(OffsetJoinType::Bevel)) =>
+            [PlineProperties::new(3, 0.0, 16.0, 0.0, 2.0, 8.0, 10.0, vec![4])]
+        }
+    );
+}
+
+fn cap_type_options(cap_type: OffsetCapType) -> PlineOffsetOptions<'static, f64> {
+    PlineOffsetOptions {
+        cap_type,
+        ..Default::default()
+    }
+}
+
+/// Test cases for end caps on open polylines.
+mod test_cap_types {
+    use super::*;
+    use cavalier_contours::{pline_closed_userdata, pline_open_userdata};
+
+    declare_offset_tests!(
+        u_turn_round_cap {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 1.0, 0.0), (-5.0, 1.0, 0.0)], 0.8, cap_type_options(OffsetCapType::Round)) =>
+            [PlineProperties::new(2, 0.0, 4.2254033307585175, -5.0, 0.2, -0.7745966692414825, 0.2, vec![4])]
+        }
+        u_turn_butt_cap {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 1.0, 0.0), (-5.0, 1.0, 0.0)], 0.8, cap_type_options(OffsetCapType::Butt)) =>
+            [PlineProperties::new(2, 0.0, 5.0, -5.0, 0.2, 0.0, 0.2, vec![4])]
+        }
+        u_turn_square_cap {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 1.0, 0.0), (-5.0, 1.0, 0.0)], 0.8, cap_type_options(OffsetCapType::Square)) =>
+            [PlineProperties::new(2, 0.0, 4.2, -5.0, 0.2, -0.8, 0.2, vec![4])]
+        }
+        u_turn_outer_side_unaffected_by_cap {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 1.0, 0.0), (-5.0, 1.0, 0.0)], -0.8, cap_type_options(OffsetCapType::Butt)) =>
+            [PlineProperties::new(6, 0.0, 28.513274122871834, -5.0, -0.8, 10.8, 1.8, vec![4])]
+        }
+        closed_ignores_cap {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], 2.0, cap_type_options(OffsetCapType::Butt)) =>
+            [PlineProperties::new(4, 96.0, 44.0, 2.0, 2.0, 18.0, 8.0, vec![4])]
+        }
+    );
+}
+
+/// Test cases for stroke outlines.
+mod test_stroke {
+    use super::*;
+    use cavalier_contours::{pline_closed_userdata, pline_open_userdata};
+
+    fn run_stroke_test(
+        input: &Polyline<f64>,
+        distance: f64,
+        options: &PlineOffsetOptions<'_, f64>,
+        expected_properties_set: &[PlineProperties],
+    ) {
+        // stroke outline does not depend on source direction
+        let mut inverted = input.clone();
+        inverted.invert_direction_mut();
+        for pline in [input, &inverted] {
+            let results = pline.stroke_opt(distance, options);
+            assert!(results.iter().all(PlineSource::is_closed));
+            let result_set = create_property_set(&results, false);
+            assert!(
+                property_sets_match(&result_set, expected_properties_set),
+                "property sets do not match"
+            );
+        }
+    }
+
+    #[test]
+    fn line_caps() {
+        let line = pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        run_stroke_test(
+            &line,
+            1.0,
+            &cap_type_options(OffsetCapType::Round),
+            &[PlineProperties::new(
+                4,
+                20.0 + std::f64::consts::PI,
+                20.0 + 2.0 * std::f64::consts::PI,
+                -1.0,
+                -1.0,
+                11.0,
+                1.0,
+                vec![4],
+            )],
+        );
+        run_stroke_test(
+            &line,
+            1.0,
+            &cap_type_options(OffsetCapType::Square),
+            &[PlineProperties::new(
+                4,
+                24.0,
+                28.0,
+                -1.0,
+                -1.0,
+                11.0,
+                1.0,
+                vec![4],
+            )],
+        );
+        run_stroke_test(
+            &line,
+            1.0,
+            &cap_type_options(OffsetCapType::Butt),
+            &[PlineProperties::new(
+                4,
+                20.0,
+                24.0,
+                0.0,
+                -1.0,
+                10.0,
+                1.0,
+                vec![4],
+            )],
+        );
+    }
+
+    #[test]
+    fn corner_joins() {
+        let corner =
+            pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+        run_stroke_test(
+            &corner,
+            1.0,
+            &PlineOffsetOptions {
+                cap_type: OffsetCapType::Butt,
+                join_type: OffsetJoinType::Miter { limit: 2.0 },
+                ..Default::default()
+            },
+            &[PlineProperties::new(
+                6,
+                40.0,
+                44.0,
+                0.0,
+                -1.0,
+                11.0,
+                10.0,
+                vec![4],
+            )],
+        );
+        run_stroke_test(
+            &corner,
+            1.0,
+            &PlineOffsetOptions {
+                cap_type: OffsetCapType::Butt,
+                join_type: OffsetJoinType::Bevel,
+                ..Default::default()
+            },
+            &[PlineProperties::new(
+                7,
+                39.5,
+                43.41421356237309,
+                0.0,
+                -1.0,
+                11.0,
+                10.0,
+                vec![4],
+            )],
+        );
+        run_stroke_test(
+            &corner,
+            1.0,
+            &cap_type_options(OffsetCapType::Round),
+            &[PlineProperties::new(
+                7,
+                42.926990816987235,
+                45.853981633974485,
+                -1.0,
+                -1.0,
+                11.0,
+                11.0,
+                vec![4],
+            )],
+        );
+    }
+
+    #[test]
+    fn arc_caps() {
+        let arc = pline_open_userdata![[4], (0.0, 0.0, 1.0), (2.0, 0.0, 0.0)];
+        run_stroke_test(
+            &arc,
+            0.5,
+            &cap_type_options(OffsetCapType::Butt),
+            &[PlineProperties::new(
+                4,
+                std::f64::consts::PI,
+                2.0 + 2.0 * std::f64::consts::PI,
+                -0.5,
+                -1.5,
+                2.5,
+                0.0,
+                vec![4],
+            )],
+        );
+        run_stroke_test(
+            &arc,
+            0.5,
+            &cap_type_options(OffsetCapType::Square),
+            &[PlineProperties::new(
+                8,
+                std::f64::consts::PI + 1.0,
+                4.0 + 2.0 * std::f64::consts::PI,
+                -0.5,
+                -1.5,
+                2.5,
+                0.5,
+                vec![4],
+            )],
+        );
+    }
+
+    #[test]
+    fn open_ring_has_hole() {
+        let ring: Polyline<f64> = pline_open_userdata![
+            [4],
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0),
+            (0.0, 1.0, 0.0)
+        ];
+        let results = ring.stroke(1.0);
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().any(|r| r.area() > 140.0));
+        assert!(
+            results
+                .iter()
+                .any(|r| (r.area() + 64.0).abs() < PlineProperties::PROP_CMP_EPS)
+        );
+    }
+
+    #[test]
+    fn closed_outer_ccw_and_inner_cw() {
+        let square = pline_closed_userdata![
+            [4],
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        run_stroke_test(
+            &square,
+            1.0,
+            &cap_type_options(OffsetCapType::Butt),
+            &[
+                PlineProperties::new(
+                    8,
+                    140.0 + std::f64::consts::PI,
+                    40.0 + 2.0 * std::f64::consts::PI,
+                    -1.0,
+                    -1.0,
+                    11.0,
+                    11.0,
+                    vec![4],
+                ),
+                PlineProperties::new(4, -64.0, 32.0, 1.0, 1.0, 9.0, 9.0, vec![4]),
+            ],
+        );
+    }
+
+    #[test]
+    fn non_positive_distance_is_empty() {
+        let line = pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        assert!(line.stroke(0.0).is_empty());
+        assert!(line.stroke(-1.0).is_empty());
+    }
+}
+
 /// Simple/basic test cases for parallel offset (e.g. circles and rectangles).
 mod test_simple {
     use super::*;
@@ -2125,3 +2438,192 @@ fn coincident_discard_does_not_remove_a_point_only_tangent() {
     assert_eq!(result[0].vertex_count(), 9);
     assert!(result[0].scan_for_self_intersect());
 }
+
+mod test_variable_offset {
+    use cavalier_contours::core::{math::Vector2, traits::FuzzyEq};
+    use cavalier_contours::polyline::{PlineSource, PlineVariableOffsetOptions, Polyline};
+    use cavalier_contours::{pline_closed, pline_open};
+
+    fn assert_matches_parallel_offset(pline: &Polyline<f64>, dist: f64) {
+        let expected = pline.parallel_offset(dist);
+        let distances = vec![dist; pline.vertex_count()];
+        let result = pline.variable_parallel_offset(&distances);
+        assert_eq!(result.len(), expected.len());
+        let mut result_props = result
+            .iter()
+            .map(|p| (p.area(), p.path_length()))
+            .collect::<Vec<_>>();
+        let mut expected_props = expected
+            .iter()
+            .map(|p| (p.area(), p.path_length()))
+            .collect::<Vec<_>>();
+        result_props.sort_by(|a, b| a.1.total_cmp(&b.1));
+        expected_props.sort_by(|a, b| a.1.total_cmp(&b.1));
+        for (r, e) in result_props.iter().zip(&expected_props) {
+            assert!(r.0.fuzzy_eq_eps(e.0, 1e-5), "area {} != {}", r.0, e.0);
+            assert!(r.1.fuzzy_eq_eps(e.1, 1e-5), "length {} != {}", r.1, e.1);
+        }
+    }
+
+    #[test]
+    fn constant_distances_match_parallel_offset() {
+        let circle = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+        assert_matches_parallel_offset(&circle, 2.0);
+        assert_matches_parallel_offset(&circle, -2.0);
+
+        let rounded_rectangle = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 1.0),
+            (10.0, 4.0, 0.0),
+            (0.0, 4.0, 0.0),
+        ];
+        assert_matches_parallel_offset(&rounded_rectangle, 1.0);
+        assert_matches_parallel_offset(&rounded_rectangle, -1.0);
+
+        let zigzag = pline_open![
+            (0.0, 0.0, 0.0),
+            (5.0, 5.0, 0.0),
+            (10.0, 0.0, 0.5),
+            (15.0, 0.0, 0.0),
+        ];
+        assert_matches_parallel_offset(&zigzag, 1.0);
+        assert_matches_parallel_offset(&zigzag, -1.0);
+    }
+
+    #[test]
+    fn tapered_line_is_exact() {
+        let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        let result = line.variable_parallel_offset(&[-1.0, -3.0]);
+        assert_eq!(result.len(), 1);
+        let offset = &result[0];
+        assert!(!offset.is_closed());
+        assert_eq!(offset.vertex_count(), 2);
+        // line tangent to both end circles, normal tilted back by the distance slope of 0.2
+        let cos = 0.96f64.sqrt();
+        assert!(offset.at(0).pos().fuzzy_eq(Vector2::new(-0.2, -cos)));
+        assert!(offset.at(1).pos().fuzzy_eq(Vector2::new(9.4, -3.0 * cos)));
+        assert!(offset.at(0).bulge.fuzzy_eq(0.0));
+    }
+
+    #[test]
+    fn tapered_open_corner() {
+        let corner = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+        // left side is the inner corner
+        let inner = corner.variable_parallel_offset(&[0.5, 1.0, 2.0]);
+        assert_eq!(inner.len(), 1);
+        assert!(!inner[0].is_closed());
+        assert!(!inner[0].scan_for_self_intersect());
+        assert!(inner[0].path_length() < 20.0);
+        // right side is the outer corner, joined with an arc
+        let outer = corner.variable_parallel_offset(&[-0.5, -1.0, -2.0]);
+        assert_eq!(outer.len(), 1);
+        assert!(!outer[0].is_closed());
+        assert_eq!(outer[0].vertex_count(), 4);
+        assert!(outer[0].at(1).bulge > 0.0);
+        assert!(outer[0].path_length() > 20.0);
+    }
+
+    #[test]
+    fn tapered_closed_rectangle() {
+        let rectangle = pline_closed![
+            (0.0, 0.0, 0.0),
+            (20.0, 0.0, 0.0),
+            (20.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0),
+        ];
+        let options = PlineVariableOffsetOptions::default();
+        // inward offset, distance 1 on the left side and 3 on the right side
+        let result = rectangle.variable_parallel_offset_opt(&[1.0, 3.0, 3.0, 1.0], &options);
+        assert_eq!(result.len(), 1);
+        let inner = &result[0];
+        assert!(inner.is_closed());
+        assert_eq!(inner.vertex_count(), 4);
+        assert!(!inner.scan_for_self_intersect());
+        for v in inner.iter_vertexes() {
+            assert!(v.bulge.fuzzy_eq(0.0));
+            assert!(v.x.fuzzy_eq(1.0) || v.x.fuzzy_eq(17.0));
+        }
+        // symmetric about the horizontal center line
+        assert!((inner.at(0).y + inner.at(3).y).fuzzy_eq(10.0));
+        assert!((inner.at(1).y + inner.at(2).y).fuzzy_eq(10.0));
+
+        // outward offset has round joins
+        let result = rectangle.variable_parallel_offset(&[-1.0, -3.0, -3.0, -1.0]);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].is_closed());
+        assert!(result[0].area() > 200.0 + 2.0 * 20.0 * 2.0);
+        assert!(result[0].vertex_count() > 4);
+    }
+
+    #[test]
+    fn tapered_circle_is_approximated() {
+        let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+        // outward offset growing from 1 to 2 and back
+        let result = circle.variable_parallel_offset(&[-1.0, -2.0]);
+        assert_eq!(result.len(), 1);
+        let outer = &result[0];
+        assert!(outer.is_closed());
+        assert!(outer.vertex_count() > 2);
+        assert!(outer.iter_vertexes().all(|v| v.bulge > 0.0));
+        let pi = std::f64::consts::PI;
+        assert!(outer.area() > pi * 36.0 && outer.area() < pi * 49.0);
+        for v in outer.iter_vertexes() {
+            let dist = v.pos().length() - 5.0;
+            assert!((1.0 - 1e-5..=2.0 + 1e-5).contains(&dist));
+        }
+    }
+
+    #[test]
+    fn spiral_error_tol_controls_arc_count() {
+        let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+        let offset_with_tol = |tol: f64| {
+            let options = PlineVariableOffsetOptions {
+                spiral_error_tol: tol,
+                ..Default::default()
+            };
+            let result = circle.variable_parallel_offset_opt(&[-1.0, -2.0], &options);
+            assert_eq!(result.len(), 1);
+            result.into_iter().next().unwrap()
+        };
+        let coarse = offset_with_tol(1e-2);
+        let fine = offset_with_tol(1e-6);
+        assert!(coarse.vertex_count() < fine.vertex_count());
+
+        // exact envelope point at the top of the circle (half way between the vertexes) is at
+        // distance 1.5 from the circle with the normal tilted by the distance slope
+        let slope = 1.0 / (5.0 * std::f64::consts::PI);
+        let top = Vector2::new(-slope * 1.5, 5.0 + (1.0 - slope * slope).sqrt() * 1.5);
+        for (pline, tol) in [(&coarse, 1e-2), (&fine, 1e-6)] {
+            let closest = pline.closest_point(top, 1e-5).unwrap();
+            assert!(
+                closest.distance <= tol + 1e-9,
+                "{} > {tol}",
+                closest.distance
+            );
+        }
+    }
+
+    #[test]
+    fn zero_distances_return_copy() {
+        let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 1.0)];
+        let result = line.variable_parallel_offset(&[0.0, 0.0, 0.0]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].vertex_count(), line.vertex_count());
+        assert!(!result[0].is_closed());
+        for (v, expected) in result[0].iter_vertexes().zip(line.iter_vertexes()) {
+            assert!(v.fuzzy_eq(expected));
+        }
+    }
+
+    #[test]
+    fn invalid_distances_return_empty() {
+        let line = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+        // wrong count
+        assert!(line.variable_parallel_offset(&[1.0, 1.0]).is_empty());
+        // mixed signs
+        assert!(line.variable_parallel_offset(&[1.0, -1.0, 1.0]).is_empty());
+        // single vertex
+        let point = pline_open![(0.0, 0.0, 0.0)];
+        assert!(point.variable_parallel_offset(&[1.0]).is_empty());
+    }
+}
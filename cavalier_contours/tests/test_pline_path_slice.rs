@@ -0,0 +1,213 @@
+mod test_utils;
+
+mod test_pline_path_slice {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::math::Vector2;
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    use crate::test_utils::{line_and_arc, square};
+
+    fn assert_slice(
+        view: Option<PlineView<'_, Polyline>>,
+        length: f64,
+        start: Vector2,
+        end: Vector2,
+    ) -> Polyline {
+        let slice = Polyline::create_from(&view.unwrap());
+        assert!(!slice.is_closed());
+        assert!(slice.path_length().fuzzy_eq(length));
+        assert!(slice[0].pos().fuzzy_eq(start));
+        assert!(slice.last().unwrap().pos().fuzzy_eq(end));
+        slice
+    }
+
+    #[test]
+    fn open_slices() {
+        let pline = line_and_arc();
+        let total = 10.0 + 5.0 * PI;
+        let slice = assert_slice(
+            pline.slice_by_path_length(5.0, 10.0 + 2.5 * PI),
+            5.0 + 2.5 * PI,
+            Vector2::new(5.0, 0.0),
+            Vector2::new(15.0, 5.0),
+        );
+        // quarter circle arc kept
+        assert_eq!(slice.vertex_count(), 3);
+        assert!(slice[1].bulge.fuzzy_eq((PI / 8.0).tan()));
+
+        // clamped to the path
+        assert_slice(
+            pline.slice_by_path_length(-5.0, 100.0),
+            total,
+            Vector2::new(0.0, 0.0),
+            Vector2::new(10.0, 10.0),
+        );
+        // start and end at vertexes
+        let slice = assert_slice(
+            pline.slice_by_path_length(10.0, total),
+            5.0 * PI,
+            Vector2::new(10.0, 0.0),
+            Vector2::new(10.0, 10.0),
+        );
+        assert_eq!(slice.vertex_count(), 2);
+
+        assert!(pline.slice_by_path_length(5.0, 5.0).is_none());
+        assert!(pline.slice_by_path_length(6.0, 5.0).is_none());
+        assert!(pline.slice_by_path_length(120.0, 150.0).is_none());
+    }
+
+    #[test]
+    fn closed_slices_wrap() {
+        let square = square(0.0, 0.0, 10.0);
+        assert_slice(
+            square.slice_by_path_length(5.0, 25.0),
+            20.0,
+            Vector2::new(5.0, 0.0),
+            Vector2::new(5.0, 10.0),
+        );
+        // negative and past the end lengths wrap around
+        for (start, end) in [(-5.0, 5.0), (35.0, 45.0), (75.0, 5.0)] {
+            assert_slice(
+                square.slice_by_path_length(start, end),
+                10.0,
+                Vector2::new(0.0, 5.0),
+                Vector2::new(5.0, 0.0),
+            );
+        }
+        // end at the first vertex
+        let slice = assert_slice(
+            square.slice_by_path_length(30.0, 40.0),
+            10.0,
+            Vector2::new(0.0, 10.0),
+            Vector2::new(0.0, 0.0),
+        );
+        assert_eq!(slice.vertex_count(), 2);
+
+        // entire loop from a new start
+        let slice = assert_slice(
+            square.slice_by_path_length(5.0, 50.0),
+            40.0,
+            Vector2::new(5.0, 0.0),
+            Vector2::new(5.0, 0.0),
+        );
+        assert_eq!(slice.vertex_count(), 6);
+        assert_slice(
+            square.slice_by_path_length(0.0, 40.0),
+            40.0,
+            Vector2::new(0.0, 0.0),
+            Vector2::new(0.0, 0.0),
+        );
+
+        assert!(square.slice_by_path_length(15.0, 15.0).is_none());
+    }
+
+    #[test]
+    fn non_finite_lengths() {
+        let pline = line_and_arc();
+        let square = square(0.0, 0.0, 10.0);
+        for (start, end) in [
+            (f64::NAN, 5.0),
+            (5.0, f64::NAN),
+            (f64::NEG_INFINITY, 5.0),
+            (5.0, f64::INFINITY),
+        ] {
+            assert!(pline.slice_by_path_length(start, end).is_none());
+            assert!(square.slice_by_path_length(start, end).is_none());
+        }
+    }
+
+    #[test]
+    fn pos_equal_eps_option() {
+        let pline = line_and_arc();
+        let options = PlineSliceOptions {
+            pos_equal_eps: 1e-3,
+        };
+        assert!(pline.slice_by_path_length(5.0, 5.0001).is_some());
+        assert!(
+            pline
+                .slice_by_path_length_opt(5.0, 5.0001, &options)
+                .is_none()
+        );
+        assert_slice(
+            pline.slice_by_path_length_opt(5.0, 10.0 + 2.5 * PI, &options),
+            5.0 + 2.5 * PI,
+            Vector2::new(5.0, 0.0),
+            Vector2::new(15.0, 5.0),
+        );
+
+        // end within the epsilon of the first vertex of a closed polyline is the end of the loop
+        let square = square(0.0, 0.0, 10.0);
+        let slice = assert_slice(
+            square.slice_by_path_length_opt(30.0, 40.0005, &options),
+            10.0,
+            Vector2::new(0.0, 10.0),
+            Vector2::new(0.0, 0.0),
+        );
+        assert_eq!(slice.vertex_count(), 2);
+    }
+
+    #[test]
+    fn closed_arcs() {
+        let radius = 5.0;
+        let circle = pline_closed![(-radius, 0.0, 1.0), (radius, 0.0, 1.0)];
+        let total = 2.0 * PI * radius;
+        // three quarters of the circle starting at the top
+        let slice = assert_slice(
+            circle.slice_by_path_length(0.75 * total, 0.5 * total),
+            0.75 * total,
+            Vector2::new(0.0, radius),
+            Vector2::new(radius, 0.0),
+        );
+        for v in slice.iter_vertexes() {
+            assert!(v.pos().length().fuzzy_eq(radius));
+        }
+        assert!(slice.iter_segments().all(|(v1, _)| v1.bulge > 0.0));
+    }
+
+    #[test]
+    fn path_length_at_point() {
+        let pline = line_and_arc();
+        for s in pline.sample_at_spacing(1.0) {
+            let length = pline.find_path_length_at_point(s.point, 1e-5).unwrap();
+            assert!(length.fuzzy_eq(s.path_length));
+            // points off the path project onto it
+            let off = s.point + s.normal.scale(0.5);
+            let length = pline.find_path_length_at_point(off, 1e-5).unwrap();
+            assert!(length.fuzzy_eq(s.path_length));
+        }
+
+        let square = square(0.0, 0.0, 10.0);
+        for s in square.sample_at_spacing(2.5).iter().skip(1) {
+            let length = square.find_path_length_at_point(s.point, 1e-5).unwrap();
+            assert!(length.fuzzy_eq(s.path_length));
+        }
+        // slice round trip
+        let a = square
+            .find_path_length_at_point(Vector2::new(10.0, 3.0), 1e-5)
+            .unwrap();
+        let b = square
+            .find_path_length_at_point(Vector2::new(3.0, 10.0), 1e-5)
+            .unwrap();
+        assert_slice(
+            square.slice_by_path_length(a, b),
+            14.0,
+            Vector2::new(10.0, 3.0),
+            Vector2::new(3.0, 10.0),
+        );
+
+        assert!(
+            Polyline::<f64>::new()
+                .find_path_length_at_point(Vector2::zero(), 1e-5)
+                .is_none()
+        );
+        let point = pline_open![(1.0, 1.0, 0.0)];
+        assert_eq!(
+            point.find_path_length_at_point(Vector2::zero(), 1e-5),
+            Some(0.0)
+        );
+        assert!(point.slice_by_path_length(0.0, 1.0).is_none());
+    }
+}
@@ -0,0 +1,168 @@
+mod test_pline_repair {
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    fn assert_ccw_areas(result: &[Polyline], expected: &[f64]) {
+        assert_eq!(result.len(), expected.len());
+        let mut areas = result.iter().map(PlineSource::area).collect::<Vec<_>>();
+        areas.sort_by(f64::total_cmp);
+        for (a, e) in areas.iter().zip(expected) {
+            assert!(a.fuzzy_eq_eps(*e, 1e-5), "{a} != {e}");
+        }
+        assert!(
+            result
+                .iter()
+                .all(|p| p.is_closed() && p.orientation() == PlineOrientation::CounterClockwise)
+        );
+    }
+
+    #[test]
+    fn valid_input_unchanged() {
+        let square = pline_closed![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (0.0, 4.0, 0.0),
+        ];
+        let result = square.repair();
+        assert_ccw_areas(&result, &[16.0]);
+        assert_eq!(result[0].vertex_count(), 4);
+
+        let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+        assert_ccw_areas(&circle.repair(), &[std::f64::consts::PI]);
+    }
+
+    #[test]
+    fn fixes_orientation() {
+        let cw_square = pline_closed![
+            (0.0, 0.0, 0.0),
+            (0.0, 4.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (4.0, 0.0, 0.0),
+        ];
+        assert_ccw_areas(&cw_square.repair(), &[16.0]);
+    }
+
+    #[test]
+    fn removes_spikes() {
+        // spike out of the bottom edge and a spike doubling back along the right edge
+        let pline = pline_closed![
+            (0.0, 0.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (2.0, -3.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 6.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (0.0, 4.0, 0.0),
+        ];
+        let result = pline.repair();
+        assert_ccw_areas(&result, &[16.0]);
+        assert_eq!(result[0].vertex_count(), 4);
+
+        // spike tip at the start vertex
+        let pline = pline_closed![
+            (-3.0, 0.0, 0.0),
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (0.0, 4.0, 0.0),
+            (0.0, 0.0, 0.0),
+        ];
+        let result = pline.repair();
+        assert_ccw_areas(&result, &[16.0]);
+        assert_eq!(result[0].vertex_count(), 4);
+    }
+
+    #[test]
+    fn closes_nearly_closed() {
+        let nearly_closed = pline_open![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (0.0, 4.0, 0.0),
+            (0.0, 0.0005, 0.0),
+        ];
+        let result = nearly_closed.repair();
+        assert_ccw_areas(&result, &[16.0]);
+
+        // gap greater than the tolerance
+        assert!(
+            nearly_closed
+                .repair_opt(&PlineRepairOptions {
+                    close_tolerance: 1e-4,
+                    ..Default::default()
+                })
+                .is_empty()
+        );
+
+        // ends exactly at the start
+        let closed_by_position = pline_open![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (0.0, 0.0, 1.0),
+        ];
+        let result = closed_by_position.repair();
+        assert_ccw_areas(&result, &[8.0]);
+        assert_eq!(result[0].vertex_count(), 3);
+
+        let line = pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)];
+        assert!(line.repair().is_empty());
+    }
+
+    #[test]
+    fn splits_figure_eight() {
+        let figure_eight = pline_closed![
+            (0.0, 0.0, 0.0),
+            (2.0, 2.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (0.0, 2.0, 0.0),
+        ];
+        assert_ccw_areas(&figure_eight.repair(), &[1.0, 1.0]);
+    }
+
+    #[test]
+    fn fill_rule() {
+        // pentagram, even odd leaves the center pentagon unfilled
+        let points = (0..5)
+            .map(|i| {
+                let angle =
+                    std::f64::consts::FRAC_PI_2 + f64::from(i * 2) * std::f64::consts::TAU / 5.0;
+                (10.0 * angle.cos(), 10.0 * angle.sin())
+            })
+            .collect::<Vec<_>>();
+        let mut star = Polyline::new_closed();
+        for (x, y) in points {
+            star.add(x, y, 0.0);
+        }
+
+        assert_eq!(star.repair().len(), 1);
+        let even_odd = star.repair_opt(&PlineRepairOptions {
+            fill_rule: FillRule::EvenOdd,
+            ..Default::default()
+        });
+        assert_eq!(even_odd.len(), 5);
+    }
+
+    #[test]
+    fn discards_slivers_and_non_finite() {
+        let sliver = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (5.0, 1e-7, 0.0)];
+        assert!(sliver.repair().is_empty());
+
+        let mut pline = pline_closed![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (f64::NAN, 2.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (0.0, 4.0, f64::INFINITY),
+            (0.0, 4.0, 0.0),
+        ];
+        assert_ccw_areas(&pline.repair(), &[16.0]);
+
+        pline.set_userdata_values([7]);
+        let result = pline.repair();
+        assert_eq!(result[0].get_userdata_values().collect::<Vec<_>>(), vec![7]);
+    }
+}
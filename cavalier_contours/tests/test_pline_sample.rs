@@ -0,0 +1,223 @@
+mod test_utils;
+
+mod test_pline_sample {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::math::Vector2;
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    use crate::test_utils::{line_and_arc, square};
+
+    fn assert_samples_on_path(pline: &Polyline, samples: &[PathSample]) {
+        for s in samples {
+            let (seg_index, point) = pline.find_point_at_path_length(s.path_length).unwrap();
+            assert!(s.point.fuzzy_eq(point));
+            if s.path_length > 0.0 {
+                assert_eq!(s.seg_index, seg_index);
+            }
+            assert!(s.tangent.length().fuzzy_eq(1.0));
+            assert!(s.normal.fuzzy_eq(s.tangent.perp()));
+            let v1 = pline[s.seg_index];
+            let v2 = pline[pline.next_wrapping_index(s.seg_index)];
+            let tangent = seg_tangent_vector(v1, v2, s.point).normalize();
+            assert!(s.tangent.fuzzy_eq(tangent));
+        }
+    }
+
+    #[test]
+    fn sample_points_open() {
+        let pline = line_and_arc();
+        let total = 10.0 + 5.0 * PI;
+        let samples = pline.sample_points(5);
+        assert_eq!(samples.len(), 5);
+        for (i, s) in (0u32..).zip(&samples) {
+            assert!(s.path_length.fuzzy_eq(total * f64::from(i) / 4.0));
+        }
+        assert!(samples[0].point.fuzzy_eq(Vector2::new(0.0, 0.0)));
+        assert!(samples[4].point.fuzzy_eq(Vector2::new(10.0, 10.0)));
+        // end of the half circle is traveling in the negative x direction
+        assert!(samples[4].tangent.fuzzy_eq(Vector2::new(-1.0, 0.0)));
+        assert_samples_on_path(&pline, &samples);
+
+        let single = pline.sample_points(1);
+        assert_eq!(single.len(), 1);
+        assert!(single[0].path_length.fuzzy_eq(0.0));
+        assert!(pline.sample_points(0).is_empty());
+    }
+
+    #[test]
+    fn sample_points_closed() {
+        let radius = 5.0;
+        let circle = pline_closed![(-radius, 0.0, 1.0), (radius, 0.0, 1.0)];
+        let samples = circle.sample_points(8);
+        assert_eq!(samples.len(), 8);
+        for s in &samples {
+            assert!(s.point.length().fuzzy_eq(radius));
+            // counter clockwise so the normals point to the center
+            assert!(s.normal.fuzzy_eq(-s.point.scale(1.0 / radius)));
+        }
+        assert_samples_on_path(&circle, &samples);
+
+        let mut cw = square(0.0, 0.0, 10.0);
+        cw.invert_direction_mut();
+        let samples = cw.sample_points(8);
+        assert_eq!(samples.len(), 8);
+        // clockwise so the normals point outward, e.g. (0, 5) on the left side points left
+        assert!(samples[7].point.fuzzy_eq(Vector2::new(0.0, 5.0)));
+        assert!(samples[7].normal.fuzzy_eq(Vector2::new(-1.0, 0.0)));
+        assert_samples_on_path(&cw, &samples);
+    }
+
+    #[test]
+    fn sample_at_spacing() {
+        let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        let samples = pline.sample_at_spacing(3.0);
+        assert_eq!(samples.len(), 4);
+        assert!(samples[3].point.fuzzy_eq(Vector2::new(9.0, 0.0)));
+        let samples = pline.sample_at_spacing(2.5);
+        assert_eq!(samples.len(), 5);
+        assert!(samples[4].point.fuzzy_eq(Vector2::new(10.0, 0.0)));
+        assert!(pline.sample_at_spacing(0.0).is_empty());
+        assert!(pline.sample_at_spacing(-1.0).is_empty());
+
+        // closed polyline does not repeat the start, points at vertexes use the segment ending there
+        let square = square(0.0, 0.0, 10.0);
+        let samples = square.sample_at_spacing(10.0);
+        assert_eq!(samples.len(), 4);
+        assert_eq!(
+            samples.iter().map(|s| s.seg_index).collect::<Vec<_>>(),
+            vec![0, 0, 1, 2]
+        );
+        assert!(samples[2].point.fuzzy_eq(Vector2::new(10.0, 10.0)));
+        assert_samples_on_path(&square, &samples);
+    }
+
+    #[test]
+    fn zero_length_segments_skipped() {
+        let pline = pline_open![
+            (0.0, 0.0, 0.0),
+            (0.0, 0.0, 0.0),
+            (5.0, 0.0, 0.0),
+            (5.0, 0.0, 0.0),
+            (5.0, 5.0, 0.0),
+        ];
+        let samples = pline.sample_at_spacing(2.5);
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples[0].seg_index, 1);
+        assert_eq!(samples[3].seg_index, 3);
+        assert!(samples.iter().all(|s| s.tangent.length().fuzzy_eq(1.0)));
+
+        let point = pline_open![(1.0, 1.0, 0.0), (1.0, 1.0, 0.0)];
+        assert!(point.sample_points(3).is_empty());
+        assert!(point.sample_at_spacing(1.0).is_empty());
+    }
+
+    #[test]
+    fn pos_equal_eps_option() {
+        // segments shorter than the epsilon are skipped
+        let pline = pline_open![
+            (0.0, 0.0, 0.0),
+            (1e-4, 0.0, 0.0),
+            (5.0, 0.0, 0.0),
+            (5.0, 1e-4, 0.0),
+            (5.0, 5.0, 0.0),
+        ];
+        let options = PlineSampleOptions {
+            pos_equal_eps: 1e-3,
+        };
+        let samples = pline.sample_at_spacing_opt(2.5, &options);
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples[0].seg_index, 1);
+        assert_eq!(samples[3].seg_index, 3);
+        assert!(samples.iter().all(|s| s.tangent.length().fuzzy_eq(1.0)));
+        // not skipped with the default epsilon
+        assert_eq!(pline.sample_at_spacing(2.5)[0].seg_index, 0);
+
+        let samples = pline.sample_points_opt(3, &options);
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0].seg_index, 1);
+        assert_eq!(samples[2].seg_index, 3);
+
+        // path shorter than the epsilon
+        let point = pline_open![(1.0, 1.0, 0.0), (1.0, 1.0 + 1e-4, 0.0)];
+        assert!(point.sample_points_opt(3, &options).is_empty());
+        assert!(point.sample_at_spacing_opt(1e-5, &options).is_empty());
+        assert_eq!(point.sample_points(3).len(), 3);
+    }
+
+    #[test]
+    fn resample_keeps_arcs() {
+        let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+        let resampled = circle.resample(2.0);
+        let total = 10.0 * PI;
+        // ceil(10 * PI / 2) segments
+        let count = 16;
+        assert_eq!(resampled.vertex_count(), count);
+        assert!(resampled.path_length().fuzzy_eq(total));
+        assert!(resampled.area().fuzzy_eq(circle.area()));
+        for (v1, v2) in resampled.iter_segments() {
+            assert!(v1.bulge.fuzzy_eq(resampled[0].bulge) && v1.bulge > 0.0);
+            assert!(seg_length(v1, v2).fuzzy_eq(total / 16.0));
+        }
+
+        // closed polylines are split into at least 2 segments
+        assert_eq!(circle.resample(100.0).vertex_count(), 2);
+
+        // ceil(2 * PI) = 7 segments, the segment across the vertex at (-1, 0) stays an arc
+        let unit_circle = pline_closed![(1.0, 0.0, 1.0), (-1.0, 0.0, 1.0)];
+        let resampled = unit_circle.resample(1.0);
+        assert_eq!(resampled.vertex_count(), 7);
+        assert!(resampled.area().fuzzy_eq(PI));
+        assert!(resampled.path_length().fuzzy_eq(2.0 * PI));
+        for (v1, v2) in resampled.iter_segments() {
+            assert!(v1.bulge.fuzzy_eq(resampled[0].bulge));
+            assert!(seg_length(v1, v2).fuzzy_eq(2.0 * PI / 7.0));
+        }
+    }
+
+    #[test]
+    fn resample_across_vertexes() {
+        let pline = line_and_arc();
+        let total = 10.0 + 5.0 * PI;
+        let resampled = pline.resample(4.0);
+        // ceil((10 + 5 * PI) / 4) segments
+        let count = 7;
+        assert_eq!(resampled.vertex_count(), count + 1);
+        let samples = pline.sample_points(count + 1);
+        for (v, s) in resampled.iter_vertexes().zip(&samples) {
+            assert!(v.pos().fuzzy_eq(s.point));
+        }
+        // the segment across the corner at (10, 0) is a line, the rest follow the path
+        let crossing = samples.iter().position(|s| s.path_length > 10.0).unwrap() - 1;
+        assert!(resampled[crossing].bulge_is_zero());
+        assert!(resampled[crossing + 1].bulge > 0.0);
+        assert!(resampled.path_length() < total);
+
+        let options = PlineResampleOptions {
+            keep_vertexes: true,
+            ..Default::default()
+        };
+        let split = pline.resample_opt(4.0, &options);
+        assert_eq!(split.vertex_count(), count + 2);
+        assert!(split.path_length().fuzzy_eq(total));
+        assert!(
+            split
+                .iter_vertexes()
+                .any(|v| v.pos().fuzzy_eq(Vector2::new(10.0, 0.0)))
+        );
+
+        // new vertex at an existing vertex is merged
+        let square = square(0.0, 0.0, 10.0);
+        let split = square.resample_opt(5.0, &options);
+        assert_eq!(split.vertex_count(), 8);
+        assert_eq!(square.resample(5.0).vertex_count(), 8);
+        assert!(square.resample(10.0).fuzzy_eq(&square));
+
+        // invalid length or zero path length returns the input
+        assert!(square.resample(0.0).fuzzy_eq(&square));
+        let point = pline_open![(1.0, 1.0, 0.0)];
+        assert!(point.resample(1.0).fuzzy_eq(&point));
+    }
+}
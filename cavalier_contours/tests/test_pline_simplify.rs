@@ -0,0 +1,171 @@
+mod test_pline_simplify {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    const METHODS: [SimplifyMethod; 2] =
+        [SimplifyMethod::DouglasPeucker, SimplifyMethod::Visvalingam];
+
+    fn options(method: SimplifyMethod) -> PlineSimplifyOptions<f64> {
+        PlineSimplifyOptions {
+            method,
+            ..Default::default()
+        }
+    }
+
+    /// Assert all vertexes and segment midpoints of `original` are within `tolerance` of `result`.
+    fn assert_within_tolerance(original: &Polyline, result: &Polyline, tolerance: f64) {
+        let points = original
+            .iter_segments()
+            .flat_map(|(v1, v2)| [v1.pos(), seg_midpoint(v1, v2), v2.pos()]);
+        for p in points {
+            let closest = result.closest_point(p, 1e-5).unwrap();
+            assert!(
+                closest.distance <= tolerance + 1e-9,
+                "{p:?} is {} from result",
+                closest.distance
+            );
+        }
+    }
+
+    fn create_noisy_line() -> Polyline {
+        let mut pline = Polyline::new();
+        for i in 0..=20 {
+            let noise = if i % 2 == 0 { 0.01 } else { -0.01 };
+            pline.add(f64::from(i), noise, 0.0);
+        }
+        pline
+    }
+
+    #[test]
+    fn noisy_line() {
+        let pline = create_noisy_line();
+        for method in METHODS {
+            let result = pline.simplify_opt(0.05, &options(method));
+            assert_eq!(result.vertex_count(), 2);
+            assert!(result.at(0).pos().fuzzy_eq(pline.at(0).pos()));
+            assert!(result.at(1).pos().fuzzy_eq(pline.last().unwrap().pos()));
+            assert_within_tolerance(&pline, &result, 0.05);
+
+            // tolerance smaller than the noise keeps everything
+            assert_eq!(
+                pline.simplify_opt(0.001, &options(method)).vertex_count(),
+                pline.vertex_count()
+            );
+        }
+    }
+
+    #[test]
+    fn small_input_unchanged() {
+        let line = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)];
+        assert_eq!(line.simplify(1.0).vertex_count(), 2);
+        let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+        let result = circle.simplify(10.0);
+        assert!(result.fuzzy_eq(&circle));
+    }
+
+    #[test]
+    fn arcs_preserved() {
+        // line segments around a half circle arc that sticks out far past the tolerance
+        let pline = pline_open![
+            (0.0, 0.0, 0.0),
+            (1.0, 0.01, 0.0),
+            (2.0, 0.0, -1.0),
+            (4.0, 0.0, 0.0),
+            (5.0, -0.01, 0.0),
+            (6.0, 0.0, 0.0),
+        ];
+        for method in METHODS {
+            let result = pline.simplify_opt(0.1, &options(method));
+            assert_eq!(result.vertex_count(), 4);
+            assert!(result.at(1).bulge.fuzzy_eq(-1.0));
+            assert!(result.path_length().fuzzy_eq_eps(pline.path_length(), 1e-3));
+            assert_within_tolerance(&pline, &result, 0.1);
+        }
+
+        // shallow arc within the tolerance is replaced by a line
+        let shallow = pline_open![(0.0, 0.0, 0.01), (4.0, 0.0, 0.0), (8.0, 0.0, 0.0)];
+        for method in METHODS {
+            let result = shallow.simplify_opt(0.1, &options(method));
+            assert_eq!(result.vertex_count(), 2);
+            assert!(result.at(0).bulge_is_zero());
+        }
+    }
+
+    #[test]
+    fn arc_bulge_counts_toward_deviation() {
+        // end points are collinear but the arc bulges out by 0.5
+        let pline = pline_open![
+            (0.0, 0.0, 0.0),
+            (2.0, 0.0, 0.5),
+            (4.0, 0.0, 0.0),
+            (6.0, 0.0, 0.0),
+        ];
+        let bulge_height = 0.5;
+        for method in METHODS {
+            let result = pline.simplify_opt(bulge_height * 0.9, &options(method));
+            assert_eq!(result.vertex_count(), 4, "{method:?}");
+            let result = pline.simplify_opt(bulge_height * 1.1, &options(method));
+            assert_eq!(result.vertex_count(), 2, "{method:?}");
+        }
+    }
+
+    #[test]
+    fn closed_polyline() {
+        let mut pline = Polyline::new_closed();
+        for i in 0..40 {
+            let angle = f64::from(i) * 2.0 * PI / 40.0;
+            pline.add(10.0 * angle.cos(), 10.0 * angle.sin(), 0.0);
+        }
+        pline.set_userdata_values([3]);
+
+        for method in METHODS {
+            let result = pline.simplify_opt(0.5, &options(method));
+            assert!(result.is_closed());
+            assert!(result.vertex_count() < pline.vertex_count());
+            assert!(result.vertex_count() >= 3);
+            assert_within_tolerance(&pline, &result, 0.5);
+            assert_eq!(result.get_userdata_values().collect::<Vec<_>>(), vec![3]);
+
+            // huge tolerance still keeps a closed polyline with area
+            let result = pline.simplify_opt(100.0, &options(method));
+            assert_eq!(result.vertex_count(), 3);
+            assert!(f64::abs(result.area()) > 1.0);
+        }
+    }
+
+    #[test]
+    fn preserve_topology() {
+        // bottom edge bumps down and the top edge notches down to just above the bump, removing
+        // the bump alone crosses the notch
+        let pline = pline_open![
+            (0.0, 0.0, 0.0),
+            (5.0, -0.3, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 1.0, 0.0),
+            (6.0, 1.0, 0.0),
+            (5.0, -0.2, 0.0),
+            (4.0, 1.0, 0.0),
+            (0.0, 1.0, 0.0),
+        ];
+        assert!(!pline.scan_for_self_intersect());
+
+        for method in METHODS {
+            let naive = pline.simplify_opt(0.35, &options(method));
+            assert!(naive.scan_for_self_intersect(), "{method:?}");
+
+            let result = pline.simplify_opt(
+                0.35,
+                &PlineSimplifyOptions {
+                    method,
+                    preserve_topology: true,
+                    ..Default::default()
+                },
+            );
+            assert!(!result.scan_for_self_intersect(), "{method:?}");
+            assert_within_tolerance(&pline, &result, 0.35);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+mod test_pline_transform {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::core::math::{Affine2, Vector2};
+    use cavalier_contours::core::traits::FuzzyEq;
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    /// Rectangle from (0, 0) to (10, 6) with corners rounded by arcs with radius 2.
+    fn rounded_rectangle() -> Polyline {
+        let b = (PI / 8.0).tan();
+        pline_closed![
+            (2.0, 0.0, 0.0),
+            (8.0, 0.0, b),
+            (10.0, 2.0, 0.0),
+            (10.0, 4.0, b),
+            (8.0, 6.0, 0.0),
+            (2.0, 6.0, b),
+            (0.0, 4.0, 0.0),
+            (0.0, 2.0, b),
+        ]
+    }
+
+    #[test]
+    fn rotate_and_mirror() {
+        let pline = rounded_rectangle();
+        let area = pline.area();
+
+        let mut rotated = pline.clone();
+        rotated.rotate_mut(Vector2::new(5.0, 3.0), PI);
+        assert!(rotated.area().fuzzy_eq(area));
+        assert!(rotated[0].pos().fuzzy_eq(Vector2::new(8.0, 6.0)));
+        rotated.rotate_mut(Vector2::new(5.0, 3.0), PI);
+        assert!(rotated.fuzzy_eq(&pline));
+
+        // mirrored arcs still bulge outward so the area is the same with opposite sign
+        let mut mirrored = pline.clone();
+        mirrored.mirror_mut(Vector2::new(0.0, 3.0), Vector2::new(1.0, 0.0));
+        assert!(mirrored.area().fuzzy_eq(-area));
+        assert!(mirrored.iter_vertexes().all(|v| v.bulge <= 0.0));
+        let extents = mirrored.extents().unwrap();
+        assert!(extents.min_y.fuzzy_eq(0.0) && extents.max_y.fuzzy_eq(6.0));
+
+        // zero direction has no line to mirror across
+        let mut unchanged = pline.clone();
+        unchanged.mirror_mut(Vector2::new(0.0, 3.0), Vector2::zero());
+        assert!(unchanged.fuzzy_eq(&pline));
+    }
+
+    #[test]
+    fn similarity_transforms_are_exact() {
+        let pline = rounded_rectangle();
+        let t = Affine2::rotation(0.3)
+            .then(&Affine2::scaling(-3.0, 3.0))
+            .then(&Affine2::translation(4.0, -1.0));
+        let result = pline.transform(&t);
+        assert_eq!(result.vertex_count(), pline.vertex_count());
+        assert!(result.area().fuzzy_eq(-9.0 * pline.area()));
+        let mut in_place = pline.clone();
+        in_place.transform_mut(&t);
+        assert!(in_place.fuzzy_eq(&result));
+    }
+
+    #[test]
+    fn ellipse_arcs_within_error() {
+        let radius: f64 = 5.0;
+        let circle = pline_closed![(-radius, 0.0, 1.0), (radius, 0.0, 1.0)];
+        for (t, error) in [
+            (Affine2::scaling(3.0, 1.0), 1e-3),
+            (Affine2::scaling(1.0, -0.5), 1e-4),
+            (
+                Affine2::shearing(2.0, 0.0).then(&Affine2::translation(1.0, 2.0)),
+                1e-2,
+            ),
+        ] {
+            let options = PlineTransformOptions {
+                ellipse_approx_error: error,
+                ..Default::default()
+            };
+            let ellipse = circle.transform_opt(&t, &options);
+            assert!(ellipse.vertex_count() > 2);
+            assert!(
+                ellipse
+                    .area()
+                    .fuzzy_eq_eps(circle.area() * t.determinant(), 0.1)
+            );
+
+            // points on the result map back onto the circle, the inverse transforms do not scale
+            // distances up by more than 2
+            let inverse = t.inverse().unwrap();
+            let samples = ellipse.arcs_to_approx_lines(1e-5).unwrap();
+            for v in samples.iter_vertexes() {
+                let p = inverse.transform_point(v.pos());
+                assert!((p.length() - radius).abs() < 2.0 * error);
+            }
+        }
+    }
+
+    #[test]
+    fn shear_keeps_area() {
+        let pline = rounded_rectangle();
+        let t = Affine2::shearing(0.5, 0.25);
+        let result = pline.transform_opt(
+            &t,
+            &PlineTransformOptions {
+                ellipse_approx_error: 1e-5,
+                ..Default::default()
+            },
+        );
+        assert!(
+            result
+                .area()
+                .fuzzy_eq_eps(pline.area() * t.determinant(), 1e-3)
+        );
+        // line segments are kept
+        assert!(
+            result
+                .iter_vertexes()
+                .any(|v| v.pos().fuzzy_eq(Vector2::new(2.0, 0.5)))
+        );
+
+        let kept = pline.transform_opt(
+            &t,
+            &PlineTransformOptions {
+                approx_ellipse_arcs: false,
+                ..Default::default()
+            },
+        );
+        let mut in_place = pline.clone();
+        in_place.transform_mut(&t);
+        assert!(kept.fuzzy_eq(&in_place));
+    }
+
+    #[test]
+    fn open_and_empty() {
+        let empty = Polyline::<f64>::new();
+        assert!(empty.transform(&Affine2::scaling(2.0, 1.0)).is_empty());
+
+        // last vertex bulge of open polyline is ignored
+        let pline = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 1.0)];
+        let result = pline.transform(&Affine2::scaling(2.0, 1.0));
+        assert_eq!(result.vertex_count(), 2);
+        assert!(!result.is_closed());
+        assert!(result[1].pos().fuzzy_eq(Vector2::new(2.0, 0.0)));
+    }
+}
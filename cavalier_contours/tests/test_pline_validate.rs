@@ -0,0 +1,136 @@
+mod test_utils;
+
+mod test_pline_validate {
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    use crate::test_utils::square;
+
+    fn validate(pline: &Polyline) -> PlineValidationReport {
+        pline.validate(&PlineValidateOptions::default())
+    }
+
+    #[test]
+    fn valid_polylines() {
+        assert!(validate(&square(0.0, 0.0, 4.0)).is_valid());
+        let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+        assert!(validate(&circle).is_valid());
+        let line = pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)];
+        assert!(validate(&line).is_valid());
+        // large arc (three quarter circle) is in range
+        let arc = pline_open![(1.0, 0.0, 2.414_213_562_373_095), (0.0, -1.0, 0.0)];
+        assert!(validate(&arc).is_valid());
+    }
+
+    #[test]
+    fn non_finite_vertexes() {
+        let mut pline = square(0.0, 0.0, 4.0);
+        pline.set_vertex(1, PlineVertex::new(f64::NAN, 0.0, 0.0));
+        pline.set_vertex(3, PlineVertex::new(0.0, 4.0, f64::INFINITY));
+        let report = validate(&pline);
+        assert_eq!(report.non_finite_vertexes, vec![1, 3]);
+        // bulge of non finite vertex is not also reported as out of range
+        assert!(report.out_of_range_bulges.is_empty());
+        // self intersect and area checks skipped
+        assert!(report.self_intersects.basic_intersects.is_empty());
+        assert!(!report.near_zero_area);
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn out_of_range_bulges() {
+        let pline = pline_open![(0.0, 0.0, 1e4), (1.0, 0.0, -2e3), (2.0, 0.0, 1e5)];
+        let report = validate(&pline);
+        // last vertex bulge of an open polyline is not a segment
+        assert_eq!(report.out_of_range_bulges, vec![0, 1]);
+
+        let options = PlineValidateOptions {
+            max_abs_bulge: 1.0,
+            ..Default::default()
+        };
+        let pline = pline_closed![(0.0, 0.0, 0.5), (2.0, 0.0, 2.0)];
+        assert_eq!(pline.validate(&options).out_of_range_bulges, vec![1]);
+    }
+
+    #[test]
+    fn zero_length_segments() {
+        let pline = pline_closed![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (0.0, 4.0, 0.0),
+            (0.0, 0.0, 0.0),
+        ];
+        let report = validate(&pline);
+        assert_eq!(report.zero_length_segments, vec![1, 5]);
+        assert!(report.repeated_positions.is_empty());
+        assert!(!report.near_zero_area);
+    }
+
+    #[test]
+    fn repeated_positions() {
+        // open polyline ending at its start
+        let pline = pline_open![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (0.0, 0.0, 0.0),
+        ];
+        let report = validate(&pline);
+        assert_eq!(report.repeated_positions, vec![(0, 3)]);
+        assert!(report.zero_length_segments.is_empty());
+
+        // figure eight passing through the same vertex position twice
+        let pline = pline_closed![
+            (0.0, 0.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (2.0, 2.0, 0.0),
+            (4.0, 2.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (2.0, 4.0, 0.0),
+            (2.0, 2.000_001, 0.0),
+            (0.0, 2.0, 0.0),
+        ];
+        let report = validate(&pline);
+        assert_eq!(report.repeated_positions, vec![(2, 6)]);
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn self_intersects() {
+        let pline = pline_open![
+            (0.0, 0.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (0.0, 4.0, 0.0),
+        ];
+        let report = validate(&pline);
+        assert_eq!(report.self_intersects.basic_intersects.len(), 1);
+        let intr = report.self_intersects.basic_intersects[0];
+        assert_eq!((intr.start_index1, intr.start_index2), (0, 2));
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn near_zero_area() {
+        let collapsed = pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)];
+        let report = validate(&collapsed);
+        assert!(report.near_zero_area);
+        // line doubling back on itself
+        assert_eq!(report.self_intersects.overlapping_intersects.len(), 1);
+
+        let thin = pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 1e-7, 0.0)];
+        assert!(validate(&thin).near_zero_area);
+
+        let options = PlineValidateOptions {
+            zero_area_eps: 1e-9,
+            ..Default::default()
+        };
+        assert!(!thin.validate(&options).near_zero_area);
+
+        // open polylines have no area
+        let line = pline_open![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0)];
+        assert!(!validate(&line).near_zero_area);
+    }
+}
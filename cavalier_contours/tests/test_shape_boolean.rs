@@ -0,0 +1,301 @@
+mod test_utils;
+
+use cavalier_contours::{
+    polyline::{BooleanOp, PlineSource},
+    shape_algorithms::Shape,
+};
+use test_utils::{PlineProperties, create_property_set, hole, property_sets_match, rectangle};
+
+fn run_shape_boolean_test(
+    shape1: &Shape<f64>,
+    shape2: &Shape<f64>,
+    operation: BooleanOp,
+    expected_ccw_properties: &[PlineProperties],
+    expected_cw_properties: &[PlineProperties],
+) {
+    let result = shape1.boolean(shape2, operation);
+    let ccw_properties = create_property_set(result.ccw_plines.iter().map(|p| &p.polyline), false);
+    let cw_properties = create_property_set(result.cw_plines.iter().map(|p| &p.polyline), false);
+    assert!(
+        property_sets_match(&ccw_properties, expected_ccw_properties),
+        "ccw result property sets do not match for {operation:?}"
+    );
+    assert!(
+        property_sets_match(&cw_properties, expected_cw_properties),
+        "cw result property sets do not match for {operation:?}"
+    );
+}
+
+/// Square with a square hole in the middle.
+fn square_with_hole() -> Shape<f64> {
+    Shape::from_plines([
+        rectangle(0.0, 0.0, 10.0, 10.0),
+        hole(rectangle(3.0, 3.0, 7.0, 7.0)),
+    ])
+}
+
+#[test]
+fn overlapping_rectangles() {
+    let shape1 = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 10.0)]);
+    let shape2 = Shape::from_plines([rectangle(5.0, 5.0, 15.0, 15.0)]);
+
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::Or,
+        &[PlineProperties::new(
+            8,
+            175.0,
+            60.0,
+            0.0,
+            0.0,
+            15.0,
+            15.0,
+            vec![],
+        )],
+        &[],
+    );
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::And,
+        &[PlineProperties::new(
+            4,
+            25.0,
+            20.0,
+            5.0,
+            5.0,
+            10.0,
+            10.0,
+            vec![],
+        )],
+        &[],
+    );
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::Not,
+        &[PlineProperties::new(
+            6,
+            75.0,
+            40.0,
+            0.0,
+            0.0,
+            10.0,
+            10.0,
+            vec![],
+        )],
+        &[],
+    );
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::Xor,
+        &[
+            PlineProperties::new(6, 75.0, 40.0, 0.0, 0.0, 10.0, 10.0, vec![]),
+            PlineProperties::new(6, 75.0, 40.0, 5.0, 5.0, 15.0, 15.0, vec![]),
+        ],
+        &[],
+    );
+}
+
+#[test]
+fn strip_crossing_hole() {
+    let shape1 = square_with_hole();
+    let shape2 = Shape::from_plines([rectangle(5.0, 4.0, 12.0, 6.0)]);
+
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::Or,
+        &[PlineProperties::new(
+            8,
+            104.0,
+            44.0,
+            0.0,
+            0.0,
+            12.0,
+            10.0,
+            vec![],
+        )],
+        &[PlineProperties::new(
+            8,
+            -12.0,
+            20.0,
+            3.0,
+            3.0,
+            7.0,
+            7.0,
+            vec![],
+        )],
+    );
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::And,
+        &[PlineProperties::new(
+            4,
+            6.0,
+            10.0,
+            7.0,
+            4.0,
+            10.0,
+            6.0,
+            vec![],
+        )],
+        &[],
+    );
+    // strip cuts the hole open to the outside, leaving a single loop with a notch
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::Not,
+        &[PlineProperties::new(
+            12,
+            78.0,
+            58.0,
+            0.0,
+            0.0,
+            10.0,
+            10.0,
+            vec![],
+        )],
+        &[],
+    );
+    let xor = shape1.boolean(&shape2, BooleanOp::Xor);
+    let xor_area: f64 = xor
+        .ccw_plines
+        .iter()
+        .chain(&xor.cw_plines)
+        .map(|p| p.polyline.area())
+        .sum();
+    assert!((xor_area - 86.0).abs() < 1e-5);
+}
+
+#[test]
+fn island_inside_hole() {
+    let shape1 = square_with_hole();
+    let shape2 = Shape::from_plines([rectangle(4.0, 4.0, 6.0, 6.0)]);
+
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::Or,
+        &[
+            PlineProperties::new(4, 100.0, 40.0, 0.0, 0.0, 10.0, 10.0, vec![]),
+            PlineProperties::new(4, 4.0, 8.0, 4.0, 4.0, 6.0, 6.0, vec![]),
+        ],
+        &[PlineProperties::new(
+            4,
+            -16.0,
+            16.0,
+            3.0,
+            3.0,
+            7.0,
+            7.0,
+            vec![],
+        )],
+    );
+    run_shape_boolean_test(&shape1, &shape2, BooleanOp::And, &[], &[]);
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::Not,
+        &[PlineProperties::new(
+            4,
+            100.0,
+            40.0,
+            0.0,
+            0.0,
+            10.0,
+            10.0,
+            vec![],
+        )],
+        &[PlineProperties::new(
+            4,
+            -16.0,
+            16.0,
+            3.0,
+            3.0,
+            7.0,
+            7.0,
+            vec![],
+        )],
+    );
+    // subtracting the shape with the hole leaves just the island
+    run_shape_boolean_test(
+        &shape2,
+        &shape1,
+        BooleanOp::Not,
+        &[PlineProperties::new(
+            4,
+            4.0,
+            8.0,
+            4.0,
+            4.0,
+            6.0,
+            6.0,
+            vec![],
+        )],
+        &[],
+    );
+}
+
+#[test]
+fn subtract_creates_hole() {
+    let shape1 = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 10.0)]);
+    let shape2 = Shape::from_plines([rectangle(4.0, 4.0, 6.0, 6.0)]);
+
+    run_shape_boolean_test(
+        &shape1,
+        &shape2,
+        BooleanOp::Not,
+        &[PlineProperties::new(
+            4,
+            100.0,
+            40.0,
+            0.0,
+            0.0,
+            10.0,
+            10.0,
+            vec![],
+        )],
+        &[PlineProperties::new(
+            4,
+            -4.0,
+            8.0,
+            4.0,
+            4.0,
+            6.0,
+            6.0,
+            vec![],
+        )],
+    );
+}
+
+#[test]
+fn shared_edge_union() {
+    let shape1 = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 10.0)]);
+    let shape2 = Shape::from_plines([rectangle(10.0, 0.0, 20.0, 10.0)]);
+
+    let result = shape1.boolean(&shape2, BooleanOp::Or);
+    assert_eq!(result.ccw_plines.len(), 1);
+    assert!(result.cw_plines.is_empty());
+    assert!((result.ccw_plines[0].polyline.area() - 200.0).abs() < 1e-5);
+}
+
+#[test]
+fn disjoint_and_empty() {
+    let shape1 = Shape::from_plines([rectangle(0.0, 0.0, 1.0, 1.0)]);
+    let shape2 = Shape::from_plines([rectangle(5.0, 5.0, 6.0, 6.0)]);
+
+    let result = shape1.boolean(&shape2, BooleanOp::Or);
+    assert_eq!(result.ccw_plines.len(), 2);
+    let result = shape1.boolean(&shape2, BooleanOp::And);
+    assert!(result.ccw_plines.is_empty() && result.cw_plines.is_empty());
+
+    let result = shape1.boolean(&Shape::empty(), BooleanOp::Or);
+    assert_eq!(result.ccw_plines.len(), 1);
+    let result = Shape::empty().boolean(&shape1, BooleanOp::Not);
+    assert!(result.ccw_plines.is_empty());
+}
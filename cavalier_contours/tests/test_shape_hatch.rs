@@ -0,0 +1,154 @@
+mod test_utils;
+
+mod test_shape_hatch {
+    use std::f64::consts::PI;
+
+    use cavalier_contours::{
+        core::traits::FuzzyEq,
+        pline_closed,
+        polyline::{PlineSource, Polyline},
+        shape_algorithms::{Shape, ShapeHatchOptions},
+    };
+
+    use crate::test_utils::{hole, rectangle};
+
+    fn offset_options(offset: f64) -> ShapeHatchOptions<f64> {
+        ShapeHatchOptions {
+            offset,
+            ..Default::default()
+        }
+    }
+
+    /// Assert all the hatch line end points are on the shape boundary and every line is inside.
+    fn assert_lines_in_shape(lines: &[Polyline], shape: &Shape<f64>) {
+        let loops = shape
+            .ccw_plines
+            .iter()
+            .chain(&shape.cw_plines)
+            .map(|p| &p.polyline)
+            .collect::<Vec<_>>();
+        let on_boundary = |p| {
+            loops
+                .iter()
+                .any(|l| l.closest_point(p, 1e-5).unwrap().distance < 1e-6)
+        };
+        for line in lines {
+            assert!(!line.is_closed());
+            assert!(on_boundary(line[0].pos()));
+            assert!(on_boundary(line[line.vertex_count() - 1].pos()));
+            for (v1, v2) in line.iter_segments() {
+                let mid = cavalier_contours::polyline::seg_midpoint(v1, v2);
+                let winding: i32 = loops.iter().map(|l| l.winding_number(mid)).sum();
+                assert!(winding != 0 || on_boundary(mid), "{mid:?} outside");
+            }
+        }
+    }
+
+    #[test]
+    fn shape_with_hole() {
+        let shape = Shape::from_plines([
+            rectangle(0.0, 0.0, 10.0, 10.0),
+            hole(rectangle(3.0, 3.0, 7.0, 7.0)),
+        ]);
+        let lines = shape.hatch(1.0, &offset_options(0.5));
+        // 6 lines full width and 4 lines split by the hole
+        assert_eq!(lines.len(), 6 + 2 * 4);
+        let total: f64 = lines.iter().map(PlineSource::path_length).sum();
+        assert!(total.fuzzy_eq(100.0 - 16.0));
+        assert_lines_in_shape(&lines, &shape);
+
+        // ordered along the perpendicular then along the line
+        assert!(lines[3][0].y.fuzzy_eq(3.5) && lines[3][0].x.fuzzy_eq(0.0));
+        assert!(lines[4][0].y.fuzzy_eq(3.5) && lines[4][0].x.fuzzy_eq(7.0));
+    }
+
+    #[test]
+    fn arcs_clipped_exactly() {
+        let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+        let shape = Shape::from_plines([circle]);
+        let lines = shape.hatch(1.0, &offset_options(0.5));
+        assert_eq!(lines.len(), 10);
+        for line in &lines {
+            let y = line[0].y;
+            assert!(line.path_length().fuzzy_eq(2.0 * (25.0 - y * y).sqrt()));
+        }
+        assert_lines_in_shape(&lines, &shape);
+    }
+
+    #[test]
+    fn angle_and_crosshatch() {
+        let shape = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 10.0)]);
+        let options = ShapeHatchOptions {
+            angle: PI / 4.0,
+            offset: 0.25,
+            ..Default::default()
+        };
+        let lines = shape.hatch(0.5, &options);
+        assert_lines_in_shape(&lines, &shape);
+        for line in &lines {
+            let d = line[1].pos() - line[0].pos();
+            assert!(d.x.fuzzy_eq(d.y) && d.x > 0.0);
+        }
+        // area covered by the lines is about the shape area
+        let total: f64 = lines.iter().map(PlineSource::path_length).sum();
+        assert!((total * 0.5 - 100.0).abs() < 1.0);
+
+        let options = ShapeHatchOptions {
+            crosshatch: true,
+            ..offset_options(0.5)
+        };
+        let lines = shape.hatch(1.0, &options);
+        assert_eq!(lines.len(), 20);
+        // second set is vertical, following the first set
+        assert!(lines[..10].iter().all(|l| l[0].y.fuzzy_eq(l[1].y)));
+        assert!(lines[10..].iter().all(|l| l[0].x.fuzzy_eq(l[1].x)));
+    }
+
+    #[test]
+    fn zigzag() {
+        let shape = Shape::from_plines([
+            rectangle(0.0, 0.0, 10.0, 10.0),
+            hole(rectangle(3.0, 3.0, 7.0, 7.0)),
+        ]);
+        let options = ShapeHatchOptions {
+            zigzag: true,
+            ..offset_options(0.5)
+        };
+        let zigzags = shape.hatch(1.0, &options);
+        assert_lines_in_shape(&zigzags, &shape);
+        // all the hatch line lengths are covered once
+        let hatch_total: f64 = shape
+            .hatch(1.0, &offset_options(0.5))
+            .iter()
+            .map(PlineSource::path_length)
+            .sum();
+        let connector_total: f64 =
+            zigzags.iter().map(PlineSource::path_length).sum::<f64>() - hatch_total;
+        // 14 lines in 2 zigzags, 12 connectors of length 1 along the sides
+        assert_eq!(zigzags.len(), 2);
+        assert!(connector_total.fuzzy_eq(12.0));
+
+        // connectors follow arc segments of the boundary
+        let circle = pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)];
+        let shape = Shape::from_plines([circle]);
+        let zigzags = shape.hatch(1.0, &options);
+        assert_eq!(zigzags.len(), 1);
+        assert!(zigzags[0].iter_vertexes().any(|v| !v.bulge_is_zero()));
+        assert_lines_in_shape(&zigzags, &shape);
+    }
+
+    #[test]
+    fn empty_cases() {
+        let shape = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 10.0)]);
+        assert!(shape.hatch(0.0, &ShapeHatchOptions::default()).is_empty());
+        assert!(shape.hatch(-1.0, &ShapeHatchOptions::default()).is_empty());
+        assert!(
+            Shape::<f64>::empty()
+                .hatch(1.0, &ShapeHatchOptions::default())
+                .is_empty()
+        );
+        // spacing larger than the shape with no line crossing it
+        let lines = shape.hatch(100.0, &offset_options(50.0));
+        assert!(lines.is_empty());
+    }
+}
@@ -87,3 +87,42 @@ mod test_specific {
         }
     );
 }
+
+#[test]
+fn miter_join_keeps_island_corners_sharp() {
+    use cavalier_contours::{pline_closed, polyline::OffsetJoinType};
+
+    let s = Shape::from_plines([
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (20.0, 0.0, 0.0),
+            (20.0, 20.0, 0.0),
+            (0.0, 20.0, 0.0)
+        ],
+        pline_closed![
+            (5.0, 5.0, 0.0),
+            (5.0, 15.0, 0.0),
+            (15.0, 15.0, 0.0),
+            (15.0, 5.0, 0.0)
+        ],
+    ]);
+    let options = ShapeOffsetOptions {
+        join_type: OffsetJoinType::Miter { limit: 2.0 },
+        ..Default::default()
+    };
+    let result = s.parallel_offset(2.0, &options);
+    let plines = result
+        .ccw_plines
+        .iter()
+        .chain(result.cw_plines.iter())
+        .map(|p| &p.polyline);
+    let result_properties = create_property_set(plines, false);
+    let expected = [
+        PlineProperties::new(4, 256.0, 64.0, 2.0, 2.0, 18.0, 18.0, vec![]),
+        PlineProperties::new(4, -196.0, 56.0, 3.0, 3.0, 17.0, 17.0, vec![]),
+    ];
+    assert!(
+        property_sets_match(&result_properties, &expected),
+        "result property sets do not match"
+    );
+}
@@ -0,0 +1,152 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::traits::FuzzyEq,
+    pline_closed,
+    polyline::{PlineOrientation, PlineSource},
+    shape_algorithms::{PocketTree, Shape, ShapeOffsetOptions},
+};
+use test_utils::{hole, rectangle};
+
+/// Check parent/child links are consistent and every parent is from the previous pass.
+fn assert_tree_links(tree: &PocketTree<f64>) {
+    for (i, l) in tree.loops.iter().enumerate() {
+        match l.parent {
+            Some(p) => {
+                assert_eq!(tree.loops[p].pass + 1, l.pass);
+                assert!(tree.loops[p].children.contains(&i));
+            }
+            None => assert!(tree.roots.contains(&i)),
+        }
+        for &c in &l.children {
+            assert_eq!(tree.loops[c].parent, Some(i));
+        }
+    }
+}
+
+#[test]
+fn inward_offsets_of_square() {
+    let shape = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 10.0)]);
+    let options = ShapeOffsetOptions::default();
+    let areas = shape
+        .inward_offsets(1.0, &options)
+        .map(|s| {
+            assert_eq!(s.ccw_plines.len(), 1);
+            assert!(s.cw_plines.is_empty());
+            s.ccw_plines[0].polyline.area()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(areas.len(), 4);
+    for (area, side) in areas.iter().zip([8.0, 6.0, 4.0, 2.0]) {
+        assert!(area.fuzzy_eq(side * side), "{area} != {}", side * side);
+    }
+
+    let mut iter = shape.inward_offsets(1.0, &options);
+    assert_eq!(iter.by_ref().count(), 4);
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn non_positive_step_is_empty() {
+    let shape = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 10.0)]);
+    let options = ShapeOffsetOptions::default();
+    assert_eq!(shape.inward_offsets(0.0, &options).count(), 0);
+    assert_eq!(shape.inward_offsets(-1.0, &options).count(), 0);
+    assert_eq!(shape.inward_offsets(f64::NAN, &options).count(), 0);
+    let tree = shape.pocket_offsets(-1.0, &options);
+    assert!(tree.loops.is_empty() && tree.roots.is_empty());
+    assert_eq!(tree.pass_count(), 0);
+}
+
+#[test]
+fn empty_shape_is_empty() {
+    let tree = Shape::<f64>::empty().pocket_offsets(1.0, &ShapeOffsetOptions::default());
+    assert!(tree.loops.is_empty());
+}
+
+#[test]
+fn square_with_island() {
+    // 20 x 20 square with a 4 x 4 island in the middle, 8 wide channel around the island
+    let shape = Shape::from_plines([
+        rectangle(0.0, 0.0, 20.0, 20.0),
+        hole(rectangle(8.0, 8.0, 12.0, 12.0)),
+    ]);
+    let tree = shape.pocket_offsets(1.5, &ShapeOffsetOptions::default());
+    assert_tree_links(&tree);
+
+    // passes at 1.5 and 3.0 keep the island separate, at 4.5 the channel is gone except for the
+    // four corners left by the rounded island loop corners
+    assert_eq!(tree.pass_count(), 3);
+    assert_eq!(tree.roots.len(), 2);
+    for pass in 0..2 {
+        let pass_loops = tree
+            .loops
+            .iter()
+            .filter(|l| l.pass == pass)
+            .collect::<Vec<_>>();
+        assert_eq!(pass_loops.len(), 2);
+        assert_eq!(
+            pass_loops[0].polyline.orientation(),
+            PlineOrientation::CounterClockwise
+        );
+        assert_eq!(
+            pass_loops[1].polyline.orientation(),
+            PlineOrientation::Clockwise
+        );
+    }
+    let corners = tree
+        .loops
+        .iter()
+        .filter(|l| l.pass == 2)
+        .collect::<Vec<_>>();
+    assert_eq!(corners.len(), 4);
+    let outer_parent = corners[0].parent.unwrap();
+    assert!(tree.loops[outer_parent].polyline.area() > 0.0);
+    assert!(corners.iter().all(|l| l.parent == Some(outer_parent)));
+
+    // outer loops chain to outer loops and island loops chain to island loops
+    for l in &tree.loops {
+        if let Some(p) = l.parent {
+            assert_eq!(
+                tree.loops[p].polyline.orientation(),
+                l.polyline.orientation()
+            );
+        }
+    }
+}
+
+#[test]
+fn neck_splits_into_two_children() {
+    // two 10 x 10 squares joined by a 4 wide neck
+    let dumbbell = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 3.0, 0.0),
+        (14.0, 3.0, 0.0),
+        (14.0, 0.0, 0.0),
+        (24.0, 0.0, 0.0),
+        (24.0, 10.0, 0.0),
+        (14.0, 10.0, 0.0),
+        (14.0, 7.0, 0.0),
+        (10.0, 7.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+    ];
+    let tree = Shape::from_plines([dumbbell]).pocket_offsets(1.5, &ShapeOffsetOptions::default());
+    assert_tree_links(&tree);
+    assert_eq!(tree.roots.len(), 1);
+    let root = &tree.loops[tree.roots[0]];
+    assert_eq!(root.children.len(), 2);
+    // each square continues to collapse on its own
+    for &c in &root.children {
+        let mut depth = 0;
+        let mut current = &tree.loops[c];
+        while let Some(&next) = current.children.first() {
+            assert_eq!(current.children.len(), 1);
+            current = &tree.loops[next];
+            depth += 1;
+        }
+        assert_eq!(current.pass, tree.pass_count() - 1);
+        assert_eq!(depth, tree.pass_count() - 2);
+    }
+}
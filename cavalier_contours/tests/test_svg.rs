@@ -0,0 +1,250 @@
+#![cfg(feature = "svg")]
+
+mod test_svg {
+    use cavalier_contours::core::{math::Vector2, traits::FuzzyEq};
+    use cavalier_contours::polyline::*;
+    use cavalier_contours::shape_algorithms::Shape;
+    use cavalier_contours::svg::*;
+    use cavalier_contours::{pline_closed, pline_open};
+
+    fn assert_plines_eq(result: &Polyline, expected: &Polyline) {
+        assert_eq!(result.is_closed(), expected.is_closed());
+        assert_eq!(
+            result.vertex_count(),
+            expected.vertex_count(),
+            "{result:?} != {expected:?}"
+        );
+        for (r, e) in result.iter_vertexes().zip(expected.iter_vertexes()) {
+            assert!(r.fuzzy_eq(e), "{r:?} != {e:?}");
+        }
+    }
+
+    fn parse(data: &str) -> Vec<Polyline> {
+        plines_from_path_data(data).unwrap()
+    }
+
+    #[test]
+    fn export_lines_and_arcs() {
+        let open = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, -1.0), (4.0, 0.0, 0.0)];
+        assert_eq!(pline_to_path_data(&open), "M 0 0 L 2 0 A 1 1 0 0 0 4 0");
+
+        let closed = pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.5, 0.0)];
+        assert_eq!(pline_to_path_data(&closed), "M 0 0 L 1 0 L 1 1.5 Z");
+
+        // large arc closing segment is written before closing
+        let closed = pline_closed![(0.0, 0.0, 0.0), (-2.0, 0.0, -2.0)];
+        let data = pline_to_path_data(&closed);
+        assert!(data.starts_with("M 0 0 L -2 0 A "), "{data}");
+        assert!(data.ends_with(" 0 1 0 0 0 Z"), "{data}");
+
+        assert_eq!(pline_to_path_data(&Polyline::<f64>::new()), "");
+        assert_eq!(pline_to_path_data(&pline_open![(1.0, -0.0, 0.0)]), "M 1 0");
+    }
+
+    #[test]
+    fn round_trip() {
+        let plines = [
+            pline_open![
+                (0.0, 0.0, 0.5),
+                (2.0, 1.0, 0.0),
+                (4.0, 0.0, -2.0),
+                (6.0, 0.0, 0.0)
+            ],
+            pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)],
+            pline_closed![
+                (0.0, 0.0, 0.0),
+                (5.0, 0.0, 0.3),
+                (5.0, 5.0, -0.25),
+                (0.0, 5.0, 3.0),
+            ],
+        ];
+        for pline in &plines {
+            let data = pline_to_path_data(pline);
+            let result = parse(&data);
+            assert_eq!(result.len(), 1, "{data}");
+            assert_plines_eq(&result[0], pline);
+        }
+    }
+
+    #[test]
+    fn shape_round_trip() {
+        let square = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0),
+        ];
+        let mut hole = pline_closed![(3.0, 5.0, 1.0), (7.0, 5.0, 1.0)];
+        hole.invert_direction_mut();
+        let shape = Shape::from_plines([square.clone(), hole.clone()]);
+        let data = shape_to_path_data(&shape);
+        let result = parse(&data);
+        assert_eq!(result.len(), 2);
+        assert_plines_eq(&result[0], &square);
+        assert_plines_eq(&result[1], &hole);
+        assert_eq!(shape_to_path_data(&Shape::<f64>::empty()), "");
+    }
+
+    #[test]
+    fn relative_and_implicit_commands() {
+        // implicit line to after move to, compact numbers and flags, and relative commands
+        let result = parse("m1-1 2,0 .5.5 h-1 v.5e1 l-1-1a1 1 0 011 0z");
+        assert_eq!(result.len(), 1);
+        let expected = pline_closed![
+            (1.0, -1.0, 0.0),
+            (3.0, -1.0, 0.0),
+            (3.5, -0.5, 0.0),
+            (2.5, -0.5, 0.0),
+            (2.5, 4.5, 0.0),
+            (1.5, 3.5, 0.2679491924311227),
+            (2.5, 3.5, 0.0),
+        ];
+        assert_plines_eq(&result[0], &expected);
+    }
+
+    #[test]
+    fn close_path_subpaths() {
+        // drawing after close path starts at the subpath start point, end position repeating the
+        // start position is merged into the closing segment
+        let result = parse("M0 0 L4 0 A2 2 0 0 1 0 0 Z l0 -3 M10 10 L10 10 M 5 5");
+        assert_eq!(result.len(), 2);
+        assert_plines_eq(&result[0], &pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 1.0)]);
+        assert_plines_eq(&result[1], &pline_open![(0.0, 0.0, 0.0), (0.0, -3.0, 0.0)]);
+        assert!(parse("").is_empty());
+        assert!(parse("  ").is_empty());
+    }
+
+    #[test]
+    fn arc_radius_handling() {
+        // radius too small is scaled up to a half circle
+        let result = parse("M0 0 A0.1 0.1 0 0 1 2 0");
+        assert_plines_eq(&result[0], &pline_open![(0.0, 0.0, 1.0), (2.0, 0.0, 0.0)]);
+        // zero radius is a line
+        let result = parse("M0 0 A0 1 0 0 1 2 0");
+        assert_plines_eq(&result[0], &pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)]);
+        // large arc and sweep flags select between the four circular arcs
+        let quarter = (std::f64::consts::PI / 8.0).tan();
+        let three_quarter = (3.0 * std::f64::consts::PI / 8.0).tan();
+        for (flags, bulge) in [
+            ("0 0", -quarter),
+            ("0 1", quarter),
+            ("1 0", -three_quarter),
+            ("1 1", three_quarter),
+        ] {
+            let result = parse(&format!("M1 0 A1 1 0 {flags} 0 1"));
+            assert!(result[0][0].bulge.fuzzy_eq(bulge), "{flags}");
+        }
+    }
+
+    #[test]
+    fn elliptical_arc_within_tolerance() {
+        // half ellipse with x radius 4 and y radius 2, rotated 90 degrees
+        let options = SvgPathParseOptions {
+            curve_tolerance: 1e-3,
+            ..Default::default()
+        };
+        let result = plines_from_path_data_opt("M0 -4 A4 2 90 0 1 0 4", &options).unwrap();
+        assert_eq!(result.len(), 1);
+        let pline = &result[0];
+        assert!(pline.vertex_count() > 10);
+        assert!(pline.iter_vertexes().all(|v| v.bulge_is_zero()));
+        assert!(pline.at(0).pos().fuzzy_eq(Vector2::new(0.0, -4.0)));
+        assert!(pline.last().unwrap().pos().fuzzy_eq(Vector2::new(0.0, 4.0)));
+        for v in pline.iter_vertexes() {
+            // rotated ellipse centered at origin, x radius along the y axis
+            let e = f64::powi(v.y / 4.0, 2) + f64::powi(v.x / 2.0, 2);
+            assert!(e.fuzzy_eq(1.0), "{v:?}");
+            // sweep is in the positive angle direction
+            assert!(v.x >= -1e-9);
+        }
+        // closing the approximated half ellipse gives area close to half the ellipse area
+        let mut closed = pline.clone();
+        closed.set_is_closed(true);
+        let half_area = std::f64::consts::PI * 4.0 * 2.0 / 2.0;
+        assert!(f64::abs(closed.area().abs() - half_area) < 0.05);
+    }
+
+    #[test]
+    fn bezier_curves_within_tolerance() {
+        let tolerance = 1e-3;
+        let options = SvgPathParseOptions {
+            curve_tolerance: tolerance,
+            ..Default::default()
+        };
+        // cubic approximating a quarter circle of radius 1
+        let k = 0.5522847498;
+        let data = format!("M1 0 C1 {k} {k} 1 0 1");
+        let result = plines_from_path_data_opt(&data, &options).unwrap();
+        let pline = &result[0];
+        assert!(pline.vertex_count() > 5);
+        for v in pline.iter_vertexes() {
+            assert!(f64::abs(v.pos().length() - 1.0) < 1e-3, "{v:?}");
+        }
+        assert!(pline.last().unwrap().pos().fuzzy_eq(Vector2::new(0.0, 1.0)));
+
+        // smooth cubic reflects the previous control point: symmetric S curve
+        let result = plines_from_path_data_opt("M0 0 C0 1 1 1 1 0 S2 -1 2 0", &options).unwrap();
+        let pline = &result[0];
+        for v in pline.iter_vertexes() {
+            let mirrored = Vector2::new(2.0 - v.x, -v.y);
+            assert!(
+                pline
+                    .iter_vertexes()
+                    .any(|w| w.pos().fuzzy_eq_eps(mirrored, 1e-6)),
+                "{v:?}"
+            );
+        }
+
+        // quadratic and smooth quadratic, parabola y = x^2 from -1 to 1 then mirrored back down
+        let result = plines_from_path_data_opt("M-1 1 Q0 -1 1 1 T3 1", &options).unwrap();
+        let pline = &result[0];
+        for v in pline.iter_vertexes().filter(|v| v.x <= 1.0) {
+            assert!(v.y.fuzzy_eq_eps(v.x * v.x, 1e-9), "{v:?}");
+        }
+        assert!(pline.iter_vertexes().any(|v| v.y > 1.9));
+        assert!(pline.last().unwrap().pos().fuzzy_eq(Vector2::new(3.0, 1.0)));
+
+        // smooth curve without a previous curve uses the current point as control point (line)
+        let result = plines_from_path_data_opt("M0 0 T2 0", &options).unwrap();
+        assert!(result[0].iter_vertexes().all(|v| v.y.fuzzy_eq(0.0)));
+        assert!(result[0].path_length().fuzzy_eq(2.0));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            plines_from_path_data::<f64>("L1 1").unwrap_err(),
+            (SvgPathError::MissingMoveTo)
+        );
+        assert_eq!(
+            plines_from_path_data::<f64>("M1 x").unwrap_err(),
+            (SvgPathError::ExpectedNumber { position: 3 })
+        );
+        assert_eq!(
+            plines_from_path_data::<f64>("M1 1 L2").unwrap_err(),
+            (SvgPathError::ExpectedNumber { position: 7 })
+        );
+        assert_eq!(
+            plines_from_path_data::<f64>("M0 0 A1 1 0 2 1 1 1").unwrap_err(),
+            (SvgPathError::ExpectedNumber { position: 12 })
+        );
+        assert_eq!(
+            plines_from_path_data::<f64>("M1 1 # 2").unwrap_err(),
+            (SvgPathError::UnexpectedChar {
+                position: 5,
+                found: '#'
+            })
+        );
+        assert_eq!(
+            plines_from_path_data::<f64>("M0 0 L1 1 Z 2 2").unwrap_err(),
+            (SvgPathError::UnexpectedChar {
+                position: 12,
+                found: '2'
+            })
+        );
+        assert_eq!(
+            SvgPathError::ExpectedNumber { position: 3 }.to_string(),
+            "expected number at position 3"
+        );
+    }
+}
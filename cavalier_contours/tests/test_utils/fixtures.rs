@@ -0,0 +1,34 @@
+use cavalier_contours::polyline::{PlineCreation, PlineSourceMut, Polyline};
+use cavalier_contours::{pline_closed, pline_open};
+
+/// Closed counter clockwise square with its bottom left corner at (`x`, `y`) and sides of length
+/// `size`.
+pub fn square(x: f64, y: f64, size: f64) -> Polyline {
+    pline_closed![
+        (x, y, 0.0),
+        (x + size, y, 0.0),
+        (x + size, y + size, 0.0),
+        (x, y + size, 0.0),
+    ]
+}
+
+/// Closed counter clockwise axis aligned rectangle from (`min_x`, `min_y`) to (`max_x`, `max_y`).
+pub fn rectangle(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Polyline {
+    pline_closed![
+        (min_x, min_y, 0.0),
+        (max_x, min_y, 0.0),
+        (max_x, max_y, 0.0),
+        (min_x, max_y, 0.0),
+    ]
+}
+
+/// Returns `pline` with its direction inverted (clockwise closed polylines are holes in a shape).
+pub fn hole(mut pline: Polyline) -> Polyline {
+    pline.invert_direction_mut();
+    pline
+}
+
+/// Line of length 10 followed by a counter clockwise half circle with radius 5.
+pub fn line_and_arc() -> Polyline {
+    pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 10.0, 0.0)]
+}
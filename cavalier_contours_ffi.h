@@ -15,6 +15,38 @@
 
 #define CAVC_COINCIDENT_SEGMENT_BEHAVIOR_DISCARD 1
 
+/**
+ * FFI values for [`OffsetJoinType`].
+ */
+#define CAVC_JOIN_TYPE_ROUND 0
+
+#define CAVC_JOIN_TYPE_MITER 1
+
+#define CAVC_JOIN_TYPE_BEVEL 2
+
+/**
+ * FFI values for [`OffsetCapType`].
+ */
+#define CAVC_CAP_TYPE_ROUND 0
+
+#define CAVC_CAP_TYPE_SQUARE 1
+
+#define CAVC_CAP_TYPE_BUTT 2
+
+/**
+ * FFI values for the optional [`FillRule`] of boolean operations.
+ */
+#define CAVC_FILL_RULE_NONE 0
+
+#define CAVC_FILL_RULE_NON_ZERO 1
+
+#define CAVC_FILL_RULE_EVEN_ODD 2
+
+/**
+ * Default miter limit written to options (only used when join type is miter).
+ */
+#define CAVC_DEFAULT_MITER_LIMIT 4.0
+
 /**
  * FFI representation of `SelfIntersectsInclude` enum
  */
@@ -85,6 +117,18 @@ typedef struct cavc_pline_parallel_offset_o {
    * One of the `CAVC_COINCIDENT_SEGMENT_BEHAVIOR_*` constants.
    */
   uint32_t coincident_segment_behavior;
+  /**
+   * One of the `CAVC_JOIN_TYPE_*` constants.
+   */
+  uint32_t join_type;
+  /**
+   * Miter limit used when `join_type` is `CAVC_JOIN_TYPE_MITER`.
+   */
+  double miter_limit;
+  /**
+   * One of the `CAVC_CAP_TYPE_*` constants.
+   */
+  uint32_t cap_type;
 } cavc_pline_parallel_offset_o;
 
 /**
@@ -97,6 +141,10 @@ typedef struct cavc_pline_boolean_o {
    * NOTE: optional parameter, set to NaN for None.
    */
   double collapsed_area_eps;
+  /**
+   * One of the `CAVC_FILL_RULE_*` constants.
+   */
+  uint32_t fill_rule;
 } cavc_pline_boolean_o;
 
 /**
@@ -132,6 +180,14 @@ typedef struct cavc_shape_offset_o {
   double pos_equal_eps;
   double offset_dist_eps;
   double slice_join_eps;
+  /**
+   * One of the `CAVC_JOIN_TYPE_*` constants.
+   */
+  uint32_t join_type;
+  /**
+   * Miter limit used when `join_type` is `CAVC_JOIN_TYPE_MITER`.
+   */
+  double miter_limit;
 } cavc_shape_offset_o;
 
 #ifdef __cplusplus
@@ -728,7 +784,7 @@ int32_t cavc_pline_eval_extents(const struct cavc_pline *pline,
  *
  * ## Specific Error Codes
  * * 1 = `pline` is null.
- * * 2 = `options` contains an unrecognized behavior value.
+ * * 2 = `options` contains an unrecognized behavior, join type, or cap type value.
  *
  * # Safety
  *
@@ -741,6 +797,26 @@ int32_t cavc_pline_parallel_offset(const struct cavc_pline *pline,
                                    const struct cavc_pline_parallel_offset_o *options,
                                    const struct cavc_plinelist **result);
 
+/**
+ * Wraps [`PlineSource::stroke_opt`].
+ *
+ * `options` is allowed to be null (default options will be used).
+ *
+ * ## Specific Error Codes
+ * * 1 = `pline` is null.
+ * * 2 = `options` contains an unrecognized behavior, join type, or cap type value.
+ *
+ * # Safety
+ *
+ * `pline` must be null or a valid `cavc_pline` object that was created with [`cavc_pline_create`] and
+ * has not been freed.
+ * `result` must point to a valid place in memory to be written.
+ */
+int32_t cavc_pline_stroke(const struct cavc_pline *pline,
+                          double distance,
+                          const struct cavc_pline_parallel_offset_o *options,
+                          const struct cavc_plinelist **result);
+
 /**
  * Wraps [`PlineSource::boolean_opt`].
  *
@@ -755,6 +831,7 @@ int32_t cavc_pline_parallel_offset(const struct cavc_pline *pline,
  * ## Specific Error Codes
  * * 1 = `pline1` and/or `pline2` is null.
  * * 2 = `operation` is unrecognized (must be one of the values listed).
+ * * 3 = `options` is invalid (unrecognized `fill_rule`).
  *
  * # Safety
  *
@@ -1040,6 +1117,7 @@ void cavc_shape_f(struct cavc_shape *shape);
  *
  * ## Specific Error Codes
  * * 1 = `shape` is null.
+ * * 2 = `options` contains an unrecognized join type value.
  *
  * # Safety
  *
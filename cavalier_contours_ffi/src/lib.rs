@@ -3,9 +3,10 @@
 use cavalier_contours::{
     core::math::Vector2,
     polyline::{
-        BooleanOp, CoincidentSegmentBehavior, PlineBooleanOptions, PlineContainsOptions,
-        PlineOffsetOptions, PlineSelfIntersectOptions, PlineSource, PlineSourceMut, PlineVertex,
-        Polyline, SelfIntersectsInclude, TouchingLoopBehavior,
+        BooleanOp, CoincidentSegmentBehavior, FillRule, OffsetCapType, OffsetJoinType,
+        PlineBooleanOptions, PlineContainsOptions, PlineOffsetOptions, PlineSelfIntersectOptions,
+        PlineSource, PlineSourceMut, PlineVertex, Polyline, SelfIntersectsInclude,
+        TouchingLoopBehavior,
     },
     shape_algorithms::{Shape, ShapeOffsetOptions},
     static_aabb2d_index::StaticAABB2DIndex,
@@ -85,6 +86,42 @@ pub const CAVC_TOUCHING_LOOP_BEHAVIOR_SEPARATE: u32 = 1;
 pub const CAVC_COINCIDENT_SEGMENT_BEHAVIOR_PRESERVE: u32 = 0;
 pub const CAVC_COINCIDENT_SEGMENT_BEHAVIOR_DISCARD: u32 = 1;
 
+/// FFI values for [`OffsetJoinType`].
+pub const CAVC_JOIN_TYPE_ROUND: u32 = 0;
+pub const CAVC_JOIN_TYPE_MITER: u32 = 1;
+pub const CAVC_JOIN_TYPE_BEVEL: u32 = 2;
+/// Default miter limit written to options (only used when join type is miter).
+pub const CAVC_DEFAULT_MITER_LIMIT: f64 = 4.0;
+
+/// FFI values for [`OffsetCapType`].
+pub const CAVC_CAP_TYPE_ROUND: u32 = 0;
+pub const CAVC_CAP_TYPE_SQUARE: u32 = 1;
+pub const CAVC_CAP_TYPE_BUTT: u32 = 2;
+
+/// FFI values for the optional [`FillRule`] of boolean operations.
+pub const CAVC_FILL_RULE_NONE: u32 = 0;
+pub const CAVC_FILL_RULE_NON_ZERO: u32 = 1;
+pub const CAVC_FILL_RULE_EVEN_ODD: u32 = 2;
+
+/// Convert FFI join type values to internal type, returns `None` if `join_type` is unrecognized.
+fn join_type_to_internal(join_type: u32, miter_limit: f64) -> Option<OffsetJoinType<f64>> {
+    match join_type {
+        CAVC_JOIN_TYPE_ROUND => Some(OffsetJoinType::Round),
+        CAVC_JOIN_TYPE_MITER => Some(OffsetJoinType::Miter { limit: miter_limit }),
+        CAVC_JOIN_TYPE_BEVEL => Some(OffsetJoinType::Bevel),
+        _ => None,
+    }
+}
+
+/// Convert internal join type to FFI values as (join type, miter limit).
+fn join_type_from_internal(join_type: OffsetJoinType<f64>) -> (u32, f64) {
+    match join_type {
+        OffsetJoinType::Round => (CAVC_JOIN_TYPE_ROUND, CAVC_DEFAULT_MITER_LIMIT),
+        OffsetJoinType::Miter { limit } => (CAVC_JOIN_TYPE_MITER, limit),
+        OffsetJoinType::Bevel => (CAVC_JOIN_TYPE_BEVEL, CAVC_DEFAULT_MITER_LIMIT),
+    }
+}
+
 /// FFI representation of [`PlineOffsetOptions`].
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -97,6 +134,12 @@ pub struct cavc_pline_parallel_offset_o {
     pub touching_loop_behavior: u32,
     /// One of the `CAVC_COINCIDENT_SEGMENT_BEHAVIOR_*` constants.
     pub coincident_segment_behavior: u32,
+    /// One of the `CAVC_JOIN_TYPE_*` constants.
+    pub join_type: u32,
+    /// Miter limit used when `join_type` is `CAVC_JOIN_TYPE_MITER`.
+    pub miter_limit: f64,
+    /// One of the `CAVC_CAP_TYPE_*` constants.
+    pub cap_type: u32,
 }
 
 impl cavc_pline_parallel_offset_o {
@@ -118,6 +161,13 @@ impl cavc_pline_parallel_offset_o {
             CAVC_COINCIDENT_SEGMENT_BEHAVIOR_DISCARD => CoincidentSegmentBehavior::Discard,
             _ => return None,
         };
+        let join_type = join_type_to_internal(self.join_type, self.miter_limit)?;
+        let cap_type = match self.cap_type {
+            CAVC_CAP_TYPE_ROUND => OffsetCapType::Round,
+            CAVC_CAP_TYPE_SQUARE => OffsetCapType::Square,
+            CAVC_CAP_TYPE_BUTT => OffsetCapType::Butt,
+            _ => return None,
+        };
         Some(PlineOffsetOptions {
             aabb_index,
             pos_equal_eps: self.pos_equal_eps,
@@ -125,6 +175,8 @@ impl cavc_pline_parallel_offset_o {
             handle_self_intersects: self.handle_self_intersects != 0,
             touching_loop_behavior,
             coincident_segment_behavior,
+            join_type,
+            cap_type,
         })
     }
 }
@@ -132,6 +184,7 @@ impl cavc_pline_parallel_offset_o {
 impl Default for cavc_pline_parallel_offset_o {
     fn default() -> Self {
         let d = PlineOffsetOptions::default();
+        let (join_type, miter_limit) = join_type_from_internal(d.join_type);
         Self {
             aabb_index: std::ptr::null(),
             pos_equal_eps: d.pos_equal_eps,
@@ -139,6 +192,13 @@ impl Default for cavc_pline_parallel_offset_o {
             handle_self_intersects: u8::from(d.handle_self_intersects),
             touching_loop_behavior: CAVC_TOUCHING_LOOP_BEHAVIOR_PRESERVE,
             coincident_segment_behavior: CAVC_COINCIDENT_SEGMENT_BEHAVIOR_PRESERVE,
+            join_type,
+            miter_limit,
+            cap_type: match d.cap_type {
+                OffsetCapType::Round => CAVC_CAP_TYPE_ROUND,
+                OffsetCapType::Square => CAVC_CAP_TYPE_SQUARE,
+                OffsetCapType::Butt => CAVC_CAP_TYPE_BUTT,
+            },
         }
     }
 }
@@ -217,18 +277,29 @@ pub struct cavc_pline_boolean_o {
     pub pos_equal_eps: f64,
     /// NOTE: optional parameter, set to NaN for None.
     pub collapsed_area_eps: f64,
+    /// One of the `CAVC_FILL_RULE_*` constants.
+    pub fill_rule: u32,
 }
 
 impl cavc_pline_boolean_o {
-    /// Convert FFI boolean options type to internal type.
+    /// Convert FFI boolean options type to internal type, returns `None` if `fill_rule` is
+    /// unrecognized.
     ///
     /// # Safety
     ///
     /// `pline1_aabb_index` field must be null or a valid pointer to a [`cavc_aabbindex`].
     #[must_use]
-    pub unsafe fn to_internal(&self) -> PlineBooleanOptions<'_, f64> {
+    pub unsafe fn to_internal(&self) -> Option<PlineBooleanOptions<'_, f64>> {
         let pline1_aabb_index = unsafe { self.pline1_aabb_index.as_ref().map(|w| &w.0) };
-        PlineBooleanOptions {
+        let fill_rule = match self.fill_rule {
+            CAVC_FILL_RULE_NONE => None,
+            CAVC_FILL_RULE_NON_ZERO => Some(FillRule::NonZero),
+            CAVC_FILL_RULE_EVEN_ODD => Some(FillRule::EvenOdd),
+            _ => {
+                return None;
+            }
+        };
+        Some(PlineBooleanOptions {
             pline1_aabb_index,
             pos_equal_eps: self.pos_equal_eps,
             collapsed_area_eps: if self.collapsed_area_eps.is_nan() {
@@ -236,7 +307,8 @@ impl cavc_pline_boolean_o {
             } else {
                 Some(self.collapsed_area_eps)
             },
-        }
+            fill_rule,
+        })
     }
 }
 
@@ -247,6 +319,7 @@ impl Default for cavc_pline_boolean_o {
             pline1_aabb_index: std::ptr::null(),
             pos_equal_eps: d.pos_equal_eps,
             collapsed_area_eps: f64::NAN,
+            fill_rule: CAVC_FILL_RULE_NONE,
         }
     }
 }
@@ -1381,7 +1454,7 @@ pub unsafe extern "C" fn cavc_pline_eval_extents(
 ///
 /// ## Specific Error Codes
 /// * 1 = `pline` is null.
-/// * 2 = `options` contains an unrecognized behavior value.
+/// * 2 = `options` contains an unrecognized behavior, join type, or cap type value.
 ///
 /// # Safety
 ///
@@ -1419,6 +1492,50 @@ pub unsafe extern "C" fn cavc_pline_parallel_offset(
     })
 }
 
+/// Wraps [`PlineSource::stroke_opt`].
+///
+/// `options` is allowed to be null (default options will be used).
+///
+/// ## Specific Error Codes
+/// * 1 = `pline` is null.
+/// * 2 = `options` contains an unrecognized behavior, join type, or cap type value.
+///
+/// # Safety
+///
+/// `pline` must be null or a valid `cavc_pline` object that was created with [`cavc_pline_create`] and
+/// has not been freed.
+/// `result` must point to a valid place in memory to be written.
+#[unsafe(no_mangle)]
+#[must_use]
+pub unsafe extern "C" fn cavc_pline_stroke(
+    pline: *const cavc_pline,
+    distance: f64,
+    options: *const cavc_pline_parallel_offset_o,
+    result: *mut *const cavc_plinelist,
+) -> i32 {
+    ffi_catch_unwind!({
+        if pline.is_null() {
+            return 1;
+        }
+
+        let pline = unsafe { &(*pline).0 };
+
+        let results = if options.is_null() {
+            pline.stroke(distance)
+        } else {
+            let Some(opts) = (unsafe { (*options).to_internal() }) else {
+                return 2;
+            };
+            pline.stroke_opt(distance, &opts)
+        };
+
+        unsafe {
+            result.write(cavc_plinelist::from_internal(results));
+        }
+        0
+    })
+}
+
 /// Wraps [`PlineSource::boolean_opt`].
 ///
 /// `options` is allowed to be null (default options will be used).
@@ -1432,6 +1549,7 @@ pub unsafe extern "C" fn cavc_pline_parallel_offset(
 /// ## Specific Error Codes
 /// * 1 = `pline1` and/or `pline2` is null.
 /// * 2 = `operation` is unrecognized (must be one of the values listed).
+/// * 3 = `options` is invalid (unrecognized `fill_rule`).
 ///
 /// # Safety
 ///
@@ -1468,8 +1586,10 @@ pub unsafe extern "C" fn cavc_pline_boolean(
         let results = if options.is_null() {
             pline1.boolean(pline2, op)
         } else {
-            let options = unsafe { &(*options).to_internal() };
-            pline1.boolean_opt(pline2, op, options)
+            let Some(options) = (unsafe { (*options).to_internal() }) else {
+                return 3;
+            };
+            pline1.boolean_opt(pline2, op, &options)
         };
 
         unsafe {
@@ -1951,27 +2071,36 @@ pub struct cavc_shape_offset_o {
     pub pos_equal_eps: f64,
     pub offset_dist_eps: f64,
     pub slice_join_eps: f64,
+    /// One of the `CAVC_JOIN_TYPE_*` constants.
+    pub join_type: u32,
+    /// Miter limit used when `join_type` is `CAVC_JOIN_TYPE_MITER`.
+    pub miter_limit: f64,
 }
 
 impl cavc_shape_offset_o {
-    /// Convert FFI shape offset options type to internal type.
+    /// Convert FFI shape offset options type to internal type, returns `None` if `join_type` is
+    /// unrecognized.
     #[must_use]
-    pub fn to_internal(&self) -> ShapeOffsetOptions<f64> {
-        ShapeOffsetOptions {
+    pub fn to_internal(&self) -> Option<ShapeOffsetOptions<f64>> {
+        Some(ShapeOffsetOptions {
             pos_equal_eps: self.pos_equal_eps,
             offset_dist_eps: self.offset_dist_eps,
             slice_join_eps: self.slice_join_eps,
-        }
+            join_type: join_type_to_internal(self.join_type, self.miter_limit)?,
+        })
     }
 }
 
 impl Default for cavc_shape_offset_o {
     fn default() -> Self {
         let d = ShapeOffsetOptions::default();
+        let (join_type, miter_limit) = join_type_from_internal(d.join_type);
         Self {
             pos_equal_eps: d.pos_equal_eps,
             offset_dist_eps: d.offset_dist_eps,
             slice_join_eps: d.slice_join_eps,
+            join_type,
+            miter_limit,
         }
     }
 }
@@ -2065,6 +2194,7 @@ pub unsafe extern "C" fn cavc_shape_f(shape: *mut cavc_shape) {
 ///
 /// ## Specific Error Codes
 /// * 1 = `shape` is null.
+/// * 2 = `options` contains an unrecognized join type value.
 ///
 /// # Safety
 ///
@@ -2089,9 +2219,10 @@ pub unsafe extern "C" fn cavc_shape_parallel_offset(
                 let default_options = ShapeOffsetOptions::new();
                 (*shape).0.parallel_offset(offset, &default_options)
             } else {
-                (*shape)
-                    .0
-                    .parallel_offset(offset, &(*options).to_internal())
+                let Some(opts) = (*options).to_internal() else {
+                    return 2;
+                };
+                (*shape).0.parallel_offset(offset, &opts)
             };
 
             result.write(Box::into_raw(Box::new(cavc_shape(results))));
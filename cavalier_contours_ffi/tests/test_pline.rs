@@ -461,6 +461,9 @@ fn pline_eval_parallel_offset() {
             handle_self_intersects: 0,
             touching_loop_behavior: u32::MAX,
             coincident_segment_behavior: u32::MAX,
+            join_type: u32::MAX,
+            miter_limit: f64::NAN,
+            cap_type: u32::MAX,
         };
 
         let mut results = ptr::null();
@@ -533,6 +536,18 @@ fn pline_eval_parallel_offset() {
                 cavc_pline_parallel_offset(pline, offset, &raw const options, &raw mut results),
                 2
             );
+            options.coincident_segment_behavior = CAVC_COINCIDENT_SEGMENT_BEHAVIOR_PRESERVE;
+            options.join_type = u32::MAX;
+            assert_eq!(
+                cavc_pline_parallel_offset(pline, offset, &raw const options, &raw mut results),
+                2
+            );
+            options.join_type = CAVC_JOIN_TYPE_ROUND;
+            options.cap_type = u32::MAX;
+            assert_eq!(
+                cavc_pline_parallel_offset(pline, offset, &raw const options, &raw mut results),
+                2
+            );
 
             cavc_aabbindex_f(aabb_index.cast_mut());
 
@@ -569,6 +584,97 @@ fn pline_parallel_offset_coincident_segment_behavior() {
     }
 }
 
+#[test]
+fn pline_parallel_offset_join_type() {
+    let square = create_pline(
+        &[
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0),
+        ],
+        true,
+    );
+
+    unsafe {
+        let mut options = cavc_pline_parallel_offset_o::default();
+        assert_eq!(options.join_type, CAVC_JOIN_TYPE_ROUND);
+
+        let result_vertex_count = |options: &cavc_pline_parallel_offset_o| {
+            let (results, result_count) = parallel_offset_result_count(square, -1.0, options);
+            assert_eq!(result_count, 1);
+            let mut result_pline = ptr::null();
+            assert_eq!(
+                cavc_plinelist_get_pline(results, 0, &raw mut result_pline),
+                0
+            );
+            let mut count = 0;
+            assert_eq!(cavc_pline_get_vertex_count(result_pline, &raw mut count), 0);
+            cavc_plinelist_f(results.cast_mut());
+            count
+        };
+
+        assert_eq!(result_vertex_count(&options), 8);
+
+        options.join_type = CAVC_JOIN_TYPE_MITER;
+        options.miter_limit = 2.0;
+        assert_eq!(result_vertex_count(&options), 4);
+
+        options.join_type = CAVC_JOIN_TYPE_BEVEL;
+        assert_eq!(result_vertex_count(&options), 8);
+
+        cavc_pline_f(square);
+    }
+}
+
+#[test]
+fn pline_stroke() {
+    let line = create_pline(&[(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)], false);
+
+    unsafe {
+        let mut options = cavc_pline_parallel_offset_o::default();
+        assert_eq!(options.cap_type, CAVC_CAP_TYPE_ROUND);
+
+        let stroke_area = |options: *const cavc_pline_parallel_offset_o| {
+            let mut results = ptr::null();
+            assert_eq!(cavc_pline_stroke(line, 1.0, options, &raw mut results), 0);
+            let mut result_count = 0;
+            assert_eq!(cavc_plinelist_get_count(results, &raw mut result_count), 0);
+            assert_eq!(result_count, 1);
+            let mut result_pline = ptr::null();
+            assert_eq!(
+                cavc_plinelist_get_pline(results, 0, &raw mut result_pline),
+                0
+            );
+            let mut area = f64::NAN;
+            assert_eq!(cavc_pline_eval_area(result_pline, &raw mut area), 0);
+            cavc_plinelist_f(results.cast_mut());
+            area
+        };
+
+        assert_fuzzy_eq!(stroke_area(ptr::null()), 20.0 + std::f64::consts::PI);
+
+        options.cap_type = CAVC_CAP_TYPE_SQUARE;
+        assert_fuzzy_eq!(stroke_area(&raw const options), 24.0);
+
+        options.cap_type = CAVC_CAP_TYPE_BUTT;
+        assert_fuzzy_eq!(stroke_area(&raw const options), 20.0);
+
+        let mut results = ptr::null();
+        assert_eq!(
+            cavc_pline_stroke(ptr::null(), 1.0, &raw const options, &raw mut results),
+            1
+        );
+        options.cap_type = u32::MAX;
+        assert_eq!(
+            cavc_pline_stroke(line, 1.0, &raw const options, &raw mut results),
+            2
+        );
+
+        cavc_pline_f(line);
+    }
+}
+
 #[test]
 fn pline_parallel_offset_touching_loop_behavior() {
     let source = create_pline(
@@ -782,11 +888,13 @@ fn pline_eval_boolean() {
             pline1_aabb_index: std::ptr::null(),
             pos_equal_eps: f64::NAN,
             collapsed_area_eps: f64::NAN,
+            fill_rule: u32::MAX,
         };
 
         unsafe {
             assert_eq!(cavc_pline_boolean_o_init(&raw mut options), 0);
             assert!(!options.pos_equal_eps.is_nan());
+            assert_eq!(options.fill_rule, CAVC_FILL_RULE_NONE);
 
             let mut pline1_aabb_index = ptr::null();
 
@@ -848,6 +956,73 @@ fn pline_eval_boolean() {
     }
 }
 
+#[test]
+fn pline_boolean_fill_rule() {
+    // figure eight
+    let pline1 = create_pline(
+        &[
+            (0.0, 0.0, 0.0),
+            (2.0, 2.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (0.0, 2.0, 0.0),
+        ],
+        true,
+    );
+    let pline2 = create_pline(
+        &[
+            (0.5, 0.0, 0.0),
+            (1.5, 0.0, 0.0),
+            (1.5, 2.0, 0.0),
+            (0.5, 2.0, 0.0),
+        ],
+        true,
+    );
+
+    unsafe {
+        let mut options = cavc_pline_boolean_o::default();
+        assert_eq!(options.fill_rule, CAVC_FILL_RULE_NONE);
+        options.fill_rule = CAVC_FILL_RULE_NON_ZERO;
+
+        let mut pos_plines = ptr::null();
+        let mut neg_plines = ptr::null();
+        assert_eq!(
+            cavc_pline_boolean(
+                pline1,
+                pline2,
+                1,
+                &raw const options,
+                &raw mut pos_plines,
+                &raw mut neg_plines
+            ),
+            0
+        );
+        let mut pos_plines_count = usize::MAX;
+        assert_eq!(
+            cavc_plinelist_get_count(pos_plines, &raw mut pos_plines_count),
+            0
+        );
+        assert_eq!(pos_plines_count, 2);
+        cavc_plinelist_f(pos_plines.cast_mut());
+        cavc_plinelist_f(neg_plines.cast_mut());
+
+        options.fill_rule = u32::MAX;
+        assert_eq!(
+            cavc_pline_boolean(
+                pline1,
+                pline2,
+                1,
+                &raw const options,
+                &raw mut pos_plines,
+                &raw mut neg_plines
+            ),
+            3
+        );
+
+        cavc_pline_f(pline1);
+        cavc_pline_f(pline2);
+    }
+}
+
 #[test]
 fn shape_eval_ffi() {
     let outer_pline = create_pline(
@@ -1195,6 +1370,7 @@ fn shape_eval_ffi() {
                 pos_equal_eps: 0.0001,
                 offset_dist_eps: 0.001,
                 slice_join_eps: 0.001,
+                ..Default::default()
             };
             let mut result_shape = ptr::null_mut();
             assert_eq!(